@@ -1,20 +1,21 @@
 use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
 use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
     program::*, // {invoke, invoke_signed}
-    system_instruction,
 };
 use anchor_spl::{
     associated_token::AssociatedToken,
     token::{self, Token, TokenAccount, Mint},
 };
-use std::convert::TryInto;
 
 declare_id!("4QfE5Y7LiQrGp2TuT84vLrgz823KM7Xaq6iSEVYw5yX6");
 
 // ProjectStatus enum for instruction
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub enum ProjectStatus {
     /// 활성 상태
+    #[default]
     Active,
     /// 일시 중지
     Paused,
@@ -24,12 +25,158 @@ pub enum ProjectStatus {
     Cancelled,
 }
 
-impl Default for ProjectStatus {
-    fn default() -> Self {
-        ProjectStatus::Active
+/// 프로젝트 상태 변경 사유 코드
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum StatusReason {
+    /// 프로젝트 등록 (초기 상태)
+    #[default]
+    Registered,
+    /// 프로젝트 관리자 요청
+    ProjectRequest,
+    /// 점검
+    Maintenance,
+    /// 보안 사고 대응
+    SecurityIncident,
+    /// 규정 검토
+    ComplianceReview,
+    /// 플랫폼 결정
+    PlatformDecision,
+    /// 펀딩 목표 달성 정산
+    FundingGoalReached,
+    /// 펀딩 목표 미달 정산
+    FundingGoalMissed,
+}
+
+impl StatusReason {
+    /// 상태 변경 요청에 직접 지정할 수 있는 사유인지 여부 (등록/정산 사유는 시스템 전용)
+    pub fn is_manual(self) -> bool {
+        !matches!(
+            self,
+            StatusReason::Registered | StatusReason::FundingGoalReached | StatusReason::FundingGoalMissed
+        )
+    }
+}
+
+/// 프로젝트 상태 변경 주체
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum StatusActor {
+    /// 프로젝트 관리자 (멀티시그 제안 실행 포함)
+    #[default]
+    ProjectAuthority,
+    /// 플랫폼 관리자
+    Platform,
+    /// 펀딩 기간 종료 정산 (finalize_project)
+    Settlement,
+}
+
+impl ProjectStatus {
+    /// 상태 전이 테이블과 주체별 권한 확인
+    ///
+    /// - Active <-> Paused: 프로젝트 관리자 또는 플랫폼 (플랫폼이 중지했거나 보안/규정 사유 중지는 플랫폼만 해제)
+    /// - Active/Paused -> Cancelled: 프로젝트 관리자, 플랫폼 또는 정산
+    /// - Active/Paused -> Completed: 정산만 가능
+    /// - Cancelled -> Active: 플랫폼만 가능
+    /// - Completed: 종료 상태
+    pub fn check_transition(
+        self,
+        to: ProjectStatus,
+        actor: StatusActor,
+        current_actor: StatusActor,
+        current_reason: StatusReason,
+    ) -> Result<()> {
+        use ProjectStatus::*;
+
+        let allowed = match (self, to) {
+            (Active, Paused) => actor != StatusActor::Settlement,
+            (Paused, Active) => match actor {
+                StatusActor::Platform => true,
+                StatusActor::ProjectAuthority => {
+                    current_actor == StatusActor::ProjectAuthority
+                        && !matches!(
+                            current_reason,
+                            StatusReason::SecurityIncident | StatusReason::ComplianceReview
+                        )
+                }
+                StatusActor::Settlement => false,
+            },
+            (Active | Paused, Cancelled) => true,
+            (Active | Paused, Completed) => actor == StatusActor::Settlement,
+            (Cancelled, Active) => actor == StatusActor::Platform,
+            _ => false,
+        };
+        require!(allowed, ForestLabError::InvalidStatusTransition);
+        Ok(())
     }
 }
 
+/// 민트별 플랫폼 수수료 장부 항목
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FeeLedgerEntry {
+    /// 수수료 토큰 민트 주소
+    pub mint: Pubkey,
+    /// 누적 징수 금액
+    pub collected: u64,
+    /// 누적 출금 금액
+    pub withdrawn: u64,
+}
+
+/// 프로젝트별 락업 티어 항목
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LockupTier {
+    /// 티어 적용 최소 락업 기간 (초)
+    pub min_duration: i64,
+    /// 연 보너스 비율 (100 = 1%)
+    pub bonus_bps: u16,
+    /// 티어에 락업 가능한 최대 LST 금액 (0 = 무제한)
+    pub max_capacity: u64,
+    /// 티어에 락업되어 있는 LST 금액
+    pub used_capacity: u64,
+}
+
+/// 투표권 계산용 락업 포인트
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LockPoint {
+    /// 락업 포지션 인덱스
+    pub index: u64,
+    /// 락업된 LST 금액
+    pub amount: u64,
+    /// 락업 시작 시간 (Unix timestamp)
+    pub start_time: i64,
+    /// 락업 종료 시간 (Unix timestamp)
+    pub end_time: i64,
+    /// 이 락업 상태가 반영된 시간 (Unix timestamp)
+    pub updated_at: i64,
+}
+
+/// 연장, 추가 예치, 해제로 대체된 과거 락업 포인트
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LockHistoryPoint {
+    /// 대체되기 전 락업 포인트
+    pub lock: LockPoint,
+    /// 대체된 시간 (Unix timestamp)
+    pub retired_at: i64,
+}
+
+/// 스테이킹 수량 변경 기록
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct StakePoint {
+    /// 변경 시간 (Unix timestamp)
+    pub timestamp: i64,
+    /// 변경 직전 LST 수량
+    pub amount_before: u64,
+}
+
+/// 락업 티어 설정 파라미터
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LockupTierParams {
+    /// 티어 적용 최소 락업 기간 (초)
+    pub min_duration: i64,
+    /// 연 보너스 비율 (100 = 1%)
+    pub bonus_bps: u16,
+    /// 티어에 락업 가능한 최대 LST 금액 (0 = 무제한)
+    pub max_capacity: u64,
+}
+
 /// 플랫폼 정보 저장 구조체
 #[account]
 #[derive(Default)]
@@ -42,7 +189,7 @@ pub struct Platform {
     pub platform_fee: u16,
     /// 최소 스테이킹 금액 (lamports)
     pub min_stake_amount: u64,
-    /// 플랫폼 활성화 상태
+    /// 플랫폼 활성화 상태 (false이면 전체 작업 중지)
     pub is_active: bool,
     /// 총 프로젝트 수
     pub total_projects: u64,
@@ -50,6 +197,26 @@ pub struct Platform {
     pub total_staked_sol: u64,
     /// 생성 시간 (Unix timestamp)
     pub created_at: i64,
+    /// 민트별 수수료 징수 장부 (최대 MAX_FEE_MINTS개)
+    pub fees_collected: Vec<FeeLedgerEntry>,
+    /// 수락 대기 중인 새 플랫폼 관리자
+    pub pending_authority: Option<Pubkey>,
+    /// 작업별 일시 중지 비트마스크 (PAUSE_* 상수)
+    pub paused_operations: u8,
+    /// 설정 변경 적용 대기 시간 (초)
+    pub settings_timelock: i64,
+    /// 대기 중인 플랫폼 수수료 변경
+    pub pending_platform_fee: Option<u16>,
+    /// 플랫폼 수수료 변경이 실행 가능해지는 시간 (Unix timestamp)
+    pub pending_platform_fee_effective_at: i64,
+    /// 대기 중인 관리자 지갑 변경
+    pub pending_admin_wallet: Option<Pubkey>,
+    /// 관리자 지갑 변경이 실행 가능해지는 시간 (Unix timestamp)
+    pub pending_admin_wallet_effective_at: i64,
+    /// 대기 중인 대기 시간 변경
+    pub pending_settings_timelock: Option<i64>,
+    /// 대기 시간 변경이 실행 가능해지는 시간 (Unix timestamp)
+    pub pending_settings_timelock_effective_at: i64,
     /// PDA 범프
     pub bump: u8,
 }
@@ -96,6 +263,32 @@ pub struct Project {
     pub apy_estimate: u16,
     /// 총 분배된 보상 양
     pub total_rewards_distributed: u64,
+    /// 스테이킹된 총 LST 수량 (보상 분배 기준)
+    pub total_staked_lst: u64,
+    /// LST 1단위당 누적 보상 (REWARD_PRECISION 배율)
+    pub acc_reward_per_share: u128,
+    /// 해제되지 않은 락업에 약속된 보너스 총액 (LST)
+    pub bonus_committed: u64,
+    /// 생성된 거버넌스 제안 수
+    pub proposal_count: u64,
+    /// 펀딩 시작 시간 (Unix timestamp)
+    pub start_time: i64,
+    /// 최대 모금 한도 (lamports, 0 = 한도 없음)
+    pub max_raise: u64,
+    /// 펀딩 시작 경계 통과 여부
+    pub campaign_started: bool,
+    /// 펀딩 종료 경계 통과 여부 (종료 시간 경과 또는 최대 모금 한도 도달)
+    pub campaign_ended: bool,
+    /// 마지막 상태 변경 사유
+    pub status_reason: StatusReason,
+    /// 마지막 상태 변경 시간 (Unix timestamp)
+    pub status_updated_at: i64,
+    /// 마지막 상태 변경 주체
+    pub status_actor: StatusActor,
+    /// 프로젝트 관리자 주소 (PDA 시드의 creator와 별개로 이전 가능)
+    pub authority: Pubkey,
+    /// 수락 대기 중인 새 프로젝트 관리자
+    pub pending_authority: Option<Pubkey>,
     /// PDA 범프
     pub bump: u8,
 }
@@ -120,6 +313,14 @@ pub struct StakeInfo {
     pub rewards_claimed: u64,
     /// 마지막 보상 청구 시간
     pub last_claim_time: i64,
+    /// 이미 정산된 누적 보상 기준값 (REWARD_PRECISION 배율)
+    pub reward_debt: u128,
+    /// 정산되었지만 아직 청구하지 않은 보상
+    pub pending_rewards: u64,
+    /// LST 수량 변경 기록 (스냅샷 시점 수량 조회용)
+    pub stake_history: Vec<StakePoint>,
+    /// 기록에서 밀려난 가장 최근 변경 시간 (이보다 이른 시점은 조회 불가)
+    pub history_floor: i64,
     /// PDA 범프
     pub bump: u8,
 }
@@ -138,6 +339,8 @@ pub struct RewardsInfo {
     pub platform_fee: u64,
     /// 프로젝트 배분 금액 (LST)
     pub project_rewards: u64,
+    /// 스왑 대상 프로젝트 몫 (LST, 프로젝트 몫 볼트에 보관)
+    pub swap_amount: u64,
     /// 처리 완료 여부
     pub processed: bool,
     /// 스왑된 총 금액 (USDC)
@@ -146,12 +349,47 @@ pub struct RewardsInfo {
     pub project_fee: u64,
     /// 프로젝트 배분 금액 (USDC)
     pub project_amount: u64,
+    /// 리스테이킹 대상 금액 (LST)
+    pub restake_amount: u64,
+    /// 리스테이킹 실행 여부
+    pub restaked: bool,
     /// 기록 시간 (Unix timestamp)
     pub timestamp: i64,
     /// PDA 범프
     pub bump: u8,
 }
 
+/// 에포크별 보상 요약 (보상 히스토리 링 버퍼 항목)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EpochRewardSummary {
+    /// 에포크 번호
+    pub epoch: u64,
+    /// 총 보상 금액 (LST)
+    pub total_rewards: u64,
+    /// 플랫폼 수수료 금액 (LST)
+    pub platform_fee: u64,
+    /// 프로젝트 배분 금액 (LST)
+    pub project_rewards: u64,
+}
+
+/// 프로젝트별 에포크 보상 히스토리 (링 버퍼)
+#[account]
+#[derive(Default)]
+pub struct RewardsHistory {
+    /// 프로젝트 주소
+    pub project: Pubkey,
+    /// 최근 에포크 보상 요약 목록 (최대 MAX_ENTRIES개)
+    pub entries: Vec<EpochRewardSummary>,
+    /// 다음에 기록할 위치
+    pub head: u8,
+    /// 마지막으로 처리된 에포크 번호
+    pub last_epoch: u64,
+    /// 처리된 총 에포크 수
+    pub total_epochs: u64,
+    /// PDA 범프
+    pub bump: u8,
+}
+
 /// LST 락업 정보 저장 구조체
 #[account]
 #[derive(Default)]
@@ -174,6 +412,80 @@ pub struct Lockup {
     pub release_time: i64,
     /// 보너스 비율 (100 = 1%)
     pub bonus_percentage: u16,
+    /// 해제 시 지급할 보너스 금액 (LST)
+    pub bonus_amount: u64,
+    /// 보너스 계산용 누적 락업량 (금액 × 락업 초)
+    pub amount_seconds: u128,
+    /// 적용된 티어의 최소 락업 기간 (티어 식별자, 0 = 티어 없음)
+    pub tier_min_duration: i64,
+    /// 사용자별 락업 포지션 인덱스
+    pub index: u64,
+    /// PDA 범프
+    pub bump: u8,
+}
+
+/// 사용자별 락업 포지션 카운터 저장 구조체
+#[account]
+#[derive(Default)]
+pub struct LockupCounter {
+    /// 사용자 주소
+    pub user: Pubkey,
+    /// 프로젝트 주소
+    pub project: Pubkey,
+    /// 다음 락업 포지션 인덱스 (생성된 총 락업 수)
+    pub next_index: u64,
+    /// 해제되지 않은 락업 수
+    pub active_count: u64,
+    /// PDA 범프
+    pub bump: u8,
+}
+
+/// 프로젝트별 락업 티어 설정 저장 구조체
+#[account]
+#[derive(Default)]
+pub struct LockupTierConfig {
+    /// 프로젝트 주소
+    pub project: Pubkey,
+    /// 최소 기간 오름차순으로 정렬된 티어 목록
+    pub tiers: Vec<LockupTier>,
+    /// 허용되는 최대 락업 기간 (초)
+    pub max_duration: i64,
+    /// 조기 해제 최대 페널티 비율 (남은 기간에 비례, 100 = 1%)
+    pub early_exit_penalty_bps: u16,
+    /// PDA 범프
+    pub bump: u8,
+}
+
+/// 사용자별 락업 기반 투표권 체크포인트 저장 구조체
+#[account]
+#[derive(Default)]
+pub struct VotingPower {
+    /// 사용자 주소
+    pub user: Pubkey,
+    /// 프로젝트 주소
+    pub project: Pubkey,
+    /// 해제되지 않은 락업 포인트 목록
+    pub locks: Vec<LockPoint>,
+    /// 대체된 과거 락업 포인트 목록 (스냅샷 시점 투표권 조회용)
+    pub history: Vec<LockHistoryPoint>,
+    /// 기록에서 밀려난 가장 최근 대체 시간 (이보다 이른 시점은 조회 불가)
+    pub history_floor: i64,
+    /// 마지막 체크포인트의 투표권
+    pub last_voting_power: u64,
+    /// 마지막 체크포인트 시간 (Unix timestamp)
+    pub last_checkpoint_time: i64,
+    /// PDA 범프
+    pub bump: u8,
+}
+
+/// 플랫폼 역할 저장 구조체
+#[account]
+#[derive(Default)]
+pub struct Roles {
+    /// 연결된 플랫폼 주소
+    pub platform: Pubkey,
+    /// 역할을 부여받은 주소 목록
+    pub members: Vec<RoleAssignment>,
     /// PDA 범프
     pub bump: u8,
 }
@@ -212,6 +524,32 @@ pub struct RestakeConfig {
     pub bump: u8,
 }
 
+/// 리스테이킹 포지션 저장 구조체 (프로젝트 및 타겟 LST 민트별)
+#[account]
+#[derive(Default)]
+pub struct RestakePosition {
+    /// 프로젝트 주소
+    pub project: Pubkey,
+    /// 타겟 LST 민트 주소
+    pub target_lst_mint: Pubkey,
+    /// 리스테이킹에 투입된 소스 LST 누적량
+    pub source_lst_restaked: u64,
+    /// 수령한 타겟 LST 누적량
+    pub target_lst_received: u64,
+    /// 투입 시점 기준 원금 가치 (lamports)
+    pub principal_lamports: u64,
+    /// 마지막 평가 가치 (lamports)
+    pub last_valuation_lamports: u64,
+    /// 리스테이킹 수익 (lamports)
+    pub yield_lamports: u64,
+    /// 마지막으로 리스테이킹된 에포크
+    pub last_restaked_epoch: u64,
+    /// 리스테이킹 실행 횟수
+    pub restake_count: u64,
+    /// PDA 범프
+    pub bump: u8,
+}
+
 /// 멀티시그 설정 저장 구조체
 #[account]
 #[derive(Default)]
@@ -224,6 +562,192 @@ pub struct MultisigConfig {
     pub threshold: u8,
     /// 활성화 상태
     pub is_active: bool,
+    /// 생성된 제안 수 (다음 제안 인덱스)
+    pub proposal_count: u64,
+    /// PDA 범프
+    pub bump: u8,
+}
+
+/// 플랫폼 운영 역할
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Role {
+    /// 최고 관리자 (모든 역할 포함)
+    SuperAdmin,
+    /// 수수료 관리자 (플랫폼 수수료 변경 및 출금)
+    FeeManager,
+    /// 보상 운영자 (에포크 보상 처리, 스왑, 리스테이킹)
+    RewardOperator,
+    /// 일시 중지 권한자
+    Pauser,
+    /// 프로젝트 중재자 (프로젝트 상태 변경)
+    ProjectModerator,
+}
+
+impl Role {
+    /// 역할 비트마스크 값
+    pub fn bit(self) -> u8 {
+        1 << (self as u8)
+    }
+}
+
+/// 주소별 역할 비트마스크 항목
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RoleAssignment {
+    /// 역할을 부여받은 주소
+    pub member: Pubkey,
+    /// 부여된 역할 비트마스크
+    pub roles: u8,
+}
+
+/// 멀티시그 제안으로 실행할 프로젝트 작업
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum ProjectAction {
+    /// 프로젝트 수수료 및 지불 지갑 변경
+    UpdateFee {
+        manager_fee_percentage: u16,
+        payout_wallet: Option<Pubkey>,
+    },
+    /// 리스테이킹 설정
+    SetupRestaking {
+        target_lst_mint: Pubkey,
+        restake_percentage: u16,
+    },
+    /// 프로젝트 상태 변경
+    UpdateStatus {
+        new_status: ProjectStatus,
+        reason: StatusReason,
+    },
+    /// 멀티시그 서명자 추가
+    AddSigner {
+        signer: Pubkey,
+    },
+    /// 멀티시그 서명자 제거
+    RemoveSigner {
+        signer: Pubkey,
+    },
+    /// 멀티시그 임계값 변경
+    ChangeThreshold {
+        threshold: u8,
+    },
+    /// 리스테이킹 볼트에서 지불 지갑으로 출금
+    WithdrawRestake {
+        target_mint: Pubkey,
+        amount: u64,
+    },
+}
+
+/// 멀티시그 제안 저장 구조체
+#[account]
+pub struct MultisigProposal {
+    /// 멀티시그 설정 주소
+    pub multisig_config: Pubkey,
+    /// 프로젝트 주소
+    pub project: Pubkey,
+    /// 제안자 주소
+    pub proposer: Pubkey,
+    /// 제안 인덱스
+    pub index: u64,
+    /// 실행할 작업
+    pub action: ProjectAction,
+    /// 승인한 서명자 목록
+    pub approvals: Vec<Pubkey>,
+    /// 실행 여부
+    pub executed: bool,
+    /// 생성 시간 (Unix timestamp)
+    pub created_at: i64,
+    /// 실행 시간 (실행된 경우)
+    pub executed_at: i64,
+    /// PDA 범프
+    pub bump: u8,
+}
+
+/// 프로젝트 거버넌스 제안 저장 구조체
+#[account]
+pub struct ProjectProposal {
+    /// 프로젝트 주소
+    pub project: Pubkey,
+    /// 제안자 주소
+    pub proposer: Pubkey,
+    /// 제안 인덱스
+    pub index: u64,
+    /// 제안 제목
+    pub title: String,
+    /// 제안 상세 설명 URI
+    pub description_uri: String,
+    /// 투표 선택지 목록
+    pub options: Vec<String>,
+    /// 선택지별 득표 가중치
+    pub votes: Vec<u64>,
+    /// 전체 투표 가중치
+    pub total_weight: u64,
+    /// 투표권 스냅샷 시간 (제안 생성 시점)
+    pub snapshot_time: i64,
+    /// 투표 시작 시간 (Unix timestamp)
+    pub voting_start: i64,
+    /// 투표 종료 시간 (Unix timestamp)
+    pub voting_end: i64,
+    /// 결과 확정 여부
+    pub finalized: bool,
+    /// 최다 득표 선택지 (득표가 없으면 None)
+    pub winning_option: Option<u8>,
+    /// 결과 확정 시간 (확정된 경우)
+    pub finalized_at: i64,
+    /// PDA 범프
+    pub bump: u8,
+}
+
+/// 투표 가중치 출처
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum VoteWeightSource {
+    /// 스테이킹된 LST 수량
+    #[default]
+    Stake,
+    /// 락업 기반 투표권
+    Lockup,
+}
+
+/// 거버넌스 투표 기록 저장 구조체
+#[account]
+#[derive(Default)]
+pub struct VoteRecord {
+    /// 제안 주소
+    pub proposal: Pubkey,
+    /// 투표자 주소
+    pub voter: Pubkey,
+    /// 선택한 선택지 인덱스
+    pub option: u8,
+    /// 투표 가중치
+    pub weight: u64,
+    /// 가중치 출처
+    pub source: VoteWeightSource,
+    /// 투표 시간 (Unix timestamp)
+    pub voted_at: i64,
+    /// PDA 범프
+    pub bump: u8,
+}
+
+/// 스왑 어댑터 종류
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SwapAdapterKind {
+    /// SPL Token Swap (Constant Product 커브)
+    #[default]
+    SplTokenSwap,
+}
+
+/// 보상 스왑 설정 저장 구조체
+#[account]
+#[derive(Default)]
+pub struct SwapConfig {
+    /// 연결된 플랫폼 주소
+    pub platform: Pubkey,
+    /// 스왑 프로그램 주소
+    pub swap_program: Pubkey,
+    /// 스왑 어댑터 종류
+    pub adapter: SwapAdapterKind,
+    /// USDC 민트 주소
+    pub usdc_mint: Pubkey,
+    /// 스테이커 보상 중 USDC 스왑용 프로젝트 몫으로 떼어두는 비율 (100 = 1%)
+    pub project_share_bps: u16,
     /// PDA 범프
     pub bump: u8,
 }
@@ -238,8 +762,100 @@ impl Platform {
                              8 +   // total_projects
                              8 +   // total_staked_sol
                              8 +   // created_at
+                             4 + (48 * Self::MAX_FEE_MINTS) + // fees_collected (최대 8개)
+                             33 +  // pending_authority
+                             1 +   // paused_operations
+                             8 +   // settings_timelock
+                             3 +   // pending_platform_fee
+                             8 +   // pending_platform_fee_effective_at
+                             33 +  // pending_admin_wallet
+                             8 +   // pending_admin_wallet_effective_at
+                             9 +   // pending_settings_timelock
+                             8 +   // pending_settings_timelock_effective_at
                              1 +   // bump
                              64;   // 여유 공간
+
+    pub const MAX_FEE_MINTS: usize = 8;
+
+    /// 스테이킹 일시 중지
+    pub const PAUSE_STAKE: u8 = 1 << 0;
+    /// 언스테이킹 일시 중지
+    pub const PAUSE_UNSTAKE: u8 = 1 << 1;
+    /// 락업 생성/변경/해제 일시 중지
+    pub const PAUSE_LOCKUP: u8 = 1 << 2;
+    /// 보상 청구 일시 중지
+    pub const PAUSE_CLAIM: u8 = 1 << 3;
+    /// 보상 처리(에포크 보상, 스왑, 리스테이킹) 일시 중지
+    pub const PAUSE_REWARDS: u8 = 1 << 4;
+    /// 프로젝트 등록 일시 중지
+    pub const PAUSE_REGISTRATION: u8 = 1 << 5;
+    /// 플랫폼 수수료 출금 일시 중지
+    pub const PAUSE_FEES: u8 = 1 << 6;
+    /// 펀딩 기간 종료 정산 일시 중지
+    pub const PAUSE_SETTLEMENT: u8 = 1 << 7;
+    /// 전체 작업 (is_active가 false이면 전체 작업이 중지된 것으로 간주)
+    pub const PAUSE_ALL: u8 = u8::MAX;
+    // 작업 비트가 없는 프로젝트 관리 작업(수수료/상태/리스테이킹/멀티시그 설정, 멀티시그 제안,
+    // 락업 티어 설정, 거버넌스 제안/투표/확정, 프로젝트 권한 이전)은 전체 중지에서만 차단 (require_active)
+    // 예외: 플랫폼 관리 작업(설정 변경, 역할 관리, 일시 중지 해제, 플랫폼 권한 이전), 권한 이전 취소,
+    // 플랫폼 중재자의 프로젝트 상태 변경, migrate_* (중지 중에도 레거시 계정 복구가 가능해야 함)
+
+    /// 기본 설정 변경 대기 시간 (2일)
+    pub const DEFAULT_SETTINGS_TIMELOCK: i64 = 2 * 86400;
+    /// 최대 설정 변경 대기 시간 (30일)
+    pub const MAX_SETTINGS_TIMELOCK: i64 = 30 * 86400;
+
+    /// 대기 중인 설정 변경이 있는지 여부
+    pub fn has_pending_settings(&self) -> bool {
+        self.pending_platform_fee.is_some()
+            || self.pending_admin_wallet.is_some()
+            || self.pending_settings_timelock.is_some()
+    }
+
+    /// 대기 중인 설정 변경 초기화
+    pub fn clear_pending_settings(&mut self) {
+        self.pending_platform_fee = None;
+        self.pending_platform_fee_effective_at = 0;
+        self.pending_admin_wallet = None;
+        self.pending_admin_wallet_effective_at = 0;
+        self.pending_settings_timelock = None;
+        self.pending_settings_timelock_effective_at = 0;
+    }
+
+    /// 플랫폼이 활성 상태인지 확인 (작업 비트가 없는 프로젝트 관리 작업용)
+    pub fn require_active(&self) -> Result<()> {
+        require!(self.is_active, ForestLabError::PlatformInactive);
+        Ok(())
+    }
+
+    /// 해당 작업이 일시 중지되지 않았는지 확인 (플랫폼 비활성화는 전체 중지)
+    pub fn require_not_paused(&self, operation: u8) -> Result<()> {
+        self.require_active()?;
+        require!(
+            self.paused_operations & operation == 0,
+            ForestLabError::OperationPaused
+        );
+        Ok(())
+    }
+
+    /// 민트별 수수료 장부 항목 조회 (없으면 새로 추가)
+    pub fn fee_ledger_mut(&mut self, mint: Pubkey) -> Result<&mut FeeLedgerEntry> {
+        let index = match self.fees_collected.iter().position(|entry| entry.mint == mint) {
+            Some(index) => index,
+            None => {
+                require!(
+                    self.fees_collected.len() < Self::MAX_FEE_MINTS,
+                    ForestLabError::FeeLedgerFull
+                );
+                self.fees_collected.push(FeeLedgerEntry {
+                    mint,
+                    ..Default::default()
+                });
+                self.fees_collected.len() - 1
+            }
+        };
+        Ok(&mut self.fees_collected[index])
+    }
 }
 
 impl Project {
@@ -263,8 +879,50 @@ impl Project {
                              32 +  // payout_wallet
                              2 +   // apy_estimate
                              8 +   // total_rewards_distributed
+                             8 +   // total_staked_lst
+                             16 +  // acc_reward_per_share
+                             8 +   // bonus_committed
+                             8 +   // proposal_count
+                             8 +   // start_time
+                             8 +   // max_raise
+                             1 +   // campaign_started
+                             1 +   // campaign_ended
+                             1 +   // status_reason
+                             8 +   // status_updated_at
+                             1 +   // status_actor
+                             32 +  // authority
+                             33 +  // pending_authority
                              1 +   // bump
                              100;  // 여유 공간
+
+    /// 누적 보상 계산 배율
+    pub const REWARD_PRECISION: u128 = 1_000_000_000_000;
+
+    /// 프로젝트 관리자 여부 (관리자 필드가 비어 있는 기존 프로젝트는 생성자)
+    pub fn is_authority(&self, key: &Pubkey) -> bool {
+        if self.authority == Pubkey::default() {
+            self.creator == *key
+        } else {
+            self.authority == *key
+        }
+    }
+
+    /// 최대 모금 한도 도달 여부
+    pub fn is_max_raise_reached(&self) -> bool {
+        self.max_raise > 0 && self.funds_raised >= self.max_raise
+    }
+
+    /// 에포크 보상을 스테이커 누적 보상에 반영하고 실제 분배된 양을 반환
+    pub fn distribute_rewards(&mut self, rewards: u64) -> u64 {
+        if self.total_staked_lst == 0 || rewards == 0 {
+            return 0;
+        }
+        let increment = (rewards as u128)
+            .saturating_mul(Self::REWARD_PRECISION)
+            / self.total_staked_lst as u128;
+        self.acc_reward_per_share = self.acc_reward_per_share.saturating_add(increment);
+        rewards
+    }
 }
 
 impl StakeInfo {
@@ -277,8 +935,66 @@ impl StakeInfo {
                              8 +   // last_stake_time
                              8 +   // rewards_claimed
                              8 +   // last_claim_time
+                             16 +  // reward_debt
+                             8 +   // pending_rewards
+                             4 + (16 * Self::MAX_STAKE_HISTORY) + // stake_history (최대 16개)
+                             8 +   // history_floor
                              1 +   // bump
                              32;   // 여유 공간
+
+    pub const MAX_STAKE_HISTORY: usize = 16;
+
+    /// LST 수량 변경 직전 수량을 기록 (가득 차면 가장 오래된 기록을 밀어냄)
+    pub fn record_stake_change(&mut self, timestamp: i64) {
+        if self.stake_history.len() >= Self::MAX_STAKE_HISTORY {
+            let dropped = self.stake_history.remove(0);
+            self.history_floor = dropped.timestamp;
+        }
+        self.stake_history.push(StakePoint {
+            timestamp,
+            amount_before: self.current_lst_amount,
+        });
+    }
+
+    /// 특정 시점의 LST 수량 (그 이후 첫 변경 직전 수량, 기록이 밀려났으면 None)
+    pub fn lst_amount_at(&self, timestamp: i64) -> Option<u64> {
+        if timestamp < self.history_floor {
+            return None;
+        }
+        Some(
+            self.stake_history
+                .iter()
+                .find(|point| point.timestamp > timestamp)
+                .map_or(self.current_lst_amount, |point| point.amount_before),
+        )
+    }
+
+    /// 현재 누적값 기준 아직 정산되지 않은 보상
+    fn unsettled_rewards(&self, acc_reward_per_share: u128) -> u64 {
+        let accrued = (self.current_lst_amount as u128)
+            .saturating_mul(acc_reward_per_share)
+            / Project::REWARD_PRECISION;
+        accrued.saturating_sub(self.reward_debt) as u64
+    }
+
+    /// 청구 가능한 총 보상 (정산된 보상 + 미정산 보상)
+    pub fn claimable_rewards(&self, acc_reward_per_share: u128) -> u64 {
+        self.pending_rewards
+            .saturating_add(self.unsettled_rewards(acc_reward_per_share))
+    }
+
+    /// 미정산 보상을 pending_rewards로 옮김 (LST 수량 변경 전 호출)
+    pub fn settle_rewards(&mut self, acc_reward_per_share: u128) {
+        self.pending_rewards = self.claimable_rewards(acc_reward_per_share);
+        self.reset_reward_debt(acc_reward_per_share);
+    }
+
+    /// 현재 LST 수량 기준으로 reward_debt 갱신 (LST 수량 변경 후 호출)
+    pub fn reset_reward_debt(&mut self, acc_reward_per_share: u128) {
+        self.reward_debt = (self.current_lst_amount as u128)
+            .saturating_mul(acc_reward_per_share)
+            / Project::REWARD_PRECISION;
+    }
 }
 
 impl RewardsInfo {
@@ -288,15 +1004,44 @@ impl RewardsInfo {
                              8 +   // total_rewards
                              8 +   // platform_fee
                              8 +   // project_rewards
+                             8 +   // swap_amount
                              1 +   // processed
                              8 +   // swapped_amount
                              8 +   // project_fee
                              8 +   // project_amount
+                             8 +   // restake_amount
+                             1 +   // restaked
                              8 +   // timestamp
                              1 +   // bump
                              32;   // 여유 공간
 }
 
+impl RewardsHistory {
+    pub const MAX_ENTRIES: usize = 32;
+
+    pub const SPACE: usize = 8 +    // 디스크리미네이터
+                             32 +   // project
+                             4 + (32 * Self::MAX_ENTRIES) + // entries (최대 32개)
+                             1 +    // head
+                             8 +    // last_epoch
+                             8 +    // total_epochs
+                             1 +    // bump
+                             32;    // 여유 공간
+
+    /// 에포크 요약을 기록 (가득 찬 경우 가장 오래된 항목을 덮어씀)
+    pub fn record(&mut self, summary: EpochRewardSummary) {
+        let head = self.head as usize;
+        if self.entries.len() < Self::MAX_ENTRIES {
+            self.entries.push(summary);
+        } else {
+            self.entries[head] = summary;
+        }
+        self.head = ((head + 1) % Self::MAX_ENTRIES) as u8;
+        self.last_epoch = summary.epoch;
+        self.total_epochs = self.total_epochs.saturating_add(1);
+    }
+}
+
 impl Lockup {
     pub const SPACE: usize = 8 +   // 디스크리미네이터
                              32 +  // user
@@ -308,1475 +1053,7482 @@ impl Lockup {
                              1 +   // is_released
                              8 +   // release_time
                              2 +   // bonus_percentage
+                             8 +   // bonus_amount
+                             16 +  // amount_seconds
+                             8 +   // tier_min_duration
+                             8 +   // index
                              1 +   // bump
                              32;   // 여유 공간
-}
 
-impl CrankInfo {
-    pub const SPACE: usize = 8 +    // 디스크리미네이터
-                             8 +    // last_executed_epoch
-                             8 +    // last_execution_time
-                             8 +    // execution_count
-                             4 + (32 * 10) + // authorized_crankers (최대 10개)
-                             1 +    // bump
-                             32;    // 여유 공간
+    /// 1년 (초)
+    pub const SECONDS_PER_YEAR: i64 = 365 * 86400;
+
+    /// 인덱스 없는 구버전 락업의 이벤트용 인덱스 값
+    pub const LEGACY_INDEX: u64 = u64::MAX;
+
+    /// 남은 락업 기간에 비례한 조기 해제 페널티 금액
+    pub fn early_exit_penalty(&self, now: i64, max_penalty_bps: u16) -> u64 {
+        let duration = self.end_time.saturating_sub(self.start_time);
+        if duration <= 0 {
+            return 0;
+        }
+        let remaining = self.end_time.saturating_sub(now).clamp(0, duration);
+        ((self.amount as u128)
+            .saturating_mul(max_penalty_bps as u128)
+            .saturating_mul(remaining as u128)
+            / (10000u128 * duration as u128)) as u64
+    }
+
+    /// 연 보너스 비율을 누적 락업량(금액 × 기간)에 적용한 보너스 금액
+    pub fn compute_bonus(amount_seconds: u128, bonus_percentage: u16) -> u64 {
+        (amount_seconds.saturating_mul(bonus_percentage as u128)
+            / (10000u128 * Self::SECONDS_PER_YEAR as u128)) as u64
+    }
 }
 
-impl RestakeConfig {
+impl LockupCounter {
     pub const SPACE: usize = 8 +   // 디스크리미네이터
+                             32 +  // user
                              32 +  // project
-                             32 +  // source_lst_mint
-                             32 +  // target_lst_mint
-                             2 +   // restake_percentage
-                             1 +   // is_active
+                             8 +   // next_index
+                             8 +   // active_count
                              1 +   // bump
                              32;   // 여유 공간
 }
 
-impl MultisigConfig {
-    pub const SPACE: usize = 8 +    // 디스크리미네이터
-                             32 +   // project
-                             4 + (32 * 10) + // signers (최대 10개)
-                             1 +    // threshold
-                             1 +    // is_active
-                             1 +    // bump
-                             32;    // 여유 공간
-}
+impl LockupTierConfig {
+    pub const SPACE: usize = 8 +   // 디스크리미네이터
+                             32 +  // project
+                             4 + (26 * Self::MAX_TIERS) + // tiers (최대 8개)
+                             8 +   // max_duration
+                             2 +   // early_exit_penalty_bps
+                             1 +   // bump
+                             32;   // 여유 공간
 
-#[error_code]
-pub enum ForestLabError {
-    #[msg("수수료 비율이 유효하지 않습니다")]
-    InvalidFeePercentage,
-    
-    #[msg("플랫폼이 비활성화되어 있습니다")]
-    PlatformInactive,
-    
-    #[msg("프로젝트 이름이 비어 있습니다")]
-    EmptyName,
+    pub const MAX_TIERS: usize = 8;
+    /// 설정 전 기본 최대 락업 기간 (4년)
+    pub const DEFAULT_MAX_DURATION: i64 = 4 * Lockup::SECONDS_PER_YEAR;
+    /// 설정 전 기본 조기 해제 최대 페널티 (50%)
+    pub const DEFAULT_EARLY_EXIT_PENALTY_BPS: u16 = 5000;
 
-    #[msg("프로젝트 심볼이 비어 있습니다")]
-    EmptySymbol,
-    
-    #[msg("펀딩 목표 금액이 유효하지 않습니다")]
-    InvalidFundingGoal,
-    
-    #[msg("펀딩 기간이 유효하지 않습니다")]
-    InvalidDuration,
-    
-    #[msg("프로젝트가 비활성화되어 있습니다")]
-    ProjectInactive,
-    
-    #[msg("최소 스테이킹 금액보다 작습니다")]
-    BelowMinimumStakeAmount,
-    
-    #[msg("에포크 보상이 이미 처리되었습니다")]
-    AlreadyProcessedForEpoch,
-    
-    #[msg("권한이 없습니다")]
-    Unauthorized,
-    
-    #[msg("유효하지 않은 금액입니다")]
-    InvalidAmount,
-    
-    #[msg("이미 처리되었습니다")]
-    AlreadyProcessed,
-    
-    #[msg("자금이 부족합니다")]
-    InsufficientFunds,
-    
-    #[msg("최소 락업 기간보다 짧습니다")]
-    LockupTooShort,
-    
-    #[msg("락업이 이미 해제되었습니다")]
-    AlreadyReleased,
-    
-    #[msg("락업 기간이 끝나지 않았습니다")]
-    LockupNotExpired,
-    
-    #[msg("비율이 유효하지 않습니다")]
-    InvalidPercentage,
-    
-    #[msg("서명자 수가 유효하지 않습니다")]
-    InvalidSignerCount,
-    
-    #[msg("서명자가 너무 많습니다 (최대 10명)")]
-    TooManySigners,
-    
-    #[msg("임계값이 유효하지 않습니다")]
-    InvalidThreshold,
-    
-    #[msg("임계값이 서명자 수보다 큽니다")]
-    ThresholdTooHigh,
+    /// 설정 전 기본 티어 (3개월 2.5%, 6개월 5%, 1년 10%, 한도 없음)
+    pub fn default_tiers() -> Vec<LockupTier> {
+        [(90, 250), (180, 500), (365, 1000)]
+            .iter()
+            .map(|&(days, bonus_bps)| LockupTier {
+                min_duration: days * 86400,
+                bonus_bps,
+                ..Default::default()
+            })
+            .collect()
+    }
 
-    #[msg("스테이킹 정보를 찾을 수 없습니다")]
-    StakeInfoNotFound,
+    /// 티어 목록, 최대 기간, 조기 해제 페널티 검증
+    pub fn validate(
+        tiers: &[LockupTierParams],
+        max_duration: i64,
+        early_exit_penalty_bps: u16,
+    ) -> Result<()> {
+        require!(
+            tiers.len() <= Self::MAX_TIERS,
+            ForestLabError::InvalidLockupTiers
+        );
+        require!(max_duration >= 86400, ForestLabError::InvalidLockupTiers);
+        require!(early_exit_penalty_bps <= 10000, ForestLabError::InvalidLockupTiers);
+        for (i, tier) in tiers.iter().enumerate() {
+            require!(
+                tier.min_duration >= 86400
+                    && tier.min_duration <= max_duration
+                    && tier.bonus_bps <= 10000,
+                ForestLabError::InvalidLockupTiers
+            );
+            if i > 0 {
+                require!(
+                    tier.min_duration > tiers[i - 1].min_duration,
+                    ForestLabError::InvalidLockupTiers
+                );
+            }
+        }
+        Ok(())
+    }
 
-    #[msg("LST 잔액이 부족합니다")]
-    InsufficientLstBalance,
-}
+    /// 락업 기간에 해당하는 티어 (최소 기간을 충족하는 가장 높은 티어)
+    pub fn select_tier(&self, duration: i64) -> Option<usize> {
+        self.tiers.iter().rposition(|tier| tier.min_duration <= duration)
+    }
 
-// 플랫폼 생성 이벤트
-#[event]
-pub struct PlatformCreatedEvent {
-    pub platform: Pubkey,
-    pub authority: Pubkey,
-    pub admin_wallet: Pubkey,
-    pub platform_fee: u16,
-    pub min_stake_amount: u64,
-}
+    /// 티어 한도 내에서 락업 금액 예약
+    pub fn reserve(&mut self, index: usize, amount: u64) -> Result<()> {
+        let tier = &mut self.tiers[index];
+        let used = tier.used_capacity.checked_add(amount).ok_or(ForestLabError::LockupTierFull)?;
+        require!(
+            tier.max_capacity == 0 || used <= tier.max_capacity,
+            ForestLabError::LockupTierFull
+        );
+        tier.used_capacity = used;
+        Ok(())
+    }
 
-// 프로젝트 등록 이벤트
-#[event]
-pub struct ProjectRegisteredEvent {
-    pub project: Pubkey,
-    pub creator: Pubkey,
-    pub platform: Pubkey,
-    pub name: String,
-    pub symbol: String,
-    pub funding_goal: u64,
-    pub end_time: i64,
-    pub lst_mint: Pubkey,
-    pub apy_estimate: u16,
+    /// 락업 해제 시 티어 사용량 반환 (최소 기간으로 티어 식별)
+    pub fn release(&mut self, min_duration: i64, amount: u64) {
+        if let Some(tier) = self.tiers.iter_mut().find(|tier| tier.min_duration == min_duration) {
+            tier.used_capacity = tier.used_capacity.saturating_sub(amount);
+        }
+    }
 }
 
-// 프로젝트 스테이킹 이벤트
-#[event]
-pub struct ProjectStakedEvent {
-    pub project: Pubkey,
-    pub user: Pubkey,
-    pub amount: u64,
-    pub lst_amount: u64,
-    pub is_new_supporter: bool,
-    pub timestamp: i64,
-}
+impl VotingPower {
+    pub const SPACE: usize = 8 +   // 디스크리미네이터
+                             32 +  // user
+                             32 +  // project
+                             4 + (40 * Self::MAX_LOCKS) + // locks (최대 16개)
+                             4 + (48 * Self::MAX_HISTORY) + // history (최대 16개)
+                             8 +   // history_floor
+                             8 +   // last_voting_power
+                             8 +   // last_checkpoint_time
+                             1 +   // bump
+                             32;   // 여유 공간
 
-// 프로젝트 언스테이킹 이벤트
-#[event]
-pub struct ProjectUnstakedEvent {
-    pub project: Pubkey,
-    pub user: Pubkey,
-    pub sol_amount: u64,
-    pub lst_amount: u64,
-    pub timestamp: i64,
-}
+    pub const MAX_LOCKS: usize = 16;
+    pub const MAX_HISTORY: usize = 16;
+    /// 최대 투표권을 받는 잔여 락업 기간 (4년)
+    pub const MAX_LOCK_TIME: i64 = 4 * Lockup::SECONDS_PER_YEAR;
 
-// 에포크 보상 처리 이벤트
-#[event]
-pub struct EpochRewardsProcessedEvent {
-    pub project: Pubkey,
-    pub epoch: u64,
-    pub total_rewards: u64,
-    pub platform_fee: u64,
-    pub project_rewards: u64,
-    pub timestamp: i64,
-}
+    /// 락업 상태를 락업 포인트 목록에 반영 (해제된 락업은 제거, 이전 상태는 기록으로 보관)
+    pub fn sync_lockup(&mut self, lockup: &Lockup, timestamp: i64) -> Result<()> {
+        let position = self.locks.iter().position(|lock| lock.index == lockup.index);
+        if let Some(position) = position {
+            self.retire(self.locks[position], timestamp);
+        }
+        if lockup.is_released {
+            if let Some(position) = position {
+                self.locks.swap_remove(position);
+            }
+            return Ok(());
+        }
 
-// 보상 분배 이벤트
-#[event]
-pub struct RewardsDistributedEvent {
-    pub project: Pubkey,
-    pub epoch: u64,
-    pub lst_amount: u64,
-    pub usdc_amount: u64,
-    pub project_fee: u64,
-    pub project_amount: u64,
-    pub timestamp: i64,
-}
+        let point = LockPoint {
+            index: lockup.index,
+            amount: lockup.amount,
+            start_time: lockup.start_time,
+            end_time: lockup.end_time,
+            updated_at: timestamp,
+        };
+        match position {
+            Some(position) => self.locks[position] = point,
+            None => {
+                require!(
+                    self.locks.len() < Self::MAX_LOCKS,
+                    ForestLabError::TooManyActiveLockups
+                );
+                self.locks.push(point);
+            }
+        }
+        Ok(())
+    }
 
-// 보상 청구 이벤트
-#[event]
-pub struct RewardsClaimedEvent {
-    pub project: Pubkey,
-    pub user: Pubkey,
-    pub reward_token_amount: u64,
-    pub timestamp: i64,
-}
+    /// 대체된 락업 포인트를 기록 (가득 차면 가장 오래된 기록을 밀어냄)
+    fn retire(&mut self, lock: LockPoint, timestamp: i64) {
+        if self.history.len() >= Self::MAX_HISTORY {
+            let dropped = self.history.remove(0);
+            self.history_floor = dropped.retired_at;
+        }
+        self.history.push(LockHistoryPoint {
+            lock,
+            retired_at: timestamp,
+        });
+    }
 
-// 프로젝트 설정 업데이트 이벤트
-#[event]
-pub struct ProjectSettingsUpdatedEvent {
-    pub project: Pubkey,
-    pub manager_fee_percentage: u16,
-    pub payout_wallet: Pubkey,
-    pub timestamp: i64,
-}
+    /// 특정 시점의 투표권 (금액 × 잔여 락업 기간 / 최대 기간, 선형 감소)
+    pub fn power_at(&self, timestamp: i64) -> u64 {
+        let current = self
+            .locks
+            .iter()
+            .filter(|lock| lock.updated_at <= timestamp);
+        let retired = self
+            .history
+            .iter()
+            .filter(|point| point.lock.updated_at <= timestamp && timestamp < point.retired_at)
+            .map(|point| &point.lock);
+        current
+            .chain(retired)
+            .filter(|lock| lock.start_time <= timestamp && timestamp < lock.end_time)
+            .map(|lock| {
+                let remaining = (lock.end_time - timestamp).min(Self::MAX_LOCK_TIME);
+                ((lock.amount as u128).saturating_mul(remaining as u128)
+                    / Self::MAX_LOCK_TIME as u128) as u64
+            })
+            .fold(0u64, |total, power| total.saturating_add(power))
+    }
 
-// 프로젝트 상태 업데이트 이벤트
-#[event]
-pub struct ProjectStatusUpdatedEvent {
-    pub project: Pubkey,
-    pub previous_status: ProjectStatus,
-    pub new_status: ProjectStatus,
-    pub updated_by: Pubkey,
-    pub timestamp: i64,
+    /// 스냅샷 시점의 투표권 (기록이 밀려났으면 None)
+    pub fn snapshot_power_at(&self, timestamp: i64) -> Option<u64> {
+        if timestamp < self.history_floor {
+            return None;
+        }
+        Some(self.power_at(timestamp))
+    }
 }
 
-// 락업 생성 이벤트
-#[event]
-pub struct LockupCreatedEvent {
-    pub lockup: Pubkey,
-    pub user: Pubkey,
-    pub project: Pubkey,
-    pub amount: u64,
-    pub start_time: i64,
-    pub end_time: i64,
-    pub bonus_percentage: u16,
-}
+impl ProjectProposal {
+    pub const SPACE: usize = 8 +   // 디스크리미네이터
+                             32 +  // project
+                             32 +  // proposer
+                             8 +   // index
+                             4 + Self::MAX_TITLE_LEN + // title
+                             4 + Self::MAX_URI_LEN +   // description_uri
+                             4 + ((4 + Self::MAX_OPTION_LEN) * Self::MAX_OPTIONS) + // options
+                             4 + (8 * Self::MAX_OPTIONS) + // votes
+                             8 +   // total_weight
+                             8 +   // snapshot_time
+                             8 +   // voting_start
+                             8 +   // voting_end
+                             1 +   // finalized
+                             2 +   // winning_option
+                             8 +   // finalized_at
+                             1 +   // bump
+                             32;   // 여유 공간
 
-// 락업 해제 이벤트
-#[event]
-pub struct LockupReleasedEvent {
-    pub lockup: Pubkey,
-    pub user: Pubkey,
-    pub project: Pubkey,
-    pub amount: u64,
-    pub release_time: i64,
-}
+    pub const MAX_TITLE_LEN: usize = 64;
+    pub const MAX_URI_LEN: usize = 200;
+    pub const MAX_OPTION_LEN: usize = 32;
+    pub const MAX_OPTIONS: usize = 8;
 
-// 크랭크 실행 이벤트
-#[event]
-pub struct CrankExecutedEvent {
-    pub project: Pubkey,
-    pub epoch: u64,
-    pub executor: Pubkey,
-    pub timestamp: i64,
+    /// 최다 득표 선택지 (동률이면 앞선 선택지, 득표가 없으면 None)
+    pub fn leading_option(&self) -> Option<u8> {
+        if self.total_weight == 0 {
+            return None;
+        }
+        self.votes
+            .iter()
+            .enumerate()
+            .fold(None, |best: Option<(usize, u64)>, (i, &weight)| match best {
+                Some((_, best_weight)) if best_weight >= weight => best,
+                _ => Some((i, weight)),
+            })
+            .map(|(i, _)| i as u8)
+    }
 }
 
-// 플랫폼 설정 업데이트 이벤트
-#[event]
-pub struct PlatformSettingsUpdatedEvent {
-    pub platform: Pubkey,
-    pub authority: Pubkey,
-    pub platform_fee: u16,
-    pub min_stake_amount: u64,
-    pub admin_wallet: Pubkey,
-    pub is_active: bool,
-    pub timestamp: i64,
+impl VoteRecord {
+    pub const SPACE: usize = 8 +   // 디스크리미네이터
+                             32 +  // proposal
+                             32 +  // voter
+                             1 +   // option
+                             8 +   // weight
+                             1 +   // source
+                             8 +   // voted_at
+                             1 +   // bump
+                             16;   // 여유 공간
 }
 
-// 크랭커 업데이트 이벤트
-#[event]
-pub struct CrankersUpdatedEvent {
-    pub platform: Pubkey,
-    pub authorized_crankers: Vec<Pubkey>,
-    pub timestamp: i64,
-}
+impl Roles {
+    pub const SPACE: usize = 8 +   // 디스크리미네이터
+                             32 +  // platform
+                             4 + (33 * Self::MAX_MEMBERS) + // members (최대 16개)
+                             1 +   // bump
+                             32;   // 여유 공간
 
-// 리스테이킹 설정 이벤트
-#[event]
-pub struct RestakingConfiguredEvent {
-    pub project: Pubkey,
-    pub source_lst_mint: Pubkey,
-    pub target_lst_mint: Pubkey,
-    pub restake_percentage: u16,
-    pub timestamp: i64,
-}
+    pub const MAX_MEMBERS: usize = 16;
 
-// 멀티시그 설정 이벤트
-#[event]
-pub struct MultisigConfiguredEvent {
-    pub project: Pubkey,
-    pub signers: Vec<Pubkey>,
-    pub threshold: u8,
-    pub timestamp: i64,
-}
+    /// 관리자 지갑에 기본으로 부여되는 역할 (보상 운영자, 프로젝트 중재자)
+    pub const ADMIN_WALLET_ROLES: [Role; 2] = [Role::RewardOperator, Role::ProjectModerator];
 
-/// 플랫폼 초기화를 위한 계정 구조체
-#[derive(Accounts)]
-#[instruction(platform_fee: u16, min_stake_amount: u64, admin_wallet: Pubkey)]
-pub struct InitializePlatform<'info> {
-    #[account(
-        init,
-        payer = payer,
-        space = Platform::SPACE,
-        seeds = [b"platform"],
-        bump,
-    )]
-    pub platform: Account<'info, Platform>,
-    
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    
-    pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
-}
+    /// 관리자 지갑 기본 역할 비트마스크
+    pub fn admin_wallet_mask() -> u8 {
+        Self::ADMIN_WALLET_ROLES.iter().fold(0, |mask, role| mask | role.bit())
+    }
 
-/// 프로젝트 등록을 위한 계정 구조체
-#[derive(Accounts)]
-#[instruction(
-    name: String,
-    symbol: String,
-    description: String,
-    website: String,
-    image_uri: String,
-    funding_goal: u64,
-    duration: i64,
-    lst_mint: Pubkey,
-    apy_estimate: u16
-)]
-pub struct RegisterProject<'info> {
-    #[account(
-        init,
-        payer = creator,
-        space = Project::SPACE,
-        seeds = [b"project", name.as_bytes(), creator.key().as_ref()],
-        bump,
-    )]
-    pub project: Account<'info, Project>,
-    
-    #[account(mut)]
-    pub creator: Signer<'info>,
-    
-    #[account(
-        mut,
-        seeds = [b"platform"], 
-        bump = platform.bump
-    )]
-    pub platform: Account<'info, Platform>,
-    
-    pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
-}
+    /// 역할 비트 추가 (처음 부여받는 주소는 목록에 추가)
+    pub fn grant(&mut self, member: Pubkey, mask: u8) -> Result<()> {
+        match self.members.iter_mut().find(|assignment| assignment.member == member) {
+            Some(assignment) => assignment.roles |= mask,
+            None => {
+                require!(self.members.len() < Self::MAX_MEMBERS, ForestLabError::RolesFull);
+                self.members.push(RoleAssignment { member, roles: mask });
+            }
+        }
+        Ok(())
+    }
 
-/// 프로젝트 스테이킹 기록을 위한 계정 구조체
-#[derive(Accounts)]
-#[instruction(amount: u64, lst_amount: u64)]
-pub struct RecordProjectStake<'info> {
-    #[account(mut, constraint = project.status == ProjectStatus::Active)]
-    pub project: Account<'info, Project>,
-    
-    #[account(mut)]
-    pub user: Signer<'info>,
-    
-    #[account(
-        mut,
-        seeds = [b"platform"], 
-        bump = platform.bump
-    )]
-    pub platform: Account<'info, Platform>,
-    
-    #[account(
-        init_if_needed,
-        payer = user,
-        space = StakeInfo::SPACE,
-        seeds = [b"stake_info", user.key().as_ref(), project.key().as_ref()],
-        bump,
-    )]
-    pub stake_info: Account<'info, StakeInfo>,
-    
-    pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
+    /// 역할 비트 제거 (남은 역할이 없으면 항목 삭제)
+    pub fn revoke(&mut self, member: &Pubkey, mask: u8) {
+        for assignment in self.members.iter_mut().filter(|assignment| assignment.member == *member) {
+            assignment.roles &= !mask;
+        }
+        self.members.retain(|assignment| assignment.roles != 0);
+    }
+
+    /// 역할 보유 여부 (최고 관리자는 모든 역할 보유)
+    pub fn has_role(&self, member: &Pubkey, role: Role) -> bool {
+        let mask = role.bit() | Role::SuperAdmin.bit();
+        self.members
+            .iter()
+            .any(|assignment| assignment.member == *member && assignment.roles & mask != 0)
+    }
 }
 
-/// 언스테이킹 기록을 위한 계정 구조체
-#[derive(Accounts)]
-#[instruction(amount: u64, lst_amount: u64)]
-pub struct RecordProjectUnstake<'info> {
-    #[account(mut)]
-    pub project: Account<'info, Project>,
-    
-    #[account(mut)]
-    pub user: Signer<'info>,
-    
-    #[account(
-        mut,
-        seeds = [b"platform"], 
-        bump = platform.bump
-    )]
-    pub platform: Account<'info, Platform>,
-    
-    #[account(
-        mut,
-        seeds = [b"stake_info", user.key().as_ref(), project.key().as_ref()],
-        bump = stake_info.bump,
-        constraint = stake_info.user == user.key() && stake_info.project == project.key()
-    )]
-    pub stake_info: Account<'info, StakeInfo>,
-    
-    pub system_program: Program<'info, System>,
+impl CrankInfo {
+    pub const SPACE: usize = 8 +    // 디스크리미네이터
+                             8 +    // last_executed_epoch
+                             8 +    // last_execution_time
+                             8 +    // execution_count
+                             4 + (32 * 10) + // authorized_crankers (최대 10개)
+                             1 +    // bump
+                             32;    // 여유 공간
 }
 
-/// 에포크 보상 처리를 위한 계정 구조체
-#[derive(Accounts)]
-#[instruction(epoch: u64, total_rewards: u64)]
-pub struct ProcessEpochRewards<'info> {
-    #[account(mut)]
-    pub project: Account<'info, Project>,
-    
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    
-    #[account(seeds = [b"platform"], bump = platform.bump)]
-    pub platform: Account<'info, Platform>,
-    
-    #[account(
-        init_if_needed,
-        payer = authority,
-        space = RewardsInfo::SPACE,
-        seeds = [b"rewards_info", project.key().as_ref()],
-        bump,
-    )]
-    pub rewards_info: Account<'info, RewardsInfo>,
-    
-    pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
+impl RestakeConfig {
+    pub const SPACE: usize = 8 +   // 디스크리미네이터
+                             32 +  // project
+                             32 +  // source_lst_mint
+                             32 +  // target_lst_mint
+                             2 +   // restake_percentage
+                             1 +   // is_active
+                             1 +   // bump
+                             32;   // 여유 공간
+
+    /// 프로젝트의 리스테이킹 PDA 계정에서 활성 리스테이킹 비율 조회 (미생성/비활성 시 0)
+    pub fn active_percentage_for(info: &AccountInfo) -> Result<u16> {
+        if info.owner != &crate::ID || info.data_is_empty() {
+            return Ok(0);
+        }
+        let data = info.try_borrow_data()?;
+        let restake_config = RestakeConfig::try_deserialize(&mut &data[..])?;
+        Ok(if restake_config.is_active { restake_config.restake_percentage } else { 0 })
+    }
 }
 
-/// 스왑 및 보상 분배를 위한 계정 구조체
-#[derive(Accounts)]
-#[instruction(reward_amount: u64, usdc_amount: u64)]
-pub struct SwapAndDistributeRewards<'info> {
-    #[account(mut)]
-    pub project: Account<'info, Project>,
-    
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    
-    #[account(seeds = [b"platform"], bump = platform.bump)]
-    pub platform: Account<'info, Platform>,
-    
-    #[account(
-        mut,
-        seeds = [b"rewards_info", project.key().as_ref()],
-        bump = rewards_info.bump,
-    )]
-    pub rewards_info: Account<'info, RewardsInfo>,
-    
-    pub system_program: Program<'info, System>,
+impl RestakePosition {
+    pub const SPACE: usize = 8 +   // 디스크리미네이터
+                             32 +  // project
+                             32 +  // target_lst_mint
+                             8 +   // source_lst_restaked
+                             8 +   // target_lst_received
+                             8 +   // principal_lamports
+                             8 +   // last_valuation_lamports
+                             8 +   // yield_lamports
+                             8 +   // last_restaked_epoch
+                             8 +   // restake_count
+                             1 +   // bump
+                             32;   // 여유 공간
 }
 
-/// 프로젝트 보상 청구를 위한 계정 구조체
-#[derive(Accounts)]
-#[instruction(reward_token_amount: u64)]
-pub struct ClaimProjectRewards<'info> {
-    #[account(
-        mut,
-        seeds = [b"stake_info", user.key().as_ref(), project.key().as_ref()],
-        bump = stake_info.bump,
-        constraint = stake_info.user == user.key() && stake_info.project == project.key()
-    )]
-    pub stake_info: Account<'info, StakeInfo>,
-    
-    #[account(mut)]
-    pub user: Signer<'info>,
-    
-    pub project: Account<'info, Project>,
-    
-    pub system_program: Program<'info, System>,
+impl MultisigConfig {
+    pub const SPACE: usize = 8 +    // 디스크리미네이터
+                             32 +   // project
+                             4 + (32 * Self::MAX_SIGNERS) + // signers (최대 10개)
+                             1 +    // threshold
+                             1 +    // is_active
+                             8 +    // proposal_count
+                             1 +    // bump
+                             32;    // 여유 공간
+
+    pub const MAX_SIGNERS: usize = 10;
+
+    /// 서명자 목록에 중복이 있는지 확인
+    pub fn has_duplicate_signers(signers: &[Pubkey]) -> bool {
+        signers
+            .iter()
+            .enumerate()
+            .any(|(i, key)| signers[..i].contains(key))
+    }
+
+    /// 서명자/임계값 변경 작업 검증 (다른 작업은 통과)
+    pub fn check_signer_change(&self, action: &ProjectAction) -> Result<()> {
+        match action {
+            ProjectAction::AddSigner { signer } => {
+                require!(!self.is_signer(signer), ForestLabError::DuplicateSigner);
+                require!(
+                    self.signers.len() < Self::MAX_SIGNERS,
+                    ForestLabError::TooManySigners
+                );
+            }
+            ProjectAction::RemoveSigner { signer } => {
+                require!(self.is_signer(signer), ForestLabError::NotMultisigSigner);
+                require!(self.signers.len() > 1, ForestLabError::InvalidSignerCount);
+                require!(
+                    (self.threshold as usize) < self.signers.len(),
+                    ForestLabError::ThresholdTooHigh
+                );
+            }
+            ProjectAction::ChangeThreshold { threshold } => {
+                require!(*threshold > 0, ForestLabError::InvalidThreshold);
+                require!(
+                    *threshold as usize <= self.signers.len(),
+                    ForestLabError::ThresholdTooHigh
+                );
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// 서명자/임계값 변경 작업 적용
+    pub fn apply_signer_change(&mut self, action: &ProjectAction) -> Result<()> {
+        self.check_signer_change(action)?;
+        match action {
+            ProjectAction::AddSigner { signer } => self.signers.push(*signer),
+            ProjectAction::RemoveSigner { signer } => self.signers.retain(|key| key != signer),
+            ProjectAction::ChangeThreshold { threshold } => self.threshold = *threshold,
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// 서명자 여부 확인
+    pub fn is_signer(&self, key: &Pubkey) -> bool {
+        self.signers.contains(key)
+    }
+
+    /// 현재 서명자 중 승인한 수
+    pub fn count_approvals(&self, approvals: &[Pubkey]) -> usize {
+        approvals.iter().filter(|key| self.signers.contains(key)).count()
+    }
+
+    /// 프로젝트의 멀티시그 PDA 계정이 활성화되어 있는지 확인 (미생성 시 false)
+    pub fn is_active_for(info: &AccountInfo) -> Result<bool> {
+        if info.owner != &crate::ID || info.data_is_empty() {
+            return Ok(false);
+        }
+        let data = info.try_borrow_data()?;
+        let multisig_config = MultisigConfig::try_deserialize(&mut &data[..])?;
+        Ok(multisig_config.is_active)
+    }
 }
 
-/// 프로젝트 설정 업데이트를 위한 계정 구조체
-#[derive(Accounts)]
-#[instruction(manager_fee_percentage: u16, payout_wallet: Option<Pubkey>)]
-pub struct UpdateProjectSettings<'info> {
-    #[account(
-        mut,
-        constraint = project.creator == authority.key(),
-    )]
-    pub project: Account<'info, Project>,
-    
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    
-    pub system_program: Program<'info, System>,
+impl MultisigProposal {
+    pub const SPACE: usize = 8 +    // 디스크리미네이터
+                             32 +   // multisig_config
+                             32 +   // project
+                             32 +   // proposer
+                             8 +    // index
+                             64 +   // action (최대 직렬화 크기)
+                             4 + (32 * 10) + // approvals (최대 10개)
+                             1 +    // executed
+                             8 +    // created_at
+                             8 +    // executed_at
+                             1 +    // bump
+                             32;    // 여유 공간
 }
 
-/// 프로젝트 상태 업데이트를 위한 계정 구조체
-#[derive(Accounts)]
-#[instruction(new_status: ProjectStatus)]
-pub struct UpdateProjectStatus<'info> {
-    #[account(mut)]
-    pub project: Account<'info, Project>,
-    
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    
-    #[account(seeds = [b"platform"], bump = platform.bump)]
-    pub platform: Account<'info, Platform>,
-    
-    pub system_program: Program<'info, System>,
+impl SwapConfig {
+    pub const SPACE: usize = 8 +   // 디스크리미네이터
+                             32 +  // platform
+                             32 +  // swap_program
+                             1 +   // adapter
+                             32 +  // usdc_mint
+                             2 +   // project_share_bps
+                             1 +   // bump
+                             32;   // 여유 공간
+
+    /// 스왑 설정 PDA 계정에서 프로젝트 몫 비율 조회 (미생성 시 0)
+    pub fn project_share_for(info: &AccountInfo) -> Result<u16> {
+        if info.owner != &crate::ID || info.data_is_empty() {
+            return Ok(0);
+        }
+        let data = info.try_borrow_data()?;
+        let swap_config = SwapConfig::try_deserialize(&mut &data[..])?;
+        Ok(swap_config.project_share_bps)
+    }
 }
 
-/// LST 락업 생성을 위한 계정 구조체
-#[derive(Accounts)]
-#[instruction(amount: u64, duration: i64)]
-pub struct CreateLockup<'info> {
-    #[account(constraint = project.status == ProjectStatus::Active)]
-    pub project: Account<'info, Project>,
-    
-    #[account(mut)]
-    pub user: Signer<'info>,
-    
-    #[account(
-        init,
-        payer = user,
-        space = Lockup::SPACE,
-        seeds = [b"lockup", user.key().as_ref(), project.key().as_ref()],
-        bump,
-    )]
-    pub lockup: Account<'info, Lockup>,
-    
-    #[account(mut)]
-    pub user_token_account: Account<'info, TokenAccount>,  // token_interface 대신 token 모듈 사용
-    
-    #[account(
-        init_if_needed,
-        payer = user,
-        associated_token::mint = lst_mint,
-        associated_token::authority = lockup,
-    )]
-    pub lockup_vault: Account<'info, TokenAccount>,  // token_interface 대신 token 모듈 사용
-    
-    pub lst_mint: Account<'info, Mint>,  // token_interface 대신 token 모듈 사용
-    
-    pub token_program: Program<'info, Token>,
-    pub associated_token_program: Program<'info, AssociatedToken>,
-    pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
+/// 구버전 플랫폼 계정 레이아웃 (마이그레이션 전, 필드 추가 이전)
+#[derive(AnchorDeserialize)]
+pub struct LegacyPlatform {
+    pub authority: Pubkey,
+    pub admin_wallet: Pubkey,
+    pub platform_fee: u16,
+    pub min_stake_amount: u64,
+    pub is_active: bool,
+    pub total_projects: u64,
+    pub total_staked_sol: u64,
+    pub created_at: i64,
+    pub bump: u8,
 }
 
-/// 락업 해제를 위한 계정 구조체
-#[derive(Accounts)]
-pub struct ReleaseLockup<'info> {
-    #[account(
-        mut,
-        seeds = [b"lockup", user.key().as_ref(), project.key().as_ref()],
-        bump = lockup.bump,
-        constraint = lockup.user == user.key(),
-        constraint = !lockup.is_released,
-    )]
-    pub lockup: Account<'info, Lockup>,
-    
-    #[account(mut)]
-    pub user: Signer<'info>,
-    
-    #[account(constraint = project.key() == lockup.project)]
-    pub project: Account<'info, Project>,
-    
-    #[account(mut)]
-    pub user_token_account: Account<'info, TokenAccount>,  // token_interface 대신 token 모듈 사용
-    
-    #[account(
-        mut,
-        associated_token::mint = lst_mint,
-        associated_token::authority = lockup,
-    )]
-    pub lockup_vault: Account<'info, TokenAccount>,  // token_interface 대신 token 모듈 사용
-    
-    pub lst_mint: Account<'info, Mint>,  // token_interface 대신 token 모듈 사용
-    
-    pub token_program: Program<'info, Token>,
-    pub system_program: Program<'info, System>,
+/// 구버전 프로젝트 계정 레이아웃 (마이그레이션 전, 보상 누적 및 펀딩 기간 필드 추가 이전)
+#[derive(AnchorDeserialize)]
+pub struct LegacyProject {
+    pub creator: Pubkey,
+    pub platform: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub description: String,
+    pub website: String,
+    pub image_uri: String,
+    pub funding_goal: u64,
+    pub funds_raised: u64,
+    pub supporters_count: u64,
+    pub lst_mint: Pubkey,
+    pub status: ProjectStatus,
+    pub created_at: i64,
+    pub end_time: i64,
+    pub funds_claimed: bool,
+    pub manager_fee_percentage: u16,
+    pub payout_wallet: Pubkey,
+    pub apy_estimate: u16,
+    pub total_rewards_distributed: u64,
+    pub bump: u8,
 }
 
-/// 크랭크 업데이트 실행을 위한 계정 구조체
-#[derive(Accounts)]
-#[instruction(epoch: u64)]
-pub struct ExecuteCrankUpdate<'info> {
-    #[account(mut)]
-    pub project: Account<'info, Project>,
-    
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    
-    #[account(seeds = [b"platform"], bump = platform.bump)]
-    pub platform: Account<'info, Platform>,
-    
-    #[account(
-        init_if_needed,
-        payer = authority,
-        space = CrankInfo::SPACE,
-        seeds = [b"crank_info", project.key().as_ref()],
-        bump,
-    )]
-    pub crank_info: Account<'info, CrankInfo>,
-    
-    pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
+/// 구버전 스테이킹 정보 계정 레이아웃 (마이그레이션 전, 보상 정산 필드 추가 이전)
+#[derive(AnchorDeserialize)]
+pub struct LegacyStakeInfo {
+    pub user: Pubkey,
+    pub project: Pubkey,
+    pub initial_stake_amount: u64,
+    pub current_lst_amount: u64,
+    pub first_stake_time: i64,
+    pub last_stake_time: i64,
+    pub rewards_claimed: u64,
+    pub last_claim_time: i64,
+    pub bump: u8,
 }
 
-/// 플랫폼 설정 업데이트를 위한 계정 구조체
-#[derive(Accounts)]
-#[instruction(
-    platform_fee: Option<u16>,
-    min_stake_amount: Option<u64>,
-    admin_wallet: Option<Pubkey>,
-    is_active: Option<bool>
-)]
-pub struct UpdatePlatformSettings<'info> {
-    #[account(
-        mut,
-        seeds = [b"platform"],
-        bump = platform.bump,
-        constraint = platform.authority == authority.key(),
-    )]
-    pub platform: Account<'info, Platform>,
-    
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    
-    pub system_program: Program<'info, System>,
+/// 구버전 락업 계정 레이아웃 (인덱스 없는 PDA, 보너스 및 티어 필드 추가 이전)
+#[derive(AnchorDeserialize)]
+pub struct LegacyLockup {
+    pub user: Pubkey,
+    pub project: Pubkey,
+    pub lst_mint: Pubkey,
+    pub amount: u64,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub is_released: bool,
+    pub release_time: i64,
+    pub bonus_percentage: u16,
+    pub bump: u8,
 }
 
-/// 크랭커 관리를 위한 계정 구조체
-#[derive(Accounts)]
-#[instruction(add_crankers: Vec<Pubkey>, remove_crankers: Vec<Pubkey>)]
-pub struct ManageCrankers<'info> {
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    
-    #[account(seeds = [b"platform"], bump = platform.bump)]
-    pub platform: Account<'info, Platform>,
-    
-    #[account(
-        init_if_needed,
-        payer = authority,
-        space = CrankInfo::SPACE,
-        seeds = [b"crankers"],
-        bump,
-    )]
-    pub crank_info: Account<'info, CrankInfo>,
-    
-    pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
+/// 구버전 에포크 보상 정보 계정 레이아웃 (마이그레이션 전, 리스테이킹 필드 추가 이전)
+#[derive(AnchorDeserialize)]
+pub struct LegacyRewardsInfo {
+    pub project: Pubkey,
+    pub epoch: u64,
+    pub total_rewards: u64,
+    pub platform_fee: u64,
+    pub project_rewards: u64,
+    pub processed: bool,
+    pub swapped_amount: u64,
+    pub project_fee: u64,
+    pub project_amount: u64,
+    pub timestamp: i64,
+    pub bump: u8,
 }
 
-/// 리스테이킹 설정을 위한 계정 구조체
-#[derive(Accounts)]
-#[instruction(target_lst_mint: Pubkey, restake_percentage: u16)]
-pub struct SetupRestaking<'info> {
-    #[account(mut)]
-    pub project: Account<'info, Project>,
-    
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    
-    #[account(
-        init_if_needed,
-        payer = authority,
-        space = RestakeConfig::SPACE,
-        seeds = [b"restake_config", project.key().as_ref()],
-        bump,
-    )]
-    pub restake_config: Account<'info, RestakeConfig>,
-    
-    pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
+/// 구버전 멀티시그 설정 계정 레이아웃 (마이그레이션 전, 제안 카운터 추가 이전)
+#[derive(AnchorDeserialize)]
+pub struct LegacyMultisigConfig {
+    pub project: Pubkey,
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+    pub is_active: bool,
+    pub bump: u8,
 }
 
-/// 멀티시그 관리 설정을 위한 계정 구조체
-#[derive(Accounts)]
-#[instruction(signers: Vec<Pubkey>, threshold: u8)]
-pub struct SetupMultisigManagement<'info> {
-    #[account(mut)]
-    pub project: Account<'info, Project>,
-    
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    
-    #[account(
-        init_if_needed,
-        payer = authority,
-        space = MultisigConfig::SPACE,
-        seeds = [b"multisig_config", project.key().as_ref()],
-        bump,
-    )]
-    pub multisig_config: Account<'info, MultisigConfig>,
-    
-    pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
+/// 구버전 레이아웃 계정 데이터를 읽어옴 (소유 프로그램, 디스크리미네이터, 크기 검증)
+fn load_legacy_account<T: AnchorDeserialize>(
+    account: &AccountInfo,
+    discriminator: &[u8],
+    current_space: usize,
+) -> Result<T> {
+    require_keys_eq!(*account.owner, crate::ID, ForestLabError::InvalidLegacyAccount);
+
+    let data = account.try_borrow_data()?;
+    require!(
+        data.len() >= 8 && &data[..8] == discriminator,
+        ForestLabError::InvalidLegacyAccount
+    );
+    // 현재 크기로 재할당된 계정은 이미 마이그레이션된 것으로 간주
+    require!(data.len() < current_space, ForestLabError::AccountAlreadyMigrated);
+
+    T::deserialize(&mut &data[8..]).map_err(|_| error!(ForestLabError::InvalidLegacyAccount))
+}
+
+/// 계정을 현재 크기로 재할당하고 부족한 렌트를 보충한 뒤 현재 레이아웃으로 다시 기록
+fn rewrite_migrated_account<'info, T: AccountSerialize>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    value: &T,
+) -> Result<()> {
+    let required_lamports = Rent::get()?.minimum_balance(space);
+    let shortfall = required_lamports.saturating_sub(account.lamports());
+    if shortfall > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                anchor_lang::system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+
+    account.realloc(space, true)?;
+
+    let mut data = account.try_borrow_mut_data()?;
+    let mut writer: &mut [u8] = &mut data;
+    value.try_serialize(&mut writer)?;
+    Ok(())
+}
+
+/// SPL Stake Pool 프로그램 ID (스테이크 풀 CPI 대상은 이 프로그램으로 고정)
+pub const SPL_STAKE_POOL_PROGRAM_ID: Pubkey = pubkey!("SPoo1Ku8WFXoNDMHPsrGSTSG1Y47rzgn41SLUNakuHy");
+
+/// SPL Stake Pool 명령어 태그 (StakePoolInstruction 열거형 순서)
+const STAKE_POOL_IX_WITHDRAW_STAKE: u8 = 10;
+const STAKE_POOL_IX_DEPOSIT_SOL: u8 = 14;
+const STAKE_POOL_IX_WITHDRAW_SOL: u8 = 16;
+
+/// SPL Stake Pool 계정에서 읽어온 검증용 정보
+pub struct StakePoolState {
+    /// 스테이크 출금 권한 PDA 범프
+    pub withdraw_bump: u8,
+    /// 검증자 목록 계정 주소
+    pub validator_list: Pubkey,
+    /// 리저브 스테이크 계정 주소
+    pub reserve_stake: Pubkey,
+    /// 풀 토큰(LST) 민트 주소
+    pub pool_mint: Pubkey,
+    /// 매니저 수수료 토큰 계정 주소
+    pub manager_fee_account: Pubkey,
+    /// 풀이 관리하는 총 lamports
+    pub total_lamports: u64,
+    /// 발행된 풀 토큰 총량
+    pub pool_token_supply: u64,
 }
 
-#[program]
-pub mod forest_lab {
-    use super::*;
+impl StakePoolState {
+    /// StakePool 계정 타입 값 (AccountType::StakePool)
+    const ACCOUNT_TYPE: u8 = 1;
+    /// 고정 헤더 길이 (account_type ~ last_update_epoch)
+    const HEADER_LEN: usize = 282;
+
+    /// 스테이크 풀 계정 데이터를 읽어옴 (소유 프로그램 검증 포함)
+    pub fn load(stake_pool: &AccountInfo, stake_pool_program: &Pubkey) -> Result<Self> {
+        require_keys_eq!(*stake_pool.owner, *stake_pool_program, ForestLabError::InvalidStakePool);
+
+        let data = stake_pool.try_borrow_data()?;
+        require!(
+            data.len() >= Self::HEADER_LEN && data[0] == Self::ACCOUNT_TYPE,
+            ForestLabError::InvalidStakePool
+        );
+
+        let read_pubkey = |offset: usize| Pubkey::new_from_array(data[offset..offset + 32].try_into().unwrap());
+        let read_u64 = |offset: usize| u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+
+        Ok(Self {
+            withdraw_bump: data[97],
+            validator_list: read_pubkey(98),
+            reserve_stake: read_pubkey(130),
+            pool_mint: read_pubkey(162),
+            manager_fee_account: read_pubkey(194),
+            total_lamports: read_u64(258),
+            pool_token_supply: read_u64(266),
+        })
+    }
+
+    /// 풀 토큰 수량의 SOL 가치 (lamports)
+    pub fn pool_tokens_to_lamports(&self, pool_tokens: u64) -> u64 {
+        if self.pool_token_supply == 0 {
+            return pool_tokens;
+        }
+        ((pool_tokens as u128)
+            .saturating_mul(self.total_lamports as u128)
+            / self.pool_token_supply as u128) as u64
+    }
+
+    /// 스테이크 풀 출금 권한 PDA 검증
+    pub fn check_withdraw_authority(
+        &self,
+        stake_pool: &Pubkey,
+        withdraw_authority: &Pubkey,
+        stake_pool_program: &Pubkey,
+    ) -> Result<()> {
+        let expected = Pubkey::create_program_address(
+            &[stake_pool.as_ref(), b"withdraw", &[self.withdraw_bump]],
+            stake_pool_program,
+        )
+        .map_err(|_| error!(ForestLabError::InvalidStakePool))?;
+        require_keys_eq!(expected, *withdraw_authority, ForestLabError::InvalidStakePool);
+        Ok(())
+    }
+}
+
+/// SPL Stake Pool 명령어 생성 (태그 + u64 인자)
+fn stake_pool_instruction(
+    stake_pool_program: &Pubkey,
+    tag: u8,
+    amount: u64,
+    accounts: Vec<AccountMeta>,
+) -> Instruction {
+    let mut data = Vec::with_capacity(9);
+    data.push(tag);
+    data.extend_from_slice(&amount.to_le_bytes());
+
+    Instruction {
+        program_id: *stake_pool_program,
+        accounts,
+        data,
+    }
+}
+
+/// 스테이킹 결과를 StakeInfo, Project, Platform에 반영하고 신규 지원자 여부를 반환
+fn apply_stake(
+    stake_info: &mut StakeInfo,
+    project: &mut Account<Project>,
+    platform: &mut Platform,
+    user: Pubkey,
+    amount: u64,
+    lst_amount: u64,
+    bump: u8,
+) -> Result<bool> {
+    let now = Clock::get()?.unix_timestamp;
+    let is_new_stake = stake_info.user == Pubkey::default();
+
+    // 기존 LST에 대한 보상 정산 및 변경 전 수량 기록
+    stake_info.settle_rewards(project.acc_reward_per_share);
+    stake_info.record_stake_change(now);
+
+    if is_new_stake {
+        // 새 스테이킹 기록 초기화
+        stake_info.user = user;
+        stake_info.project = project.key();
+        stake_info.initial_stake_amount = amount;
+        stake_info.current_lst_amount = lst_amount;
+        stake_info.first_stake_time = now;
+        stake_info.bump = bump;
+        stake_info.rewards_claimed = 0;
+        stake_info.last_claim_time = 0;
+        stake_info.pending_rewards = 0;
+    } else {
+        // 기존 스테이킹 기록 업데이트
+        stake_info.initial_stake_amount = stake_info.initial_stake_amount.saturating_add(amount);
+        stake_info.current_lst_amount = stake_info.current_lst_amount.saturating_add(lst_amount);
+    }
+
+    stake_info.last_stake_time = now;
+    stake_info.reset_reward_debt(project.acc_reward_per_share);
+
+    // 프로젝트 정보 업데이트
+    if is_new_stake {
+        project.supporters_count = project.supporters_count.saturating_add(1);
+    }
+    project.funds_raised = project.funds_raised.saturating_add(amount);
+    project.total_staked_lst = project.total_staked_lst.saturating_add(lst_amount);
+
+    // 플랫폼 통계 업데이트
+    platform.total_staked_sol = platform.total_staked_sol.saturating_add(amount);
+
+    Ok(is_new_stake)
+}
+
+/// 언스테이킹 결과를 StakeInfo, Project, Platform에 반영하고 차감된 원금(lamports)을 반환
+fn apply_unstake(
+    stake_info: &mut StakeInfo,
+    project: &mut Project,
+    platform: &mut Platform,
+    lst_amount: u64,
+) -> Result<u64> {
+    // LST 수량 변경 전 보상 정산 및 변경 전 수량 기록
+    stake_info.settle_rewards(project.acc_reward_per_share);
+    stake_info.record_stake_change(Clock::get()?.unix_timestamp);
+
+    // LST 비율만큼 원금 차감
+    let principal_removed = if stake_info.current_lst_amount == 0 {
+        0
+    } else {
+        ((stake_info.initial_stake_amount as u128)
+            .saturating_mul(lst_amount as u128)
+            / stake_info.current_lst_amount as u128) as u64
+    };
+
+    stake_info.initial_stake_amount = stake_info.initial_stake_amount.saturating_sub(principal_removed);
+    stake_info.current_lst_amount = stake_info.current_lst_amount.saturating_sub(lst_amount);
+    stake_info.reset_reward_debt(project.acc_reward_per_share);
+
+    // 프로젝트 정보 업데이트
+    project.funds_raised = project.funds_raised.saturating_sub(principal_removed);
+    project.total_staked_lst = project.total_staked_lst.saturating_sub(lst_amount);
+
+    // 스테이킹 정보가 0이 되면 지원자 수 감소
+    if stake_info.current_lst_amount == 0 {
+        project.supporters_count = project.supporters_count.saturating_sub(1);
+    }
+
+    // 플랫폼 통계 업데이트
+    platform.total_staked_sol = platform.total_staked_sol.saturating_sub(principal_removed);
+
+    Ok(principal_removed)
+}
+
+/// 프로젝트 수수료 설정 적용 (직접 호출 및 멀티시그 제안 실행 공용)
+fn apply_project_fee(
+    project: &mut Account<Project>,
+    manager_fee_percentage: u16,
+    payout_wallet: Option<Pubkey>,
+) -> Result<()> {
+    // 수수료 비율 유효성 검증 (최대 100%)
+    require!(
+        manager_fee_percentage <= 10000,
+        ForestLabError::InvalidFeePercentage
+    );
+
+    // 프로젝트 수수료 설정 업데이트
+    project.manager_fee_percentage = manager_fee_percentage;
+
+    // 지불 지갑 업데이트 (선택적)
+    if let Some(wallet) = payout_wallet {
+        project.payout_wallet = wallet;
+    }
+
+    // 설정 업데이트 이벤트 발행
+    emit!(ProjectSettingsUpdatedEvent {
+        project: project.key(),
+        manager_fee_percentage,
+        payout_wallet: project.payout_wallet,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// 리스테이킹 설정 적용 (직접 호출 및 멀티시그 제안 실행 공용)
+fn apply_restaking(
+    restake_config: &mut RestakeConfig,
+    project: &Account<Project>,
+    target_lst_mint: Pubkey,
+    restake_percentage: u16,
+    bump: u8,
+) -> Result<()> {
+    // 리스테이킹 비율 유효성 검증 (최대 100%)
+    require!(
+        restake_percentage <= 10000,
+        ForestLabError::InvalidPercentage
+    );
+
+    // 리스테이킹 설정 초기화 또는 업데이트
+    restake_config.project = project.key();
+    restake_config.source_lst_mint = project.lst_mint;
+    restake_config.target_lst_mint = target_lst_mint;
+    restake_config.restake_percentage = restake_percentage;
+    restake_config.is_active = true;
+    restake_config.bump = bump;
+
+    // 리스테이킹 설정 이벤트 발행
+    emit!(RestakingConfiguredEvent {
+        project: project.key(),
+        source_lst_mint: project.lst_mint,
+        target_lst_mint,
+        restake_percentage,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// 리스테이킹 볼트 출금 및 포지션 원금·평가 가치 비례 차감 (직접 호출 및 멀티시그 제안 실행 공용)
+#[allow(clippy::too_many_arguments)]
+fn apply_restake_withdrawal<'info>(
+    restake_vault: &Account<'info, TokenAccount>,
+    restake_position: &mut RestakePosition,
+    destination: &Account<'info, TokenAccount>,
+    vault_authority: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    vault_authority_bump: u8,
+    authority: Pubkey,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, ForestLabError::InvalidAmount);
+
+    let vault_amount = restake_vault.amount;
+    require!(amount <= vault_amount, ForestLabError::InsufficientFunds);
+
+    // 리스테이킹 볼트에서 출금 (볼트 권한 PDA 서명)
+    let project_key = restake_position.project;
+    let seeds = &[
+        b"vault_authority".as_ref(),
+        project_key.as_ref(),
+        &[vault_authority_bump],
+    ];
+    token::transfer(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            token::Transfer {
+                from: restake_vault.to_account_info(),
+                to: destination.to_account_info(),
+                authority: vault_authority.clone(),
+            },
+            &[&seeds[..]],
+        ),
+        amount,
+    )?;
+
+    // 출금 비율만큼 원금과 평가 가치 차감
+    let remaining_share = |value: u64| -> u64 {
+        ((value as u128).saturating_mul((vault_amount - amount) as u128) / vault_amount as u128) as u64
+    };
+    restake_position.principal_lamports = remaining_share(restake_position.principal_lamports);
+    restake_position.last_valuation_lamports = remaining_share(restake_position.last_valuation_lamports);
+    restake_position.yield_lamports = restake_position
+        .last_valuation_lamports
+        .saturating_sub(restake_position.principal_lamports);
+
+    // 리스테이킹 출금 이벤트 발행
+    emit!(RestakeWithdrawnEvent {
+        project: project_key,
+        target_lst_mint: restake_position.target_lst_mint,
+        authority,
+        destination: destination.key(),
+        amount,
+        principal_lamports: restake_position.principal_lamports,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// 락업 티어와 보너스 재계산 (생성, 연장, 추가 예치 공용)
+///
+/// 이전 티어 사용량을 반환한 뒤 현재 기간으로 티어를 다시 선택하고,
+/// 보너스가 늘어나는 경우 보너스 풀 잔액으로 감당 가능한지 확인합니다.
+fn reprice_lockup(
+    lockup: &mut Lockup,
+    lockup_tier_config: &mut LockupTierConfig,
+    project: &mut Project,
+    previous_amount: u64,
+    bonus_vault_balance: u64,
+) -> Result<()> {
+    // 최대 락업 기간 확인
+    let duration = lockup.end_time.saturating_sub(lockup.start_time);
+    require!(
+        duration <= lockup_tier_config.max_duration,
+        ForestLabError::LockupTooLong
+    );
+
+    // 이전 티어 사용량 반환 후 현재 기간에 맞는 티어 선택 및 한도 예약
+    lockup_tier_config.release(lockup.tier_min_duration, previous_amount);
+    match lockup_tier_config.select_tier(duration) {
+        Some(tier_index) => {
+            lockup_tier_config.reserve(tier_index, lockup.amount)?;
+            let tier = lockup_tier_config.tiers[tier_index];
+            lockup.bonus_percentage = tier.bonus_bps;
+            lockup.tier_min_duration = tier.min_duration;
+        }
+        None => {
+            // 기본: 보너스 없음
+            lockup.bonus_percentage = 0;
+            lockup.tier_min_duration = 0;
+        }
+    }
+
+    // 보너스 재계산 및 약속된 보너스 총액 갱신
+    let previous_bonus = lockup.bonus_amount;
+    let bonus_amount = Lockup::compute_bonus(lockup.amount_seconds, lockup.bonus_percentage);
+    let bonus_committed = project
+        .bonus_committed
+        .saturating_sub(previous_bonus)
+        .saturating_add(bonus_amount);
+
+    // 보너스 풀이 기존 약속분과 늘어난 보너스를 모두 감당할 수 있는지 확인
+    if bonus_amount > previous_bonus {
+        require!(
+            bonus_vault_balance >= bonus_committed,
+            ForestLabError::InsufficientBonusPool
+        );
+    }
+    lockup.bonus_amount = bonus_amount;
+    project.bonus_committed = bonus_committed;
+
+    Ok(())
+}
+
+/// 락업 변경을 투표권 체크포인트에 반영 (락업 생성, 연장, 추가 예치, 해제 공용)
+fn checkpoint_voting_power(voting_power: &mut VotingPower, lockup: &Lockup) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    voting_power.sync_lockup(lockup, current_time)?;
+
+    voting_power.last_voting_power = voting_power.power_at(current_time);
+    voting_power.last_checkpoint_time = current_time;
+
+    // 투표권 체크포인트 갱신 이벤트 발행
+    emit!(VotingPowerUpdatedEvent {
+        user: voting_power.user,
+        project: voting_power.project,
+        voting_power: voting_power.last_voting_power,
+        active_locks: voting_power.locks.len() as u8,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+/// 펀딩 시작/종료 경계 갱신 (경계를 넘은 첫 번째 성공한 작업에서 이벤트 발행)
+fn refresh_campaign_phase(project: &mut Account<Project>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+
+    if !project.campaign_started && current_time >= project.start_time {
+        project.campaign_started = true;
+
+        // 펀딩 시작 이벤트 발행
+        emit!(CampaignStartedEvent {
+            project: project.key(),
+            start_time: project.start_time,
+            timestamp: current_time,
+        });
+    }
+
+    let max_raise_reached = project.is_max_raise_reached();
+    if !project.campaign_ended && (current_time >= project.end_time || max_raise_reached) {
+        project.campaign_ended = true;
+
+        // 펀딩 종료 이벤트 발행
+        emit!(CampaignEndedEvent {
+            project: project.key(),
+            end_time: project.end_time,
+            funds_raised: project.funds_raised,
+            max_raise_reached,
+            timestamp: current_time,
+        });
+    }
+
+    Ok(())
+}
+
+/// 펀딩 기간 중인지 확인 (스테이킹 및 락업 공용, 경계 이벤트는 성공 후 refresh_campaign_phase에서 발행)
+fn require_campaign_open(project: &Project) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(
+        project.campaign_started || current_time >= project.start_time,
+        ForestLabError::CampaignNotStarted
+    );
+    require!(
+        !project.campaign_ended && current_time < project.end_time && !project.is_max_raise_reached(),
+        ForestLabError::CampaignEnded
+    );
+    Ok(())
+}
+
+/// 역할 보유 여부 (플랫폼 관리자는 모든 역할 보유)
+fn has_role(platform: &Platform, roles: Option<&Roles>, member: &Pubkey, role: Role) -> bool {
+    platform.authority == *member || roles.is_some_and(|roles| roles.has_role(member, role))
+}
+
+/// 역할 권한 확인
+fn require_role(platform: &Platform, roles: Option<&Roles>, member: &Pubkey, role: Role) -> Result<()> {
+    require!(has_role(platform, roles, member, role), ForestLabError::MissingRole);
+    Ok(())
+}
+
+/// 역할 목록 초기화 (관리자 지갑에 기본 운영 역할 부여)
+fn initialize_roles(
+    roles: &mut Roles,
+    platform: Pubkey,
+    admin_wallet: Pubkey,
+    bump: u8,
+    granted_by: Pubkey,
+) -> Result<()> {
+    roles.platform = platform;
+    roles.bump = bump;
+    roles.grant(admin_wallet, Roles::admin_wallet_mask())?;
+
+    // 역할 부여 이벤트 발행
+    let timestamp = Clock::get()?.unix_timestamp;
+    for role in Roles::ADMIN_WALLET_ROLES {
+        emit!(RoleGrantedEvent {
+            platform,
+            member: admin_wallet,
+            role,
+            granted_by,
+            timestamp,
+        });
+    }
+    Ok(())
+}
+
+/// 프로젝트 상태 변경 적용 (직접 호출, 멀티시그 제안 실행, 펀딩 정산 공용)
+fn apply_project_status(
+    project: &mut Account<Project>,
+    new_status: ProjectStatus,
+    reason: StatusReason,
+    actor: StatusActor,
+    updated_by: Pubkey,
+) -> Result<()> {
+    // 이전 상태 저장 및 전이 가능 여부 확인
+    let previous_status = project.status;
+    previous_status.check_transition(new_status, actor, project.status_actor, project.status_reason)?;
+
+    // 상태 및 사유 업데이트
+    let current_time = Clock::get()?.unix_timestamp;
+    project.status = new_status;
+    project.status_reason = reason;
+    project.status_actor = actor;
+    project.status_updated_at = current_time;
+
+    // 상태 업데이트 이벤트 발행
+    emit!(ProjectStatusUpdatedEvent {
+        project: project.key(),
+        previous_status,
+        new_status,
+        reason,
+        updated_by,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+/// 취소된 프로젝트의 미실행 프로젝트 몫(스왑 및 리스테이킹 대기분)을 스테이커 보상으로 돌려놓고 반환량을 반환
+///
+/// 프로젝트 몫 볼트가 아직 생성되지 않았거나 스테이커가 없으면 아무것도 하지 않습니다.
+fn return_project_share_to_stakers<'info>(
+    project: &mut Account<'info, Project>,
+    project_share_vault: &AccountInfo<'info>,
+    reward_vault: &AccountInfo<'info>,
+    vault_authority: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    vault_authority_bump: u8,
+) -> Result<u64> {
+    if project_share_vault.owner != &token::ID || project_share_vault.data_is_empty() {
+        return Ok(0);
+    }
+    let balance = TokenAccount::try_deserialize(&mut &project_share_vault.try_borrow_data()?[..])?.amount;
+
+    // 스테이커별 누적 보상에 반영된 만큼만 보상 볼트로 이동
+    let returned = project.distribute_rewards(balance);
+    if returned == 0 {
+        return Ok(0);
+    }
+
+    let project_key = project.key();
+    let seeds = &[
+        b"vault_authority".as_ref(),
+        project_key.as_ref(),
+        &[vault_authority_bump],
+    ];
+    token::transfer(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            token::Transfer {
+                from: project_share_vault.clone(),
+                to: reward_vault.clone(),
+                authority: vault_authority.clone(),
+            },
+            &[&seeds[..]],
+        ),
+        returned,
+    )?;
+    project.total_rewards_distributed = project.total_rewards_distributed.saturating_add(returned);
+
+    // 프로젝트 몫 반환 이벤트 발행
+    emit!(ProjectShareReturnedEvent {
+        project: project_key,
+        amount: returned,
+        acc_reward_per_share: project.acc_reward_per_share,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(returned)
+}
+
+impl ProjectAction {
+    /// 제안 생성 시 작업 인자 검증
+    pub fn validate(&self) -> Result<()> {
+        match self {
+            ProjectAction::UpdateFee { manager_fee_percentage, .. } => {
+                require!(*manager_fee_percentage <= 10000, ForestLabError::InvalidFeePercentage);
+            }
+            ProjectAction::SetupRestaking { restake_percentage, .. } => {
+                require!(*restake_percentage <= 10000, ForestLabError::InvalidPercentage);
+            }
+            ProjectAction::UpdateStatus { reason, .. } => {
+                require!(reason.is_manual(), ForestLabError::InvalidStatusReason);
+            }
+            ProjectAction::WithdrawRestake { amount, .. } => {
+                require!(*amount > 0, ForestLabError::InvalidAmount);
+            }
+            ProjectAction::AddSigner { .. }
+            | ProjectAction::RemoveSigner { .. }
+            | ProjectAction::ChangeThreshold { .. } => {}
+        }
+        Ok(())
+    }
+}
+
+/// 스왑 CPI에 필요한 공통 계정 모음
+pub struct SwapAccounts<'a, 'info> {
+    /// 스왑 프로그램
+    pub swap_program: AccountInfo<'info>,
+    /// 소스 토큰 계정의 이체 권한
+    pub transfer_authority: AccountInfo<'info>,
+    /// 입력 토큰 계정
+    pub source: AccountInfo<'info>,
+    /// 출력 토큰 계정
+    pub destination: AccountInfo<'info>,
+    /// SPL Token 프로그램
+    pub token_program: AccountInfo<'info>,
+    /// 어댑터별 풀 계정 (remaining_accounts)
+    pub pool_accounts: &'a [AccountInfo<'info>],
+}
+
+/// 스왑 프로그램별 CPI 어댑터
+pub trait SwapAdapter {
+    /// amount_in만큼 스왑하고 최소 min_out 이상 받도록 요청
+    fn swap(
+        &self,
+        accounts: &SwapAccounts<'_, '_>,
+        amount_in: u64,
+        min_out: u64,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()>;
+}
+
+/// SPL Token Swap (Constant Product) 어댑터
+///
+/// pool_accounts 순서: swap, swap_authority, pool_source, pool_destination, pool_mint, pool_fee_account, (host_fee_account)
+pub struct SplTokenSwapAdapter;
+
+impl SplTokenSwapAdapter {
+    /// Swap 명령어 태그
+    const IX_SWAP: u8 = 1;
+
+    /// Swap 명령어 생성 (pool_keys는 pool_accounts와 같은 순서)
+    #[allow(clippy::too_many_arguments)]
+    pub fn swap_instruction(
+        swap_program: &Pubkey,
+        transfer_authority: &Pubkey,
+        source: &Pubkey,
+        destination: &Pubkey,
+        token_program: &Pubkey,
+        pool_keys: &[Pubkey],
+        amount_in: u64,
+        min_out: u64,
+    ) -> Result<Instruction> {
+        require!(pool_keys.len() == 6 || pool_keys.len() == 7, ForestLabError::InvalidSwapAccounts);
+
+        let mut metas = vec![
+            AccountMeta::new_readonly(pool_keys[0], false),
+            AccountMeta::new_readonly(pool_keys[1], false),
+            AccountMeta::new_readonly(*transfer_authority, true),
+            AccountMeta::new(*source, false),
+            AccountMeta::new(pool_keys[2], false),
+            AccountMeta::new(pool_keys[3], false),
+            AccountMeta::new(*destination, false),
+            AccountMeta::new(pool_keys[4], false),
+            AccountMeta::new(pool_keys[5], false),
+            AccountMeta::new_readonly(*token_program, false),
+        ];
+        if let Some(host_fee) = pool_keys.get(6) {
+            metas.push(AccountMeta::new(*host_fee, false));
+        }
+
+        let mut data = Vec::with_capacity(17);
+        data.push(Self::IX_SWAP);
+        data.extend_from_slice(&amount_in.to_le_bytes());
+        data.extend_from_slice(&min_out.to_le_bytes());
+
+        Ok(Instruction {
+            program_id: *swap_program,
+            accounts: metas,
+            data,
+        })
+    }
+}
+
+impl SwapAdapter for SplTokenSwapAdapter {
+    fn swap(
+        &self,
+        accounts: &SwapAccounts<'_, '_>,
+        amount_in: u64,
+        min_out: u64,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        let pool = accounts.pool_accounts;
+        require!(pool.len() == 6 || pool.len() == 7, ForestLabError::InvalidSwapAccounts);
+        require_keys_eq!(*pool[0].owner, accounts.swap_program.key(), ForestLabError::InvalidSwapAccounts);
+
+        let pool_keys: Vec<Pubkey> = pool.iter().map(|info| info.key()).collect();
+        let ix = Self::swap_instruction(
+            &accounts.swap_program.key(),
+            &accounts.transfer_authority.key(),
+            &accounts.source.key(),
+            &accounts.destination.key(),
+            &accounts.token_program.key(),
+            &pool_keys,
+            amount_in,
+            min_out,
+        )?;
+
+        let mut infos = vec![
+            pool[0].clone(),
+            pool[1].clone(),
+            accounts.transfer_authority.clone(),
+            accounts.source.clone(),
+            pool[2].clone(),
+            pool[3].clone(),
+            accounts.destination.clone(),
+            pool[4].clone(),
+            pool[5].clone(),
+            accounts.token_program.clone(),
+        ];
+        if let Some(host_fee) = pool.get(6) {
+            infos.push(host_fee.clone());
+        }
+        infos.push(accounts.swap_program.clone());
+
+        invoke_signed(&ix, &infos, signer_seeds)?;
+        Ok(())
+    }
+}
+
+impl SwapAdapterKind {
+    /// 설정된 어댑터 구현 반환
+    pub fn adapter(&self) -> &'static dyn SwapAdapter {
+        match self {
+            SwapAdapterKind::SplTokenSwap => &SplTokenSwapAdapter,
+        }
+    }
+}
+
+#[error_code]
+pub enum ForestLabError {
+    #[msg("수수료 비율이 유효하지 않습니다")]
+    InvalidFeePercentage,
+    
+    #[msg("플랫폼이 비활성화되어 있습니다")]
+    PlatformInactive,
+    
+    #[msg("프로젝트 이름이 비어 있습니다")]
+    EmptyName,
+
+    #[msg("프로젝트 심볼이 비어 있습니다")]
+    EmptySymbol,
+    
+    #[msg("펀딩 목표 금액이 유효하지 않습니다")]
+    InvalidFundingGoal,
+    
+    #[msg("펀딩 기간이 유효하지 않습니다")]
+    InvalidDuration,
+    
+    #[msg("프로젝트가 비활성화되어 있습니다")]
+    ProjectInactive,
+    
+    #[msg("최소 스테이킹 금액보다 작습니다")]
+    BelowMinimumStakeAmount,
+    
+    #[msg("에포크 보상이 이미 처리되었습니다")]
+    AlreadyProcessedForEpoch,
+    
+    #[msg("권한이 없습니다")]
+    Unauthorized,
+    
+    #[msg("유효하지 않은 금액입니다")]
+    InvalidAmount,
+    
+    #[msg("이미 처리되었습니다")]
+    AlreadyProcessed,
+    
+    #[msg("자금이 부족합니다")]
+    InsufficientFunds,
+    
+    #[msg("최소 락업 기간보다 짧습니다")]
+    LockupTooShort,
+    
+    #[msg("락업이 이미 해제되었습니다")]
+    AlreadyReleased,
+    
+    #[msg("락업 기간이 끝나지 않았습니다")]
+    LockupNotExpired,
+    
+    #[msg("비율이 유효하지 않습니다")]
+    InvalidPercentage,
+    
+    #[msg("서명자 수가 유효하지 않습니다")]
+    InvalidSignerCount,
+    
+    #[msg("서명자가 너무 많습니다 (최대 10명)")]
+    TooManySigners,
+    
+    #[msg("임계값이 유효하지 않습니다")]
+    InvalidThreshold,
+    
+    #[msg("임계값이 서명자 수보다 큽니다")]
+    ThresholdTooHigh,
+
+    #[msg("스테이킹 정보를 찾을 수 없습니다")]
+    StakeInfoNotFound,
+
+    #[msg("LST 잔액이 부족합니다")]
+    InsufficientLstBalance,
+
+    #[msg("스테이크 풀 계정이 유효하지 않습니다")]
+    InvalidStakePool,
+
+    #[msg("발행된 LST가 없습니다")]
+    NoLstMinted,
+
+    #[msg("소각된 LST 수량이 요청과 다릅니다")]
+    LstBalanceMismatch,
+
+    #[msg("수령한 SOL이 없습니다")]
+    NoLamportsReceived,
+
+    #[msg("유효하지 않은 에포크입니다")]
+    InvalidEpoch,
+
+    #[msg("청구 가능한 보상이 없습니다")]
+    NoPendingRewards,
+
+    #[msg("보상 볼트 잔액이 부족합니다")]
+    InsufficientRewardVault,
+
+    #[msg("스왑 계정이 유효하지 않습니다")]
+    InvalidSwapAccounts,
+
+    #[msg("스왑 결과가 최소 수령량보다 적습니다")]
+    SlippageExceeded,
+
+    #[msg("수수료 장부에 더 이상 민트를 추가할 수 없습니다")]
+    FeeLedgerFull,
+
+    #[msg("멀티시그가 활성화되어 있어 제안을 통해서만 실행할 수 있습니다")]
+    MultisigRequired,
+
+    #[msg("멀티시그가 비활성화되어 있습니다")]
+    MultisigInactive,
+
+    #[msg("멀티시그 서명자가 아닙니다")]
+    NotMultisigSigner,
+
+    #[msg("이미 승인한 제안입니다")]
+    AlreadyApproved,
+
+    #[msg("이미 실행된 제안입니다")]
+    ProposalAlreadyExecuted,
+
+    #[msg("승인 수가 임계값에 도달하지 않았습니다")]
+    ThresholdNotMet,
+
+    #[msg("리스테이킹 설정 계정이 필요합니다")]
+    MissingRestakeConfig,
+
+    #[msg("리스테이킹 출금 계정이 없거나 제안과 일치하지 않습니다")]
+    MissingRestakeAccounts,
+
+    #[msg("중복된 서명자가 있습니다")]
+    DuplicateSigner,
+
+    #[msg("리스테이킹이 비활성화되어 있습니다")]
+    RestakingInactive,
+
+    #[msg("이미 리스테이킹된 에포크입니다")]
+    AlreadyRestaked,
+
+    #[msg("보너스 풀 잔액이 약속된 보너스를 감당할 수 없습니다")]
+    InsufficientBonusPool,
+
+    #[msg("락업 티어 설정이 올바르지 않습니다")]
+    InvalidLockupTiers,
+
+    #[msg("락업 기간이 최대 허용 기간을 초과합니다")]
+    LockupTooLong,
+
+    #[msg("락업 티어 한도를 초과했습니다")]
+    LockupTierFull,
+
+    #[msg("이미 만료된 락업은 일반 해제를 사용해야 합니다")]
+    LockupAlreadyExpired,
+
+    #[msg("투표권에 반영 가능한 활성 락업 수를 초과했습니다")]
+    TooManyActiveLockups,
+
+    #[msg("거버넌스 제안 내용이 유효하지 않습니다")]
+    InvalidProposal,
+
+    #[msg("투표 기간이 아닙니다")]
+    VotingNotActive,
+
+    #[msg("투표 기간이 아직 끝나지 않았습니다")]
+    VotingNotEnded,
+
+    #[msg("이미 확정된 제안입니다")]
+    ProposalAlreadyFinalized,
+
+    #[msg("유효하지 않은 투표 선택지입니다")]
+    InvalidVoteOption,
+
+    #[msg("투표 가중치 계정이 필요합니다")]
+    MissingVoteWeightAccount,
+
+    #[msg("스냅샷 시점의 투표권 기록이 남아 있지 않습니다")]
+    VoteSnapshotUnavailable,
+
+    #[msg("투표권이 없습니다")]
+    NoVotingPower,
+
+    #[msg("프로젝트 펀딩 기간이 아직 끝나지 않았습니다")]
+    CampaignNotEnded,
+
+    #[msg("이미 정산된 프로젝트입니다")]
+    ProjectAlreadyFinalized,
+
+    #[msg("취소된 프로젝트는 보상을 지급받을 수 없습니다")]
+    ProjectCancelled,
+
+    #[msg("프로젝트 펀딩이 아직 시작되지 않았습니다")]
+    CampaignNotStarted,
+
+    #[msg("프로젝트 펀딩이 종료되었습니다")]
+    CampaignEnded,
+
+    #[msg("최대 모금 한도를 초과합니다")]
+    MaxRaiseExceeded,
+
+    #[msg("허용되지 않은 프로젝트 상태 변경입니다")]
+    InvalidStatusTransition,
+
+    #[msg("유효하지 않은 상태 변경 사유입니다")]
+    InvalidStatusReason,
+
+    #[msg("대기 중인 관리자 이전이 없습니다")]
+    NoPendingAuthority,
+
+    #[msg("필요한 역할 권한이 없습니다")]
+    MissingRole,
+
+    #[msg("역할 목록이 가득 찼습니다")]
+    RolesFull,
+
+    #[msg("일시 중지된 작업입니다")]
+    OperationPaused,
+
+    #[msg("유효하지 않은 일시 중지 플래그입니다")]
+    InvalidPauseFlags,
+
+    #[msg("대기 중인 설정 변경이 없습니다")]
+    NoPendingSettingsChange,
+
+    #[msg("설정 변경 대기 시간이 지나지 않았습니다")]
+    SettingsTimelockNotElapsed,
+
+    #[msg("유효하지 않은 설정 변경 대기 시간입니다")]
+    InvalidSettingsTimelock,
+
+    #[msg("유효하지 않은 구버전 계정입니다")]
+    InvalidLegacyAccount,
+
+    #[msg("이미 마이그레이션된 계정입니다")]
+    AccountAlreadyMigrated,
+
+    #[msg("산술 오버플로우가 발생했습니다")]
+    MathOverflow,
+
+    #[msg("취소된 프로젝트가 아닙니다")]
+    ProjectNotCancelled,
+}
+
+// 플랫폼 생성 이벤트
+#[event]
+pub struct PlatformCreatedEvent {
+    pub platform: Pubkey,
+    pub authority: Pubkey,
+    pub admin_wallet: Pubkey,
+    pub platform_fee: u16,
+    pub min_stake_amount: u64,
+}
+
+// 프로젝트 등록 이벤트
+#[event]
+pub struct ProjectRegisteredEvent {
+    pub project: Pubkey,
+    pub creator: Pubkey,
+    pub platform: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub funding_goal: u64,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub max_raise: u64,
+    pub lst_mint: Pubkey,
+    pub apy_estimate: u16,
+}
+
+// 프로젝트 스테이킹 이벤트
+#[event]
+pub struct ProjectStakedEvent {
+    pub project: Pubkey,
+    pub user: Pubkey,
+    pub amount: u64,
+    pub lst_amount: u64,
+    pub is_new_supporter: bool,
+    pub timestamp: i64,
+}
+
+// 프로젝트 언스테이킹 이벤트
+#[event]
+pub struct ProjectUnstakedEvent {
+    pub project: Pubkey,
+    pub user: Pubkey,
+    pub sol_amount: u64,
+    pub lst_amount: u64,
+    pub timestamp: i64,
+}
+
+// 에포크 보상 처리 이벤트
+#[event]
+pub struct EpochRewardsProcessedEvent {
+    pub project: Pubkey,
+    pub epoch: u64,
+    pub total_rewards: u64,
+    pub platform_fee: u64,
+    pub project_rewards: u64,
+    pub timestamp: i64,
+}
+
+// 보상 분배 이벤트
+#[event]
+pub struct RewardsDistributedEvent {
+    pub project: Pubkey,
+    pub epoch: u64,
+    pub lst_amount: u64,
+    pub usdc_amount: u64,
+    pub project_fee: u64,
+    pub project_amount: u64,
+    pub timestamp: i64,
+}
+
+// 보상 청구 이벤트
+#[event]
+pub struct RewardsClaimedEvent {
+    pub project: Pubkey,
+    pub user: Pubkey,
+    pub reward_token_amount: u64,
+    pub timestamp: i64,
+}
+
+// 프로젝트 설정 업데이트 이벤트
+#[event]
+pub struct ProjectSettingsUpdatedEvent {
+    pub project: Pubkey,
+    pub manager_fee_percentage: u16,
+    pub payout_wallet: Pubkey,
+    pub timestamp: i64,
+}
+
+// 프로젝트 상태 업데이트 이벤트
+#[event]
+pub struct ProjectStatusUpdatedEvent {
+    pub project: Pubkey,
+    pub previous_status: ProjectStatus,
+    pub new_status: ProjectStatus,
+    pub reason: StatusReason,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+// 펀딩 시작 이벤트
+#[event]
+pub struct CampaignStartedEvent {
+    pub project: Pubkey,
+    pub start_time: i64,
+    pub timestamp: i64,
+}
+
+// 펀딩 종료 이벤트
+#[event]
+pub struct CampaignEndedEvent {
+    pub project: Pubkey,
+    pub end_time: i64,
+    pub funds_raised: u64,
+    pub max_raise_reached: bool,
+    pub timestamp: i64,
+}
+
+// 프로젝트 펀딩 정산 이벤트
+#[event]
+pub struct ProjectFinalizedEvent {
+    pub project: Pubkey,
+    pub status: ProjectStatus,
+    pub funds_raised: u64,
+    pub funding_goal: u64,
+    pub finalized_by: Pubkey,
+    pub timestamp: i64,
+}
+
+// 프로젝트 몫 반환 이벤트
+#[event]
+pub struct ProjectShareReturnedEvent {
+    pub project: Pubkey,
+    pub amount: u64,
+    pub acc_reward_per_share: u128,
+    pub timestamp: i64,
+}
+
+// 락업 생성 이벤트
+#[event]
+pub struct LockupCreatedEvent {
+    pub lockup: Pubkey,
+    pub user: Pubkey,
+    pub project: Pubkey,
+    pub index: u64,
+    pub amount: u64,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub bonus_percentage: u16,
+    pub bonus_amount: u64,
+}
+
+// 락업 해제 이벤트
+#[event]
+pub struct LockupReleasedEvent {
+    pub lockup: Pubkey,
+    pub user: Pubkey,
+    pub project: Pubkey,
+    pub index: u64,
+    pub amount: u64,
+    pub bonus_amount: u64,
+    pub release_time: i64,
+}
+
+// 투표권 체크포인트 갱신 이벤트
+#[event]
+pub struct VotingPowerUpdatedEvent {
+    pub user: Pubkey,
+    pub project: Pubkey,
+    pub voting_power: u64,
+    pub active_locks: u8,
+    pub timestamp: i64,
+}
+
+// 락업 연장 이벤트
+#[event]
+pub struct LockupExtendedEvent {
+    pub lockup: Pubkey,
+    pub user: Pubkey,
+    pub project: Pubkey,
+    pub index: u64,
+    pub previous_end_time: i64,
+    pub end_time: i64,
+    pub bonus_percentage: u16,
+    pub bonus_amount: u64,
+    pub timestamp: i64,
+}
+
+// 락업 추가 예치 이벤트
+#[event]
+pub struct LockupIncreasedEvent {
+    pub lockup: Pubkey,
+    pub user: Pubkey,
+    pub project: Pubkey,
+    pub index: u64,
+    pub added_amount: u64,
+    pub amount: u64,
+    pub bonus_percentage: u16,
+    pub bonus_amount: u64,
+    pub timestamp: i64,
+}
+
+// 락업 조기 해제 이벤트
+#[event]
+pub struct LockupEarlyReleasedEvent {
+    pub lockup: Pubkey,
+    pub user: Pubkey,
+    pub project: Pubkey,
+    pub index: u64,
+    pub amount: u64,
+    pub penalty_amount: u64,
+    pub returned_amount: u64,
+    pub forfeited_bonus: u64,
+    pub remaining_time: i64,
+    pub release_time: i64,
+}
+
+// 크랭크 실행 이벤트
+#[event]
+pub struct CrankExecutedEvent {
+    pub project: Pubkey,
+    pub epoch: u64,
+    pub executor: Pubkey,
+    pub timestamp: i64,
+}
+
+// 플랫폼 설정 업데이트 이벤트
+#[event]
+pub struct PlatformSettingsUpdatedEvent {
+    pub platform: Pubkey,
+    pub authority: Pubkey,
+    pub platform_fee: u16,
+    pub min_stake_amount: u64,
+    pub admin_wallet: Pubkey,
+    pub is_active: bool,
+    pub timestamp: i64,
+}
+
+// 플랫폼 설정 변경 예약 이벤트
+#[event]
+pub struct PlatformSettingsQueuedEvent {
+    pub platform: Pubkey,
+    pub authority: Pubkey,
+    pub pending_platform_fee: Option<u16>,
+    pub pending_admin_wallet: Option<Pubkey>,
+    pub pending_settings_timelock: Option<i64>,
+    pub platform_fee_effective_at: i64,
+    pub admin_wallet_effective_at: i64,
+    pub settings_timelock_effective_at: i64,
+    pub timestamp: i64,
+}
+
+// 플랫폼 설정 변경 실행 이벤트
+#[event]
+pub struct PlatformSettingsExecutedEvent {
+    pub platform: Pubkey,
+    pub executor: Pubkey,
+    pub platform_fee: u16,
+    pub admin_wallet: Pubkey,
+    pub settings_timelock: i64,
+    pub timestamp: i64,
+}
+
+// 플랫폼 설정 변경 취소 이벤트
+#[event]
+pub struct PlatformSettingsCancelledEvent {
+    pub platform: Pubkey,
+    pub authority: Pubkey,
+    pub cancelled_platform_fee: Option<u16>,
+    pub cancelled_admin_wallet: Option<Pubkey>,
+    pub cancelled_settings_timelock: Option<i64>,
+    pub timestamp: i64,
+}
+
+// 플랫폼 관리자 이전 제안 이벤트
+#[event]
+pub struct PlatformAuthorityProposedEvent {
+    pub platform: Pubkey,
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+// 플랫폼 관리자 이전 완료 이벤트
+#[event]
+pub struct PlatformAuthorityTransferredEvent {
+    pub platform: Pubkey,
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+// 플랫폼 관리자 이전 취소 이벤트
+#[event]
+pub struct PlatformAuthorityTransferCancelledEvent {
+    pub platform: Pubkey,
+    pub authority: Pubkey,
+    pub cancelled_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+// 프로젝트 관리자 이전 제안 이벤트
+#[event]
+pub struct ProjectAuthorityProposedEvent {
+    pub project: Pubkey,
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+// 프로젝트 관리자 이전 완료 이벤트
+#[event]
+pub struct ProjectAuthorityTransferredEvent {
+    pub project: Pubkey,
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+// 프로젝트 관리자 이전 취소 이벤트
+#[event]
+pub struct ProjectAuthorityTransferCancelledEvent {
+    pub project: Pubkey,
+    pub authority: Pubkey,
+    pub cancelled_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+// 역할 부여 이벤트
+#[event]
+pub struct RoleGrantedEvent {
+    pub platform: Pubkey,
+    pub member: Pubkey,
+    pub role: Role,
+    pub granted_by: Pubkey,
+    pub timestamp: i64,
+}
+
+// 역할 회수 이벤트
+#[event]
+pub struct RoleRevokedEvent {
+    pub platform: Pubkey,
+    pub member: Pubkey,
+    pub role: Role,
+    pub revoked_by: Pubkey,
+    pub timestamp: i64,
+}
+
+// 작업 일시 중지 이벤트
+#[event]
+pub struct OperationsPausedEvent {
+    pub platform: Pubkey,
+    pub operations: u8,
+    pub paused_operations: u8,
+    pub paused_by: Pubkey,
+    pub timestamp: i64,
+}
+
+// 작업 재개 이벤트
+#[event]
+pub struct OperationsUnpausedEvent {
+    pub platform: Pubkey,
+    pub operations: u8,
+    pub paused_operations: u8,
+    pub unpaused_by: Pubkey,
+    pub timestamp: i64,
+}
+
+// 크랭커 업데이트 이벤트
+#[event]
+pub struct CrankersUpdatedEvent {
+    pub platform: Pubkey,
+    pub authorized_crankers: Vec<Pubkey>,
+    pub timestamp: i64,
+}
+
+// 리스테이킹 설정 이벤트
+#[event]
+pub struct RestakingConfiguredEvent {
+    pub project: Pubkey,
+    pub source_lst_mint: Pubkey,
+    pub target_lst_mint: Pubkey,
+    pub restake_percentage: u16,
+    pub timestamp: i64,
+}
+
+// 멀티시그 설정 이벤트
+#[event]
+pub struct MultisigConfiguredEvent {
+    pub project: Pubkey,
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+// 스왑 설정 이벤트
+#[event]
+pub struct SwapConfiguredEvent {
+    pub platform: Pubkey,
+    pub swap_program: Pubkey,
+    pub adapter: SwapAdapterKind,
+    pub usdc_mint: Pubkey,
+    pub project_share_bps: u16,
+    pub timestamp: i64,
+}
+
+// 플랫폼 수수료 출금 이벤트
+#[event]
+pub struct PlatformFeesWithdrawnEvent {
+    pub platform: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub destination: Pubkey,
+    pub timestamp: i64,
+}
+
+// 멀티시그 제안 생성 이벤트
+#[event]
+pub struct ProposalCreatedEvent {
+    pub project: Pubkey,
+    pub proposal: Pubkey,
+    pub index: u64,
+    pub proposer: Pubkey,
+    pub action: ProjectAction,
+    pub timestamp: i64,
+}
+
+// 멀티시그 제안 승인 이벤트
+#[event]
+pub struct ProposalApprovedEvent {
+    pub project: Pubkey,
+    pub proposal: Pubkey,
+    pub signer: Pubkey,
+    pub approvals: u8,
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+// 멀티시그 제안 실행 이벤트
+#[event]
+pub struct ProposalExecutedEvent {
+    pub project: Pubkey,
+    pub proposal: Pubkey,
+    pub executor: Pubkey,
+    pub action: ProjectAction,
+    pub timestamp: i64,
+}
+
+// 멀티시그 서명자 변경 이벤트
+#[event]
+pub struct MultisigSignersChangedEvent {
+    pub project: Pubkey,
+    pub proposal: Pubkey,
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+// 리스테이킹 실행 이벤트
+#[event]
+pub struct RestakeExecutedEvent {
+    pub project: Pubkey,
+    pub epoch: u64,
+    pub executor: Pubkey,
+    pub source_amount: u64,
+    pub target_amount: u64,
+    pub principal_lamports: u64,
+    pub yield_lamports: u64,
+    pub timestamp: i64,
+}
+
+// 리스테이킹 출금 이벤트
+#[event]
+pub struct RestakeWithdrawnEvent {
+    pub project: Pubkey,
+    pub target_lst_mint: Pubkey,
+    pub authority: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub principal_lamports: u64,
+    pub timestamp: i64,
+}
+
+// 보너스 볼트 충전 이벤트
+#[event]
+pub struct BonusVaultFundedEvent {
+    pub project: Pubkey,
+    pub funder: Pubkey,
+    pub amount: u64,
+    pub vault_balance: u64,
+    pub bonus_committed: u64,
+    pub timestamp: i64,
+}
+
+// 락업 티어 설정 이벤트
+#[event]
+pub struct LockupTiersConfiguredEvent {
+    pub project: Pubkey,
+    pub authority: Pubkey,
+    pub tiers: Vec<LockupTier>,
+    pub max_duration: i64,
+    pub early_exit_penalty_bps: u16,
+    pub timestamp: i64,
+}
+
+// 거버넌스 제안 생성 이벤트
+#[event]
+pub struct ProjectProposalCreatedEvent {
+    pub proposal: Pubkey,
+    pub project: Pubkey,
+    pub proposer: Pubkey,
+    pub index: u64,
+    pub title: String,
+    pub options: Vec<String>,
+    pub snapshot_time: i64,
+    pub voting_start: i64,
+    pub voting_end: i64,
+}
+
+// 거버넌스 투표 이벤트
+#[event]
+pub struct VoteCastEvent {
+    pub proposal: Pubkey,
+    pub voter: Pubkey,
+    pub option: u8,
+    pub weight: u64,
+    pub source: VoteWeightSource,
+    pub timestamp: i64,
+}
+
+// 거버넌스 제안 결과 확정 이벤트
+#[event]
+pub struct ProjectProposalFinalizedEvent {
+    pub proposal: Pubkey,
+    pub project: Pubkey,
+    pub votes: Vec<u64>,
+    pub total_weight: u64,
+    pub winning_option: Option<u8>,
+    pub timestamp: i64,
+}
+
+// 계정 마이그레이션 이벤트
+#[event]
+pub struct AccountMigratedEvent {
+    pub account: Pubkey,
+    pub old_size: u64,
+    pub new_size: u64,
+    pub migrated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// 플랫폼 초기화를 위한 계정 구조체
+#[derive(Accounts)]
+#[instruction(platform_fee: u16, min_stake_amount: u64, admin_wallet: Pubkey)]
+pub struct InitializePlatform<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = Platform::SPACE,
+        seeds = [b"platform"],
+        bump,
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = Roles::SPACE,
+        seeds = [b"roles"],
+        bump,
+    )]
+    pub roles: Account<'info, Roles>,
+    
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// 프로젝트 등록을 위한 계정 구조체
+#[derive(Accounts)]
+#[instruction(
+    name: String,
+    symbol: String,
+    description: String,
+    website: String,
+    image_uri: String,
+    funding_goal: u64,
+    duration: i64,
+    lst_mint: Pubkey,
+    apy_estimate: u16
+)]
+pub struct RegisterProject<'info> {
+    #[account(
+        init,
+        payer = creator,
+        space = Project::SPACE,
+        seeds = [b"project", name.as_bytes(), creator.key().as_ref()],
+        bump,
+    )]
+    pub project: Account<'info, Project>,
+    
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"platform"], 
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// SOL 스테이킹(SPL Stake Pool DepositSol CPI)을 위한 계정 구조체
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct StakeSol<'info> {
+    #[account(mut, constraint = project.status == ProjectStatus::Active)]
+    pub project: Account<'info, Project>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"platform"], 
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = StakeInfo::SPACE,
+        seeds = [b"stake_info", user.key().as_ref(), project.key().as_ref()],
+        bump,
+    )]
+    pub stake_info: Account<'info, StakeInfo>,
+    
+    /// CHECK: 스테이크 풀 계정 (핸들러에서 소유 프로그램 및 데이터 검증)
+    #[account(mut)]
+    pub stake_pool: UncheckedAccount<'info>,
+    
+    /// CHECK: 스테이크 풀 출금 권한 PDA (핸들러에서 검증)
+    pub stake_pool_withdraw_authority: UncheckedAccount<'info>,
+    
+    /// CHECK: 스테이크 풀 리저브 계정 (스테이크 풀 데이터와 대조)
+    #[account(mut)]
+    pub reserve_stake: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        token::mint = lst_mint,
+        token::authority = user,
+    )]
+    pub user_lst_account: Account<'info, TokenAccount>,
+    
+    #[account(mut, token::mint = lst_mint)]
+    pub manager_fee_account: Account<'info, TokenAccount>,
+    
+    #[account(mut, address = project.lst_mint)]
+    pub lst_mint: Account<'info, Mint>,
+    
+    /// CHECK: SPL Stake Pool 프로그램 (주소 고정, 스테이크 풀 계정 소유자와 대조)
+    #[account(executable, address = SPL_STAKE_POOL_PROGRAM_ID)]
+    pub stake_pool_program: UncheckedAccount<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// SOL 언스테이킹(SPL Stake Pool WithdrawSol CPI)을 위한 계정 구조체
+#[derive(Accounts)]
+#[instruction(lst_amount: u64)]
+pub struct UnstakeSol<'info> {
+    #[account(mut)]
+    pub project: Account<'info, Project>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"platform"], 
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    #[account(
+        mut,
+        seeds = [b"stake_info", user.key().as_ref(), project.key().as_ref()],
+        bump = stake_info.bump,
+        constraint = stake_info.user == user.key() && stake_info.project == project.key()
+    )]
+    pub stake_info: Account<'info, StakeInfo>,
+    
+    /// CHECK: 스테이크 풀 계정 (핸들러에서 소유 프로그램 및 데이터 검증)
+    #[account(mut)]
+    pub stake_pool: UncheckedAccount<'info>,
+    
+    /// CHECK: 스테이크 풀 출금 권한 PDA (핸들러에서 검증)
+    pub stake_pool_withdraw_authority: UncheckedAccount<'info>,
+    
+    /// CHECK: 스테이크 풀 리저브 계정 (스테이크 풀 데이터와 대조)
+    #[account(mut)]
+    pub reserve_stake: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        token::mint = lst_mint,
+        token::authority = user,
+    )]
+    pub user_lst_account: Account<'info, TokenAccount>,
+    
+    #[account(mut, token::mint = lst_mint)]
+    pub manager_fee_account: Account<'info, TokenAccount>,
+    
+    #[account(mut, address = project.lst_mint)]
+    pub lst_mint: Account<'info, Mint>,
+    
+    /// CHECK: SPL Stake Pool 프로그램 (주소 고정, 스테이크 풀 계정 소유자와 대조)
+    #[account(executable, address = SPL_STAKE_POOL_PROGRAM_ID)]
+    pub stake_pool_program: UncheckedAccount<'info>,
+    
+    pub clock: Sysvar<'info, Clock>,
+    
+    /// CHECK: 스테이크 히스토리 시스템 변수
+    #[account(address = anchor_lang::solana_program::sysvar::stake_history::ID)]
+    pub stake_history: UncheckedAccount<'info>,
+    
+    /// CHECK: 네이티브 스테이크 프로그램
+    #[account(address = anchor_lang::solana_program::stake::program::ID)]
+    pub stake_program: UncheckedAccount<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// 대량 언스테이킹(SPL Stake Pool WithdrawStake CPI)을 위한 계정 구조체
+#[derive(Accounts)]
+#[instruction(lst_amount: u64)]
+pub struct UnstakeStake<'info> {
+    #[account(mut)]
+    pub project: Account<'info, Project>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"platform"], 
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    #[account(
+        mut,
+        seeds = [b"stake_info", user.key().as_ref(), project.key().as_ref()],
+        bump = stake_info.bump,
+        constraint = stake_info.user == user.key() && stake_info.project == project.key()
+    )]
+    pub stake_info: Account<'info, StakeInfo>,
+    
+    /// CHECK: 스테이크 풀 계정 (핸들러에서 소유 프로그램 및 데이터 검증)
+    #[account(mut)]
+    pub stake_pool: UncheckedAccount<'info>,
+    
+    /// CHECK: 검증자 목록 계정 (스테이크 풀 데이터와 대조)
+    #[account(mut)]
+    pub validator_list: UncheckedAccount<'info>,
+    
+    /// CHECK: 스테이크 풀 출금 권한 PDA (핸들러에서 검증)
+    pub stake_pool_withdraw_authority: UncheckedAccount<'info>,
+    
+    /// CHECK: 분할할 검증자 또는 리저브 스테이크 계정 (스테이크 풀 프로그램에서 검증)
+    #[account(mut)]
+    pub stake_to_split: UncheckedAccount<'info>,
+    
+    /// CHECK: 출금 받을 미초기화 스테이크 계정 (스테이크 프로그램 소유)
+    #[account(mut, owner = anchor_lang::solana_program::stake::program::ID)]
+    pub stake_to_receive: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        token::mint = lst_mint,
+        token::authority = user,
+    )]
+    pub user_lst_account: Account<'info, TokenAccount>,
+    
+    #[account(mut, token::mint = lst_mint)]
+    pub manager_fee_account: Account<'info, TokenAccount>,
+    
+    #[account(mut, address = project.lst_mint)]
+    pub lst_mint: Account<'info, Mint>,
+    
+    /// CHECK: SPL Stake Pool 프로그램 (주소 고정, 스테이크 풀 계정 소유자와 대조)
+    #[account(executable, address = SPL_STAKE_POOL_PROGRAM_ID)]
+    pub stake_pool_program: UncheckedAccount<'info>,
+    
+    pub clock: Sysvar<'info, Clock>,
+    
+    /// CHECK: 네이티브 스테이크 프로그램
+    #[account(address = anchor_lang::solana_program::stake::program::ID)]
+    pub stake_program: UncheckedAccount<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// 에포크 보상 처리를 위한 계정 구조체
+#[derive(Accounts)]
+#[instruction(epoch: u64, total_rewards: u64)]
+pub struct ProcessEpochRewards<'info> {
+    #[account(mut)]
+    pub project: Account<'info, Project>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(mut, seeds = [b"platform"], bump = platform.bump)]
+    pub platform: Account<'info, Platform>,
+    
+    /// 역할 목록 (역할을 부여받은 주소가 실행하는 경우에만 필요)
+    #[account(seeds = [b"roles"], bump)]
+    pub roles: Option<Account<'info, Roles>>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = RewardsInfo::SPACE,
+        seeds = [b"rewards_info", project.key().as_ref(), &epoch.to_le_bytes()],
+        bump,
+    )]
+    pub rewards_info: Account<'info, RewardsInfo>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = RewardsHistory::SPACE,
+        seeds = [b"rewards_history", project.key().as_ref()],
+        bump,
+    )]
+    pub rewards_history: Account<'info, RewardsHistory>,
+    
+    /// CHECK: 프로젝트 리스테이킹 설정 PDA (미생성 가능, 핸들러에서 활성 여부 확인)
+    #[account(seeds = [b"restake_config", project.key().as_ref()], bump)]
+    pub restake_config: UncheckedAccount<'info>,
+    
+    /// CHECK: 보상 스왑 설정 PDA (미생성 가능, 핸들러에서 프로젝트 몫 비율 조회)
+    #[account(seeds = [b"swap_config"], bump)]
+    pub swap_config: UncheckedAccount<'info>,
+    
+    /// CHECK: 프로젝트 볼트 권한 PDA (데이터 없음)
+    #[account(seeds = [b"vault_authority", project.key().as_ref()], bump)]
+    pub vault_authority: UncheckedAccount<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        seeds = [b"reward_vault", project.key().as_ref()],
+        bump,
+        token::mint = lst_mint,
+        token::authority = vault_authority,
+    )]
+    pub reward_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        seeds = [b"project_share_vault", project.key().as_ref()],
+        bump,
+        token::mint = lst_mint,
+        token::authority = vault_authority,
+    )]
+    pub project_share_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        token::mint = lst_mint,
+        token::authority = authority,
+    )]
+    pub reward_source: Account<'info, TokenAccount>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        seeds = [b"platform_treasury", lst_mint.key().as_ref()],
+        bump,
+        token::mint = lst_mint,
+        token::authority = platform,
+    )]
+    pub platform_treasury: Account<'info, TokenAccount>,
+    
+    #[account(address = project.lst_mint)]
+    pub lst_mint: Account<'info, Mint>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// 스왑 및 보상 분배를 위한 계정 구조체
+#[derive(Accounts)]
+#[instruction(epoch: u64, min_out: u64)]
+pub struct SwapAndDistributeRewards<'info> {
+    #[account(
+        mut,
+        constraint = project.status != ProjectStatus::Cancelled @ ForestLabError::ProjectCancelled,
+    )]
+    pub project: Account<'info, Project>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(seeds = [b"platform"], bump = platform.bump)]
+    pub platform: Account<'info, Platform>,
+    
+    /// 역할 목록 (역할을 부여받은 주소가 실행하는 경우에만 필요)
+    #[account(seeds = [b"roles"], bump)]
+    pub roles: Option<Account<'info, Roles>>,
+    
+    #[account(
+        mut,
+        seeds = [b"rewards_info", project.key().as_ref(), &epoch.to_le_bytes()],
+        bump = rewards_info.bump,
+    )]
+    pub rewards_info: Account<'info, RewardsInfo>,
+    
+    #[account(seeds = [b"swap_config"], bump = swap_config.bump)]
+    pub swap_config: Account<'info, SwapConfig>,
+    
+    /// CHECK: 설정된 스왑 프로그램
+    #[account(executable, address = swap_config.swap_program)]
+    pub swap_program: UncheckedAccount<'info>,
+    
+    /// CHECK: 프로젝트 볼트 권한 PDA (데이터 없음)
+    #[account(seeds = [b"vault_authority", project.key().as_ref()], bump)]
+    pub vault_authority: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"project_share_vault", project.key().as_ref()],
+        bump,
+        token::mint = project.lst_mint,
+        token::authority = vault_authority,
+    )]
+    pub project_share_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        seeds = [b"usdc_vault", project.key().as_ref()],
+        bump,
+        token::mint = usdc_mint,
+        token::authority = vault_authority,
+    )]
+    pub usdc_vault: Account<'info, TokenAccount>,
+    
+    #[account(address = swap_config.usdc_mint)]
+    pub usdc_mint: Account<'info, Mint>,
+    
+    #[account(
+        mut,
+        token::mint = swap_config.usdc_mint,
+        token::authority = project.payout_wallet,
+    )]
+    pub payout_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        token::mint = swap_config.usdc_mint,
+        token::authority = platform.admin_wallet,
+    )]
+    pub project_fee_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// 프로젝트 보상 청구를 위한 계정 구조체
+#[derive(Accounts)]
+pub struct ClaimProjectRewards<'info> {
+    #[account(
+        mut,
+        seeds = [b"stake_info", user.key().as_ref(), project.key().as_ref()],
+        bump = stake_info.bump,
+        constraint = stake_info.user == user.key() && stake_info.project == project.key()
+    )]
+    pub stake_info: Account<'info, StakeInfo>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    pub project: Account<'info, Project>,
+    
+    #[account(seeds = [b"platform"], bump = platform.bump)]
+    pub platform: Account<'info, Platform>,
+    
+    /// CHECK: 프로젝트 볼트 권한 PDA (데이터 없음)
+    #[account(seeds = [b"vault_authority", project.key().as_ref()], bump)]
+    pub vault_authority: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"reward_vault", project.key().as_ref()],
+        bump,
+        token::mint = lst_mint,
+        token::authority = vault_authority,
+    )]
+    pub reward_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = lst_mint,
+        associated_token::authority = user,
+    )]
+    pub user_reward_account: Account<'info, TokenAccount>,
+    
+    #[account(address = project.lst_mint)]
+    pub lst_mint: Account<'info, Mint>,
+    
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// 프로젝트 설정 업데이트를 위한 계정 구조체
+#[derive(Accounts)]
+#[instruction(manager_fee_percentage: u16, payout_wallet: Option<Pubkey>)]
+pub struct UpdateProjectSettings<'info> {
+    #[account(
+        mut,
+        constraint = project.is_authority(&authority.key()),
+    )]
+    pub project: Account<'info, Project>,
+    
+    #[account(seeds = [b"platform"], bump = platform.bump)]
+    pub platform: Account<'info, Platform>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// CHECK: 프로젝트 멀티시그 PDA (미생성 가능, 핸들러에서 활성 여부 확인)
+    #[account(seeds = [b"multisig_config", project.key().as_ref()], bump)]
+    pub multisig_config: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// 프로젝트 상태 업데이트를 위한 계정 구조체
+#[derive(Accounts)]
+#[instruction(new_status: ProjectStatus, reason: StatusReason)]
+pub struct UpdateProjectStatus<'info> {
+    #[account(mut)]
+    pub project: Account<'info, Project>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(seeds = [b"platform"], bump = platform.bump)]
+    pub platform: Account<'info, Platform>,
+    
+    /// 역할 목록 (역할을 부여받은 주소가 실행하는 경우에만 필요)
+    #[account(seeds = [b"roles"], bump)]
+    pub roles: Option<Account<'info, Roles>>,
+    
+    /// CHECK: 프로젝트 멀티시그 PDA (미생성 가능, 핸들러에서 활성 여부 확인)
+    #[account(seeds = [b"multisig_config", project.key().as_ref()], bump)]
+    pub multisig_config: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// LST 락업 생성을 위한 계정 구조체
+#[derive(Accounts)]
+#[instruction(amount: u64, duration: i64)]
+pub struct CreateLockup<'info> {
+    #[account(mut, constraint = project.status == ProjectStatus::Active)]
+    pub project: Account<'info, Project>,
+    
+    #[account(seeds = [b"platform"], bump = platform.bump)]
+    pub platform: Account<'info, Platform>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = LockupCounter::SPACE,
+        seeds = [b"lockup_counter", user.key().as_ref(), project.key().as_ref()],
+        bump,
+    )]
+    pub lockup_counter: Account<'info, LockupCounter>,
+    
+    #[account(
+        init,
+        payer = user,
+        space = Lockup::SPACE,
+        seeds = [
+            b"lockup",
+            user.key().as_ref(),
+            project.key().as_ref(),
+            &lockup_counter.next_index.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub lockup: Account<'info, Lockup>,
+    
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = LockupTierConfig::SPACE,
+        seeds = [b"lockup_tier_config", project.key().as_ref()],
+        bump,
+    )]
+    pub lockup_tier_config: Account<'info, LockupTierConfig>,
+    
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = VotingPower::SPACE,
+        seeds = [b"voting_power", user.key().as_ref(), project.key().as_ref()],
+        bump,
+    )]
+    pub voting_power: Account<'info, VotingPower>,
+    
+    #[account(mut)]
+    pub user_token_account: Account<'info, TokenAccount>,  // token_interface 대신 token 모듈 사용
+    
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = lst_mint,
+        associated_token::authority = lockup,
+    )]
+    pub lockup_vault: Account<'info, TokenAccount>,  // token_interface 대신 token 모듈 사용
+    
+    /// CHECK: 프로젝트 볼트 권한 PDA (데이터 없음)
+    #[account(seeds = [b"vault_authority", project.key().as_ref()], bump)]
+    pub vault_authority: UncheckedAccount<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = user,
+        seeds = [b"bonus_vault", project.key().as_ref()],
+        bump,
+        token::mint = lst_mint,
+        token::authority = vault_authority,
+    )]
+    pub bonus_vault: Account<'info, TokenAccount>,
+    
+    #[account(address = project.lst_mint)]
+    pub lst_mint: Account<'info, Mint>,  // token_interface 대신 token 모듈 사용
+    
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// 락업 해제를 위한 계정 구조체
+#[derive(Accounts)]
+pub struct ReleaseLockup<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"lockup",
+            user.key().as_ref(),
+            project.key().as_ref(),
+            &lockup.index.to_le_bytes(),
+        ],
+        bump = lockup.bump,
+        constraint = lockup.user == user.key(),
+        constraint = !lockup.is_released,
+    )]
+    pub lockup: Account<'info, Lockup>,
+    
+    #[account(
+        mut,
+        seeds = [b"lockup_counter", user.key().as_ref(), project.key().as_ref()],
+        bump = lockup_counter.bump,
+    )]
+    pub lockup_counter: Account<'info, LockupCounter>,
+    
+    #[account(
+        mut,
+        seeds = [b"lockup_tier_config", project.key().as_ref()],
+        bump = lockup_tier_config.bump,
+    )]
+    pub lockup_tier_config: Account<'info, LockupTierConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"voting_power", user.key().as_ref(), project.key().as_ref()],
+        bump = voting_power.bump,
+    )]
+    pub voting_power: Account<'info, VotingPower>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    #[account(mut, constraint = project.key() == lockup.project)]
+    pub project: Account<'info, Project>,
+    
+    #[account(seeds = [b"platform"], bump = platform.bump)]
+    pub platform: Account<'info, Platform>,
+    
+    #[account(mut)]
+    pub user_token_account: Account<'info, TokenAccount>,  // token_interface 대신 token 모듈 사용
+    
+    #[account(
+        mut,
+        associated_token::mint = lst_mint,
+        associated_token::authority = lockup,
+    )]
+    pub lockup_vault: Account<'info, TokenAccount>,  // token_interface 대신 token 모듈 사용
+    
+    /// CHECK: 프로젝트 볼트 권한 PDA (데이터 없음)
+    #[account(seeds = [b"vault_authority", project.key().as_ref()], bump)]
+    pub vault_authority: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"bonus_vault", project.key().as_ref()],
+        bump,
+        token::mint = lst_mint,
+        token::authority = vault_authority,
+    )]
+    pub bonus_vault: Account<'info, TokenAccount>,
+    
+    #[account(address = lockup.lst_mint)]
+    pub lst_mint: Account<'info, Mint>,  // token_interface 대신 token 모듈 사용
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// 락업 연장을 위한 계정 구조체
+#[derive(Accounts)]
+pub struct ExtendLockup<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"lockup",
+            user.key().as_ref(),
+            project.key().as_ref(),
+            &lockup.index.to_le_bytes(),
+        ],
+        bump = lockup.bump,
+        constraint = lockup.user == user.key(),
+        constraint = !lockup.is_released,
+    )]
+    pub lockup: Account<'info, Lockup>,
+    
+    #[account(
+        mut,
+        seeds = [b"lockup_tier_config", project.key().as_ref()],
+        bump = lockup_tier_config.bump,
+    )]
+    pub lockup_tier_config: Account<'info, LockupTierConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"voting_power", user.key().as_ref(), project.key().as_ref()],
+        bump = voting_power.bump,
+    )]
+    pub voting_power: Account<'info, VotingPower>,
+    
+    pub user: Signer<'info>,
+    
+    #[account(
+        mut,
+        constraint = project.key() == lockup.project,
+        constraint = project.status == ProjectStatus::Active,
+    )]
+    pub project: Account<'info, Project>,
+    
+    #[account(seeds = [b"platform"], bump = platform.bump)]
+    pub platform: Account<'info, Platform>,
+    
+    #[account(
+        seeds = [b"bonus_vault", project.key().as_ref()],
+        bump,
+        token::mint = project.lst_mint,
+    )]
+    pub bonus_vault: Account<'info, TokenAccount>,
+}
+
+/// 락업 추가 예치를 위한 계정 구조체
+#[derive(Accounts)]
+pub struct IncreaseLockup<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"lockup",
+            user.key().as_ref(),
+            project.key().as_ref(),
+            &lockup.index.to_le_bytes(),
+        ],
+        bump = lockup.bump,
+        constraint = lockup.user == user.key(),
+        constraint = !lockup.is_released,
+    )]
+    pub lockup: Account<'info, Lockup>,
+    
+    #[account(
+        mut,
+        seeds = [b"lockup_tier_config", project.key().as_ref()],
+        bump = lockup_tier_config.bump,
+    )]
+    pub lockup_tier_config: Account<'info, LockupTierConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"voting_power", user.key().as_ref(), project.key().as_ref()],
+        bump = voting_power.bump,
+    )]
+    pub voting_power: Account<'info, VotingPower>,
+    
+    pub user: Signer<'info>,
+    
+    #[account(
+        mut,
+        constraint = project.key() == lockup.project,
+        constraint = project.status == ProjectStatus::Active,
+    )]
+    pub project: Account<'info, Project>,
+    
+    #[account(seeds = [b"platform"], bump = platform.bump)]
+    pub platform: Account<'info, Platform>,
+    
+    #[account(mut)]
+    pub user_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        associated_token::mint = lst_mint,
+        associated_token::authority = lockup,
+    )]
+    pub lockup_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        seeds = [b"bonus_vault", project.key().as_ref()],
+        bump,
+        token::mint = lst_mint,
+    )]
+    pub bonus_vault: Account<'info, TokenAccount>,
+    
+    #[account(address = lockup.lst_mint)]
+    pub lst_mint: Account<'info, Mint>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+/// 락업 조기 해제를 위한 계정 구조체
+#[derive(Accounts)]
+pub struct EarlyReleaseLockup<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"lockup",
+            user.key().as_ref(),
+            project.key().as_ref(),
+            &lockup.index.to_le_bytes(),
+        ],
+        bump = lockup.bump,
+        constraint = lockup.user == user.key(),
+        constraint = !lockup.is_released,
+    )]
+    pub lockup: Account<'info, Lockup>,
+    
+    #[account(
+        mut,
+        seeds = [b"lockup_counter", user.key().as_ref(), project.key().as_ref()],
+        bump = lockup_counter.bump,
+    )]
+    pub lockup_counter: Account<'info, LockupCounter>,
+    
+    #[account(
+        mut,
+        seeds = [b"lockup_tier_config", project.key().as_ref()],
+        bump = lockup_tier_config.bump,
+    )]
+    pub lockup_tier_config: Account<'info, LockupTierConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"voting_power", user.key().as_ref(), project.key().as_ref()],
+        bump = voting_power.bump,
+    )]
+    pub voting_power: Account<'info, VotingPower>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    #[account(mut, constraint = project.key() == lockup.project)]
+    pub project: Account<'info, Project>,
+    
+    #[account(seeds = [b"platform"], bump = platform.bump)]
+    pub platform: Account<'info, Platform>,
+    
+    #[account(mut)]
+    pub user_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        associated_token::mint = lst_mint,
+        associated_token::authority = lockup,
+    )]
+    pub lockup_vault: Account<'info, TokenAccount>,
+    
+    /// CHECK: 프로젝트 볼트 권한 PDA (데이터 없음)
+    #[account(seeds = [b"vault_authority", project.key().as_ref()], bump)]
+    pub vault_authority: UncheckedAccount<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = user,
+        seeds = [b"reward_vault", project.key().as_ref()],
+        bump,
+        token::mint = lst_mint,
+        token::authority = vault_authority,
+    )]
+    pub reward_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"bonus_vault", project.key().as_ref()],
+        bump,
+        token::mint = lst_mint,
+        token::authority = vault_authority,
+    )]
+    pub bonus_vault: Account<'info, TokenAccount>,
+    
+    #[account(address = lockup.lst_mint)]
+    pub lst_mint: Account<'info, Mint>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// 락업 티어 설정을 위한 계정 구조체
+#[derive(Accounts)]
+pub struct ConfigureLockupTiers<'info> {
+    #[account(constraint = project.is_authority(&authority.key()) @ ForestLabError::Unauthorized)]
+    pub project: Account<'info, Project>,
+    
+    #[account(seeds = [b"platform"], bump = platform.bump)]
+    pub platform: Account<'info, Platform>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = LockupTierConfig::SPACE,
+        seeds = [b"lockup_tier_config", project.key().as_ref()],
+        bump,
+    )]
+    pub lockup_tier_config: Account<'info, LockupTierConfig>,
+    
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// 거버넌스 제안 생성을 위한 계정 구조체
+#[derive(Accounts)]
+pub struct CreateProjectProposal<'info> {
+    #[account(
+        mut,
+        constraint = project.is_authority(&proposer.key()) @ ForestLabError::Unauthorized,
+    )]
+    pub project: Account<'info, Project>,
+    
+    #[account(seeds = [b"platform"], bump = platform.bump)]
+    pub platform: Account<'info, Platform>,
+    
+    #[account(
+        init,
+        payer = proposer,
+        space = ProjectProposal::SPACE,
+        seeds = [
+            b"project_proposal",
+            project.key().as_ref(),
+            &project.proposal_count.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub proposal: Account<'info, ProjectProposal>,
+    
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// 거버넌스 투표를 위한 계정 구조체
+#[derive(Accounts)]
+pub struct CastVote<'info> {
+    #[account(
+        mut,
+        constraint = !proposal.finalized @ ForestLabError::ProposalAlreadyFinalized,
+    )]
+    pub proposal: Account<'info, ProjectProposal>,
+    
+    #[account(seeds = [b"platform"], bump = platform.bump)]
+    pub platform: Account<'info, Platform>,
+    
+    #[account(
+        init,
+        payer = voter,
+        space = VoteRecord::SPACE,
+        seeds = [b"vote_record", proposal.key().as_ref(), voter.key().as_ref()],
+        bump,
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+    
+    #[account(
+        seeds = [b"stake_info", voter.key().as_ref(), proposal.project.as_ref()],
+        bump,
+    )]
+    pub stake_info: Option<Account<'info, StakeInfo>>,
+    
+    #[account(
+        seeds = [b"voting_power", voter.key().as_ref(), proposal.project.as_ref()],
+        bump,
+    )]
+    pub voting_power: Option<Account<'info, VotingPower>>,
+    
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// 거버넌스 제안 결과 확정을 위한 계정 구조체
+#[derive(Accounts)]
+pub struct FinalizeProposal<'info> {
+    #[account(
+        mut,
+        constraint = !proposal.finalized @ ForestLabError::ProposalAlreadyFinalized,
+    )]
+    pub proposal: Account<'info, ProjectProposal>,
+    
+    #[account(seeds = [b"platform"], bump = platform.bump)]
+    pub platform: Account<'info, Platform>,
+    
+    pub authority: Signer<'info>,
+}
+
+/// 프로젝트 펀딩 정산을 위한 계정 구조체
+#[derive(Accounts)]
+pub struct FinalizeProject<'info> {
+    #[account(
+        mut,
+        constraint = matches!(project.status, ProjectStatus::Active | ProjectStatus::Paused)
+            @ ForestLabError::ProjectAlreadyFinalized,
+    )]
+    pub project: Account<'info, Project>,
+    
+    #[account(seeds = [b"platform"], bump = platform.bump)]
+    pub platform: Account<'info, Platform>,
+    
+    pub authority: Signer<'info>,
+    
+    /// CHECK: 프로젝트 볼트 권한 PDA (데이터 없음)
+    #[account(seeds = [b"vault_authority", project.key().as_ref()], bump)]
+    pub vault_authority: UncheckedAccount<'info>,
+    
+    /// CHECK: 프로젝트 몫 볼트 PDA (미생성 가능, 핸들러에서 확인)
+    #[account(mut, seeds = [b"project_share_vault", project.key().as_ref()], bump)]
+    pub project_share_vault: UncheckedAccount<'info>,
+    
+    /// CHECK: 보상 볼트 PDA (프로젝트 몫 볼트와 함께 생성됨)
+    #[account(mut, seeds = [b"reward_vault", project.key().as_ref()], bump)]
+    pub reward_vault: UncheckedAccount<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+/// 취소된 프로젝트의 프로젝트 몫 반환을 위한 계정 구조체
+#[derive(Accounts)]
+pub struct ReturnProjectShare<'info> {
+    #[account(
+        mut,
+        constraint = project.status == ProjectStatus::Cancelled @ ForestLabError::ProjectNotCancelled,
+    )]
+    pub project: Account<'info, Project>,
+    
+    #[account(seeds = [b"platform"], bump = platform.bump)]
+    pub platform: Account<'info, Platform>,
+    
+    pub authority: Signer<'info>,
+    
+    /// CHECK: 프로젝트 볼트 권한 PDA (데이터 없음)
+    #[account(seeds = [b"vault_authority", project.key().as_ref()], bump)]
+    pub vault_authority: UncheckedAccount<'info>,
+    
+    /// CHECK: 프로젝트 몫 볼트 PDA (미생성 가능, 핸들러에서 확인)
+    #[account(mut, seeds = [b"project_share_vault", project.key().as_ref()], bump)]
+    pub project_share_vault: UncheckedAccount<'info>,
+    
+    /// CHECK: 보상 볼트 PDA (프로젝트 몫 볼트와 함께 생성됨)
+    #[account(mut, seeds = [b"reward_vault", project.key().as_ref()], bump)]
+    pub reward_vault: UncheckedAccount<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+/// 리스테이킹 포지션 출금을 위한 계정 구조체
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct WithdrawRestake<'info> {
+    pub project: Account<'info, Project>,
+    
+    pub authority: Signer<'info>,
+    
+    #[account(seeds = [b"platform"], bump = platform.bump)]
+    pub platform: Account<'info, Platform>,
+    
+    /// CHECK: 프로젝트 볼트 권한 PDA (데이터 없음)
+    #[account(seeds = [b"vault_authority", project.key().as_ref()], bump)]
+    pub vault_authority: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"restake_vault", project.key().as_ref(), target_lst_mint.key().as_ref()],
+        bump,
+        token::mint = target_lst_mint,
+        token::authority = vault_authority,
+    )]
+    pub restake_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"restake_position", project.key().as_ref(), target_lst_mint.key().as_ref()],
+        bump = restake_position.bump,
+    )]
+    pub restake_position: Account<'info, RestakePosition>,
+    
+    pub target_lst_mint: Account<'info, Mint>,
+    
+    #[account(
+        mut,
+        token::mint = target_lst_mint,
+        token::authority = project.payout_wallet,
+    )]
+    pub destination: Account<'info, TokenAccount>,
+    
+    /// CHECK: 프로젝트 멀티시그 PDA (미생성 가능, 핸들러에서 활성 여부 확인)
+    #[account(seeds = [b"multisig_config", project.key().as_ref()], bump)]
+    pub multisig_config: UncheckedAccount<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+/// 보너스 볼트 충전을 위한 계정 구조체
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct FundBonusVault<'info> {
+    pub project: Account<'info, Project>,
+    
+    #[account(seeds = [b"platform"], bump = platform.bump)]
+    pub platform: Account<'info, Platform>,
+    
+    #[account(mut)]
+    pub funder: Signer<'info>,
+    
+    #[account(
+        mut,
+        token::mint = lst_mint,
+        token::authority = funder,
+    )]
+    pub funder_token_account: Account<'info, TokenAccount>,
+    
+    /// CHECK: 프로젝트 볼트 권한 PDA (데이터 없음)
+    #[account(seeds = [b"vault_authority", project.key().as_ref()], bump)]
+    pub vault_authority: UncheckedAccount<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = funder,
+        seeds = [b"bonus_vault", project.key().as_ref()],
+        bump,
+        token::mint = lst_mint,
+        token::authority = vault_authority,
+    )]
+    pub bonus_vault: Account<'info, TokenAccount>,
+    
+    #[account(address = project.lst_mint)]
+    pub lst_mint: Account<'info, Mint>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// 크랭크 업데이트 실행을 위한 계정 구조체
+#[derive(Accounts)]
+#[instruction(epoch: u64)]
+pub struct ExecuteCrankUpdate<'info> {
+    #[account(mut)]
+    pub project: Account<'info, Project>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(seeds = [b"platform"], bump = platform.bump)]
+    pub platform: Account<'info, Platform>,
+    
+    /// 역할 목록 (역할을 부여받은 주소가 실행하는 경우에만 필요)
+    #[account(seeds = [b"roles"], bump)]
+    pub roles: Option<Account<'info, Roles>>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = CrankInfo::SPACE,
+        seeds = [b"crank_info", project.key().as_ref()],
+        bump,
+    )]
+    pub crank_info: Account<'info, CrankInfo>,
+    
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// 플랫폼 설정 업데이트를 위한 계정 구조체
+#[derive(Accounts)]
+#[instruction(
+    platform_fee: Option<u16>,
+    min_stake_amount: Option<u64>,
+    admin_wallet: Option<Pubkey>,
+    is_active: Option<bool>
+)]
+pub struct UpdatePlatformSettings<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump = platform.bump,
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    /// 역할 목록 (역할을 부여받은 주소가 실행하는 경우에만 필요)
+    #[account(seeds = [b"roles"], bump)]
+    pub roles: Option<Account<'info, Roles>>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// 예약된 플랫폼 설정 변경 실행을 위한 계정 구조체 (누구나 실행 가능)
+#[derive(Accounts)]
+pub struct ExecutePlatformSettings<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump = platform.bump,
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    /// 역할 목록 (관리자 지갑 변경 시 기본 역할 이전)
+    #[account(mut, seeds = [b"roles"], bump = roles.bump)]
+    pub roles: Account<'info, Roles>,
+    
+    pub executor: Signer<'info>,
+}
+
+/// 예약된 플랫폼 설정 변경 취소를 위한 계정 구조체
+#[derive(Accounts)]
+pub struct CancelPlatformSettings<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump = platform.bump,
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    /// 역할 목록 (역할을 부여받은 주소가 실행하는 경우에만 필요)
+    #[account(seeds = [b"roles"], bump)]
+    pub roles: Option<Account<'info, Roles>>,
+    
+    pub authority: Signer<'info>,
+}
+
+/// 플랫폼 관리자 이전 제안을 위한 계정 구조체
+#[derive(Accounts)]
+#[instruction(new_authority: Pubkey)]
+pub struct ProposePlatformAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump = platform.bump,
+        constraint = platform.authority == authority.key() @ ForestLabError::Unauthorized,
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    pub authority: Signer<'info>,
+}
+
+/// 플랫폼 관리자 이전 수락을 위한 계정 구조체
+#[derive(Accounts)]
+pub struct AcceptPlatformAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump = platform.bump,
+        constraint = platform.pending_authority == Some(new_authority.key()) @ ForestLabError::Unauthorized,
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    pub new_authority: Signer<'info>,
+}
+
+/// 플랫폼 관리자 이전 취소를 위한 계정 구조체
+#[derive(Accounts)]
+pub struct CancelPlatformAuthorityTransfer<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump = platform.bump,
+        constraint = platform.authority == authority.key() @ ForestLabError::Unauthorized,
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    pub authority: Signer<'info>,
+}
+
+/// 프로젝트 관리자 이전 제안을 위한 계정 구조체
+#[derive(Accounts)]
+#[instruction(new_authority: Pubkey)]
+pub struct TransferProjectAuthority<'info> {
+    #[account(
+        mut,
+        constraint = project.is_authority(&authority.key()) @ ForestLabError::Unauthorized,
+    )]
+    pub project: Account<'info, Project>,
+    
+    #[account(seeds = [b"platform"], bump = platform.bump)]
+    pub platform: Account<'info, Platform>,
+    
+    pub authority: Signer<'info>,
+    
+    /// CHECK: 프로젝트 멀티시그 PDA (미생성 가능, 핸들러에서 활성 여부 확인)
+    #[account(seeds = [b"multisig_config", project.key().as_ref()], bump)]
+    pub multisig_config: UncheckedAccount<'info>,
+}
+
+/// 프로젝트 관리자 이전 수락을 위한 계정 구조체
+#[derive(Accounts)]
+pub struct AcceptProjectAuthority<'info> {
+    #[account(
+        mut,
+        constraint = project.pending_authority == Some(new_authority.key()) @ ForestLabError::Unauthorized,
+    )]
+    pub project: Account<'info, Project>,
+    
+    #[account(seeds = [b"platform"], bump = platform.bump)]
+    pub platform: Account<'info, Platform>,
+    
+    pub new_authority: Signer<'info>,
+}
+
+/// 프로젝트 관리자 이전 취소를 위한 계정 구조체
+#[derive(Accounts)]
+pub struct CancelProjectAuthorityTransfer<'info> {
+    #[account(
+        mut,
+        constraint = project.is_authority(&authority.key()) @ ForestLabError::Unauthorized,
+    )]
+    pub project: Account<'info, Project>,
+    
+    pub authority: Signer<'info>,
+}
+
+/// 역할 부여를 위한 계정 구조체
+#[derive(Accounts)]
+#[instruction(member: Pubkey, role: Role)]
+pub struct GrantRole<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(seeds = [b"platform"], bump = platform.bump)]
+    pub platform: Account<'info, Platform>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = Roles::SPACE,
+        seeds = [b"roles"],
+        bump,
+    )]
+    pub roles: Account<'info, Roles>,
+    
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// 역할 회수를 위한 계정 구조체
+#[derive(Accounts)]
+#[instruction(member: Pubkey, role: Role)]
+pub struct RevokeRole<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(seeds = [b"platform"], bump = platform.bump)]
+    pub platform: Account<'info, Platform>,
+    
+    #[account(mut, seeds = [b"roles"], bump = roles.bump)]
+    pub roles: Account<'info, Roles>,
+}
+
+/// 작업 일시 중지를 위한 계정 구조체
+#[derive(Accounts)]
+pub struct PauseOperations<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(mut, seeds = [b"platform"], bump = platform.bump)]
+    pub platform: Account<'info, Platform>,
+    
+    /// 역할 목록 (역할을 부여받은 주소가 실행하는 경우에만 필요)
+    #[account(seeds = [b"roles"], bump)]
+    pub roles: Option<Account<'info, Roles>>,
+}
+
+/// 작업 재개를 위한 계정 구조체
+#[derive(Accounts)]
+pub struct UnpauseOperations<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(mut, seeds = [b"platform"], bump = platform.bump)]
+    pub platform: Account<'info, Platform>,
+    
+    /// 역할 목록 (역할을 부여받은 주소가 실행하는 경우에만 필요)
+    #[account(seeds = [b"roles"], bump)]
+    pub roles: Option<Account<'info, Roles>>,
+}
+
+/// 크랭커 관리를 위한 계정 구조체
+#[derive(Accounts)]
+#[instruction(add_crankers: Vec<Pubkey>, remove_crankers: Vec<Pubkey>)]
+pub struct ManageCrankers<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(seeds = [b"platform"], bump = platform.bump)]
+    pub platform: Account<'info, Platform>,
+    
+    /// 역할 목록 (역할을 부여받은 주소가 실행하는 경우에만 필요)
+    #[account(seeds = [b"roles"], bump)]
+    pub roles: Option<Account<'info, Roles>>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = CrankInfo::SPACE,
+        seeds = [b"crankers"],
+        bump,
+    )]
+    pub crank_info: Account<'info, CrankInfo>,
+    
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// 리스테이킹 설정을 위한 계정 구조체
+#[derive(Accounts)]
+#[instruction(target_lst_mint: Pubkey, restake_percentage: u16)]
+pub struct SetupRestaking<'info> {
+    #[account(mut)]
+    pub project: Account<'info, Project>,
+    
+    #[account(seeds = [b"platform"], bump = platform.bump)]
+    pub platform: Account<'info, Platform>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = RestakeConfig::SPACE,
+        seeds = [b"restake_config", project.key().as_ref()],
+        bump,
+    )]
+    pub restake_config: Account<'info, RestakeConfig>,
+    
+    /// CHECK: 프로젝트 멀티시그 PDA (미생성 가능, 핸들러에서 활성 여부 확인)
+    #[account(seeds = [b"multisig_config", project.key().as_ref()], bump)]
+    pub multisig_config: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// 멀티시그 관리 설정을 위한 계정 구조체
+#[derive(Accounts)]
+#[instruction(signers: Vec<Pubkey>, threshold: u8)]
+pub struct SetupMultisigManagement<'info> {
+    #[account(mut)]
+    pub project: Account<'info, Project>,
+    
+    #[account(seeds = [b"platform"], bump = platform.bump)]
+    pub platform: Account<'info, Platform>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = MultisigConfig::SPACE,
+        seeds = [b"multisig_config", project.key().as_ref()],
+        bump,
+    )]
+    pub multisig_config: Account<'info, MultisigConfig>,
+    
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// 멀티시그 제안 생성을 위한 계정 구조체
+#[derive(Accounts)]
+#[instruction(action: ProjectAction)]
+pub struct CreateProposal<'info> {
+    #[account(constraint = project.key() == multisig_config.project)]
+    pub project: Account<'info, Project>,
+    
+    #[account(seeds = [b"platform"], bump = platform.bump)]
+    pub platform: Account<'info, Platform>,
+    
+    #[account(
+        mut,
+        seeds = [b"multisig_config", project.key().as_ref()],
+        bump = multisig_config.bump,
+    )]
+    pub multisig_config: Account<'info, MultisigConfig>,
+    
+    #[account(
+        init,
+        payer = proposer,
+        space = MultisigProposal::SPACE,
+        seeds = [
+            b"multisig_proposal",
+            multisig_config.key().as_ref(),
+            &multisig_config.proposal_count.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+    
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// 멀티시그 제안 승인을 위한 계정 구조체
+#[derive(Accounts)]
+pub struct ApproveProposal<'info> {
+    #[account(
+        seeds = [b"multisig_config", multisig_config.project.as_ref()],
+        bump = multisig_config.bump,
+    )]
+    pub multisig_config: Account<'info, MultisigConfig>,
+    
+    #[account(seeds = [b"platform"], bump = platform.bump)]
+    pub platform: Account<'info, Platform>,
+    
+    #[account(
+        mut,
+        seeds = [
+            b"multisig_proposal",
+            multisig_config.key().as_ref(),
+            &proposal.index.to_le_bytes(),
+        ],
+        bump = proposal.bump,
+        constraint = proposal.multisig_config == multisig_config.key(),
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+    
+    pub signer: Signer<'info>,
+}
+
+/// 멀티시그 제안 실행을 위한 계정 구조체
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    #[account(mut, constraint = project.key() == multisig_config.project)]
+    pub project: Account<'info, Project>,
+    
+    #[account(seeds = [b"platform"], bump = platform.bump)]
+    pub platform: Account<'info, Platform>,
+    
+    #[account(
+        mut,
+        seeds = [b"multisig_config", project.key().as_ref()],
+        bump = multisig_config.bump,
+    )]
+    pub multisig_config: Account<'info, MultisigConfig>,
+    
+    #[account(
+        mut,
+        seeds = [
+            b"multisig_proposal",
+            multisig_config.key().as_ref(),
+            &proposal.index.to_le_bytes(),
+        ],
+        bump = proposal.bump,
+        constraint = proposal.multisig_config == multisig_config.key(),
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+    
+    #[account(mut)]
+    pub executor: Signer<'info>,
+    
+    /// 리스테이킹 작업 실행 시에만 필요
+    #[account(
+        init_if_needed,
+        payer = executor,
+        space = RestakeConfig::SPACE,
+        seeds = [b"restake_config", project.key().as_ref()],
+        bump,
+    )]
+    pub restake_config: Option<Account<'info, RestakeConfig>>,
+    
+    /// CHECK: 프로젝트 볼트 권한 PDA (리스테이킹 출금 실행 시에만 필요)
+    #[account(seeds = [b"vault_authority", project.key().as_ref()], bump)]
+    pub vault_authority: Option<UncheckedAccount<'info>>,
+    
+    /// 리스테이킹 출금 실행 시에만 필요 (민트는 핸들러에서 제안과 대조)
+    #[account(
+        mut,
+        seeds = [b"restake_vault", project.key().as_ref(), restake_vault.mint.as_ref()],
+        bump,
+        token::authority = vault_authority,
+    )]
+    pub restake_vault: Option<Account<'info, TokenAccount>>,
+    
+    /// 리스테이킹 출금 실행 시에만 필요
+    #[account(
+        mut,
+        seeds = [b"restake_position", project.key().as_ref(), restake_position.target_lst_mint.as_ref()],
+        bump = restake_position.bump,
+    )]
+    pub restake_position: Option<Account<'info, RestakePosition>>,
+    
+    /// 리스테이킹 출금 실행 시에만 필요 (지불 지갑 소유 계정)
+    #[account(mut, token::authority = project.payout_wallet)]
+    pub restake_destination: Option<Account<'info, TokenAccount>>,
+    
+    pub token_program: Option<Program<'info, Token>>,
+    
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// 리스테이킹 실행을 위한 계정 구조체
+#[derive(Accounts)]
+#[instruction(epoch: u64, min_out: u64)]
+pub struct ExecuteRestake<'info> {
+    #[account(constraint = project.status != ProjectStatus::Cancelled @ ForestLabError::ProjectCancelled)]
+    pub project: Account<'info, Project>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(seeds = [b"platform"], bump = platform.bump)]
+    pub platform: Account<'info, Platform>,
+    
+    /// 역할 목록 (역할을 부여받은 주소가 실행하는 경우에만 필요)
+    #[account(seeds = [b"roles"], bump)]
+    pub roles: Option<Account<'info, Roles>>,
+    
+    /// 허가된 크랭커 목록 (크랭커가 실행하는 경우에만 필요)
+    #[account(seeds = [b"crankers"], bump)]
+    pub crankers: Option<Account<'info, CrankInfo>>,
+    
+    #[account(
+        seeds = [b"restake_config", project.key().as_ref()],
+        bump = restake_config.bump,
+    )]
+    pub restake_config: Account<'info, RestakeConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"rewards_info", project.key().as_ref(), &epoch.to_le_bytes()],
+        bump = rewards_info.bump,
+    )]
+    pub rewards_info: Account<'info, RewardsInfo>,
+    
+    /// CHECK: 프로젝트 볼트 권한 PDA (데이터 없음)
+    #[account(seeds = [b"vault_authority", project.key().as_ref()], bump)]
+    pub vault_authority: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"project_share_vault", project.key().as_ref()],
+        bump,
+        token::mint = project.lst_mint,
+        token::authority = vault_authority,
+    )]
+    pub project_share_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        seeds = [b"restake_vault", project.key().as_ref(), target_lst_mint.key().as_ref()],
+        bump,
+        token::mint = target_lst_mint,
+        token::authority = vault_authority,
+    )]
+    pub restake_vault: Account<'info, TokenAccount>,
+    
+    #[account(address = restake_config.target_lst_mint)]
+    pub target_lst_mint: Account<'info, Mint>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = RestakePosition::SPACE,
+        seeds = [b"restake_position", project.key().as_ref(), target_lst_mint.key().as_ref()],
+        bump,
+    )]
+    pub restake_position: Account<'info, RestakePosition>,
+    
+    #[account(seeds = [b"swap_config"], bump = swap_config.bump)]
+    pub swap_config: Account<'info, SwapConfig>,
+    
+    /// CHECK: 설정된 스왑 프로그램
+    #[account(executable, address = swap_config.swap_program)]
+    pub swap_program: UncheckedAccount<'info>,
+    
+    /// CHECK: 타겟 LST의 스테이크 풀 계정 (가치 평가용, 핸들러에서 검증)
+    pub target_stake_pool: UncheckedAccount<'info>,
+    
+    /// CHECK: 타겟 스테이크 풀 프로그램 (주소 고정, 스테이크 풀 계정 소유자와 대조)
+    #[account(executable, address = SPL_STAKE_POOL_PROGRAM_ID)]
+    pub target_stake_pool_program: UncheckedAccount<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// 플랫폼 수수료 출금을 위한 계정 구조체
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct WithdrawPlatformFees<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump = platform.bump,
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    /// 역할 목록 (역할을 부여받은 주소가 실행하는 경우에만 필요)
+    #[account(seeds = [b"roles"], bump)]
+    pub roles: Option<Account<'info, Roles>>,
+    
+    #[account(
+        mut,
+        seeds = [b"platform_treasury", mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = platform,
+    )]
+    pub platform_treasury: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = platform.admin_wallet,
+    )]
+    pub admin_token_account: Account<'info, TokenAccount>,
+    
+    pub mint: Account<'info, Mint>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+/// 보상 스왑 설정을 위한 계정 구조체
+#[derive(Accounts)]
+#[instruction(swap_program: Pubkey, adapter: SwapAdapterKind, usdc_mint: Pubkey)]
+pub struct ConfigureSwap<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"platform"],
+        bump = platform.bump,
+    )]
+    pub platform: Account<'info, Platform>,
+    
+    /// 역할 목록 (역할을 부여받은 주소가 실행하는 경우에만 필요)
+    #[account(seeds = [b"roles"], bump)]
+    pub roles: Option<Account<'info, Roles>>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = SwapConfig::SPACE,
+        seeds = [b"swap_config"],
+        bump,
+    )]
+    pub swap_config: Account<'info, SwapConfig>,
+    
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// 구버전 플랫폼 계정 마이그레이션을 위한 계정 구조체
+#[derive(Accounts)]
+pub struct MigratePlatform<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// CHECK: 구버전 레이아웃 플랫폼 계정 (핸들러에서 소유 프로그램, 디스크리미네이터, 크기 검증)
+    #[account(mut, seeds = [b"platform"], bump)]
+    pub platform: UncheckedAccount<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = Roles::SPACE,
+        seeds = [b"roles"],
+        bump,
+    )]
+    pub roles: Account<'info, Roles>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// 구버전 프로젝트 계정 마이그레이션을 위한 계정 구조체
+#[derive(Accounts)]
+pub struct MigrateProject<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// CHECK: 구버전 레이아웃 프로젝트 계정 (핸들러에서 소유 프로그램, 디스크리미네이터, PDA 검증)
+    #[account(mut)]
+    pub project: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// 구버전 스테이킹 정보 계정 마이그레이션을 위한 계정 구조체
+#[derive(Accounts)]
+pub struct MigrateStakeInfo<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// 마이그레이션이 끝난 프로젝트 계정
+    #[account(mut)]
+    pub project: Account<'info, Project>,
+    
+    /// CHECK: 구버전 레이아웃 스테이킹 정보 계정 (핸들러에서 소유 프로그램, 디스크리미네이터, PDA 검증)
+    #[account(mut)]
+    pub stake_info: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// 구버전 에포크 보상 정보 계정 마이그레이션을 위한 계정 구조체
+#[derive(Accounts)]
+pub struct MigrateRewardsInfo<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// CHECK: 구버전 레이아웃 에포크 보상 정보 계정 (핸들러에서 소유 프로그램, 디스크리미네이터, PDA 검증)
+    #[account(mut)]
+    pub rewards_info: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// 구버전 멀티시그 설정 계정 마이그레이션을 위한 계정 구조체
+#[derive(Accounts)]
+pub struct MigrateMultisigConfig<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// CHECK: 구버전 레이아웃 멀티시그 설정 계정 (핸들러에서 소유 프로그램, 디스크리미네이터, PDA 검증)
+    #[account(mut)]
+    pub multisig_config: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// 구버전 락업(인덱스 없는 PDA) 해제를 위한 계정 구조체
+#[derive(Accounts)]
+pub struct ReleaseLegacyLockup<'info> {
+    /// CHECK: 구버전 레이아웃 락업 계정 (인덱스 없는 시드, 핸들러에서 데이터 검증)
+    #[account(
+        mut,
+        seeds = [b"lockup", user.key().as_ref(), project.key().as_ref()],
+        bump,
+    )]
+    pub lockup: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    pub project: Account<'info, Project>,
+    
+    #[account(seeds = [b"platform"], bump = platform.bump)]
+    pub platform: Account<'info, Platform>,
+    
+    #[account(mut, token::mint = lst_mint, token::authority = user)]
+    pub user_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        associated_token::mint = lst_mint,
+        associated_token::authority = lockup,
+    )]
+    pub lockup_vault: Account<'info, TokenAccount>,
+    
+    #[account(address = project.lst_mint)]
+    pub lst_mint: Account<'info, Mint>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[program]
+pub mod forest_lab {
+    use super::*;
+
+    /// Forest Lab 플랫폼 초기화 함수
+    pub fn initialize_platform(
+        ctx: Context<InitializePlatform>,
+        platform_fee: u16,
+        min_stake_amount: u64,
+        admin_wallet: Pubkey,
+    ) -> Result<()> {
+        // 수수료 비율 검증 (최대 2.5%)
+        require!(platform_fee <= 250, ForestLabError::InvalidFeePercentage);
+
+        let platform = &mut ctx.accounts.platform;
+        let payer = &ctx.accounts.payer;
+
+        // 플랫폼 상태 초기화
+        platform.authority = payer.key();
+        platform.admin_wallet = admin_wallet;
+        platform.platform_fee = platform_fee;
+        platform.min_stake_amount = min_stake_amount;
+        platform.is_active = true;
+        platform.created_at = Clock::get()?.unix_timestamp;
+        platform.bump = ctx.bumps.platform;
+        platform.total_projects = 0;
+        platform.total_staked_sol = 0;
+        platform.fees_collected = Vec::new();
+        platform.pending_authority = None;
+        platform.paused_operations = 0;
+        platform.settings_timelock = Platform::DEFAULT_SETTINGS_TIMELOCK;
+        platform.clear_pending_settings();
+
+        // 역할 목록 생성 (관리자 지갑에 보상 운영자, 프로젝트 중재자 역할 부여)
+        initialize_roles(
+            &mut ctx.accounts.roles,
+            platform.key(),
+            admin_wallet,
+            ctx.bumps.roles,
+            payer.key(),
+        )?;
+
+        // 플랫폼 생성 이벤트 발행
+        emit!(PlatformCreatedEvent {
+            platform: platform.key(),
+            authority: platform.authority,
+            admin_wallet,
+            platform_fee,
+            min_stake_amount,
+        });
+
+        Ok(())
+    }
+
+    /// 프로젝트 등록 함수
+    #[allow(clippy::too_many_arguments)]
+    pub fn register_project(
+        ctx: Context<RegisterProject>,
+        name: String,
+        symbol: String,
+        description: String,
+        website: String,
+        image_uri: String,
+        funding_goal: u64,
+        duration: i64,
+        lst_mint: Pubkey,
+        apy_estimate: u16,
+        start_time: Option<i64>,
+        max_raise: Option<u64>,
+    ) -> Result<()> {
+        // 플랫폼이 활성화되어 있고 프로젝트 등록이 일시 중지되지 않았는지 확인
+        ctx.accounts.platform.require_not_paused(Platform::PAUSE_REGISTRATION)?;
+
+        // 기본 검증
+        require!(!name.is_empty(), ForestLabError::EmptyName);
+        require!(!symbol.is_empty(), ForestLabError::EmptySymbol);
+        require!(funding_goal > 0, ForestLabError::InvalidFundingGoal);
+        require!(duration > 0, ForestLabError::InvalidDuration);
+
+        // 펀딩 시작 시간 (지정하지 않으면 즉시 시작) 및 최대 모금 한도 검증
+        let current_time = Clock::get()?.unix_timestamp;
+        let start_time = start_time.unwrap_or(current_time);
+        require!(start_time >= current_time, ForestLabError::InvalidDuration);
+        let max_raise = max_raise.unwrap_or(0);
+        require!(
+            max_raise == 0 || max_raise >= funding_goal,
+            ForestLabError::InvalidFundingGoal
+        );
+
+        let project = &mut ctx.accounts.project;
+        let creator = &ctx.accounts.creator;
+        let platform = &mut ctx.accounts.platform;
+
+        // 프로젝트 정보 초기화
+        project.creator = creator.key();
+        project.platform = platform.key();
+        project.name = name.clone();
+        project.symbol = symbol.clone();
+        project.description = description;
+        project.website = website;
+        project.image_uri = image_uri;
+        project.funding_goal = funding_goal;
+        project.funds_raised = 0;
+        project.supporters_count = 0;
+        project.lst_mint = lst_mint;
+        project.status = ProjectStatus::Active;
+        project.status_reason = StatusReason::Registered;
+        project.status_actor = StatusActor::ProjectAuthority;
+        project.status_updated_at = current_time;
+        project.authority = creator.key();
+        project.pending_authority = None;
+        project.created_at = current_time;
+        project.start_time = start_time;
+        project.end_time = start_time
+            .checked_add(duration)
+            .ok_or(ForestLabError::InvalidDuration)?;
+        project.max_raise = max_raise;
+        project.campaign_started = false;
+        project.campaign_ended = false;
+        project.funds_claimed = false;
+        project.manager_fee_percentage = 250; // 기본 2.5%
+        project.payout_wallet = creator.key(); // 기본값은 생성자 지갑
+        project.apy_estimate = apy_estimate; // 예상 APY (100 = 1%)
+        project.total_rewards_distributed = 0;
+        project.total_staked_lst = 0;
+        project.acc_reward_per_share = 0;
+        project.bonus_committed = 0;
+        project.proposal_count = 0;
+        project.bump = ctx.bumps.project;
+
+        // 플랫폼 통계 업데이트
+        platform.total_projects = platform.total_projects.saturating_add(1);
+
+        // 프로젝트 등록 이벤트 발행
+        emit!(ProjectRegisteredEvent {
+            project: project.key(),
+            creator: creator.key(),
+            platform: platform.key(),
+            name,
+            symbol: project.symbol.clone(),
+            funding_goal,
+            start_time,
+            end_time: project.end_time,
+            max_raise,
+            lst_mint,
+            apy_estimate,
+        });
+
+        Ok(())
+    }
+
+    /// SOL 스테이킹 함수 (SPL Stake Pool DepositSol CPI 후 실제 발행된 LST 기록)
+    pub fn stake_sol(
+        ctx: Context<StakeSol>,
+        amount: u64,
+    ) -> Result<()> {
+        // 프로젝트가 활성 상태인지 확인
+        require!(
+            ctx.accounts.project.status == ProjectStatus::Active,
+            ForestLabError::ProjectInactive
+        );
+
+        // 플랫폼이 활성화되어 있고 스테이킹이 일시 중지되지 않았는지 확인
+        ctx.accounts.platform.require_not_paused(Platform::PAUSE_STAKE)?;
+
+        // 최소 스테이킹 금액 확인
+        require!(
+            amount >= ctx.accounts.platform.min_stake_amount,
+            ForestLabError::BelowMinimumStakeAmount
+        );
+
+        // 펀딩 기간 및 최대 모금 한도 확인
+        require_campaign_open(&ctx.accounts.project)?;
+        let project = &ctx.accounts.project;
+        require!(
+            project.max_raise == 0 || project.funds_raised.saturating_add(amount) <= project.max_raise,
+            ForestLabError::MaxRaiseExceeded
+        );
+
+        // 스테이크 풀 계정 검증
+        let stake_pool_program = ctx.accounts.stake_pool_program.key();
+        let pool = StakePoolState::load(&ctx.accounts.stake_pool, &stake_pool_program)?;
+        pool.check_withdraw_authority(
+            &ctx.accounts.stake_pool.key(),
+            &ctx.accounts.stake_pool_withdraw_authority.key(),
+            &stake_pool_program,
+        )?;
+        require_keys_eq!(pool.pool_mint, ctx.accounts.lst_mint.key(), ForestLabError::InvalidStakePool);
+        require_keys_eq!(pool.reserve_stake, ctx.accounts.reserve_stake.key(), ForestLabError::InvalidStakePool);
+        require_keys_eq!(
+            pool.manager_fee_account,
+            ctx.accounts.manager_fee_account.key(),
+            ForestLabError::InvalidStakePool
+        );
+
+        // DepositSol 전 LST 및 SOL 잔액
+        let lst_before = ctx.accounts.user_lst_account.amount;
+        let lamports_before = ctx.accounts.user.lamports();
+
+        // SPL Stake Pool DepositSol CPI (추천 수수료는 매니저 수수료 계정으로)
+        let deposit_ix = stake_pool_instruction(
+            &stake_pool_program,
+            STAKE_POOL_IX_DEPOSIT_SOL,
+            amount,
+            vec![
+                AccountMeta::new(ctx.accounts.stake_pool.key(), false),
+                AccountMeta::new_readonly(ctx.accounts.stake_pool_withdraw_authority.key(), false),
+                AccountMeta::new(ctx.accounts.reserve_stake.key(), false),
+                AccountMeta::new(ctx.accounts.user.key(), true),
+                AccountMeta::new(ctx.accounts.user_lst_account.key(), false),
+                AccountMeta::new(ctx.accounts.manager_fee_account.key(), false),
+                AccountMeta::new(ctx.accounts.manager_fee_account.key(), false),
+                AccountMeta::new(ctx.accounts.lst_mint.key(), false),
+                AccountMeta::new_readonly(ctx.accounts.system_program.key(), false),
+                AccountMeta::new_readonly(ctx.accounts.token_program.key(), false),
+            ],
+        );
+        invoke(
+            &deposit_ix,
+            &[
+                ctx.accounts.stake_pool.to_account_info(),
+                ctx.accounts.stake_pool_withdraw_authority.to_account_info(),
+                ctx.accounts.reserve_stake.to_account_info(),
+                ctx.accounts.user.to_account_info(),
+                ctx.accounts.user_lst_account.to_account_info(),
+                ctx.accounts.manager_fee_account.to_account_info(),
+                ctx.accounts.lst_mint.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+                ctx.accounts.token_program.to_account_info(),
+                ctx.accounts.stake_pool_program.to_account_info(),
+            ],
+        )?;
+
+        // 실제 발행된 LST 수량 측정
+        ctx.accounts.user_lst_account.reload()?;
+        let lst_amount = ctx.accounts.user_lst_account.amount.saturating_sub(lst_before);
+        require!(lst_amount > 0, ForestLabError::NoLstMinted);
+
+        // 실제 차감된 SOL 수량 측정 (요청 금액이 아닌 잔액 변화량을 기록)
+        let sol_amount = lamports_before.saturating_sub(ctx.accounts.user.lamports());
+        require!(sol_amount > 0 && sol_amount <= amount, ForestLabError::InvalidAmount);
+
+        // 스테이킹 기록 생성 또는 업데이트
+        let user = ctx.accounts.user.key();
+        let is_new_supporter = apply_stake(
+            &mut ctx.accounts.stake_info,
+            &mut ctx.accounts.project,
+            &mut ctx.accounts.platform,
+            user,
+            sol_amount,
+            lst_amount,
+            ctx.bumps.stake_info,
+        )?;
+
+        // 펀딩 경계 갱신 (이번 스테이킹으로 최대 모금 한도에 도달했으면 펀딩 종료)
+        refresh_campaign_phase(&mut ctx.accounts.project)?;
+
+        // 스테이킹 이벤트 발행
+        emit!(ProjectStakedEvent {
+            project: ctx.accounts.project.key(),
+            user,
+            amount: sol_amount,
+            lst_amount,
+            is_new_supporter,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// SOL 언스테이킹 함수 (SPL Stake Pool WithdrawSol CPI로 LST 소각 후 기록)
+    pub fn unstake_sol(
+        ctx: Context<UnstakeSol>,
+        lst_amount: u64,
+    ) -> Result<()> {
+        // 언스테이킹이 일시 중지되었는지 확인
+        ctx.accounts.platform.require_not_paused(Platform::PAUSE_UNSTAKE)?;
+
+        require!(lst_amount > 0, ForestLabError::InvalidAmount);
+
+        // LST 잔액이 충분한지 확인
+        require!(
+            ctx.accounts.stake_info.current_lst_amount >= lst_amount,
+            ForestLabError::InsufficientLstBalance
+        );
+
+        // 스테이크 풀 계정 검증
+        let stake_pool_program = ctx.accounts.stake_pool_program.key();
+        let pool = StakePoolState::load(&ctx.accounts.stake_pool, &stake_pool_program)?;
+        pool.check_withdraw_authority(
+            &ctx.accounts.stake_pool.key(),
+            &ctx.accounts.stake_pool_withdraw_authority.key(),
+            &stake_pool_program,
+        )?;
+        require_keys_eq!(pool.pool_mint, ctx.accounts.lst_mint.key(), ForestLabError::InvalidStakePool);
+        require_keys_eq!(pool.reserve_stake, ctx.accounts.reserve_stake.key(), ForestLabError::InvalidStakePool);
+        require_keys_eq!(
+            pool.manager_fee_account,
+            ctx.accounts.manager_fee_account.key(),
+            ForestLabError::InvalidStakePool
+        );
+
+        // WithdrawSol 전 잔액
+        let lst_before = ctx.accounts.user_lst_account.amount;
+        let lamports_before = ctx.accounts.user.lamports();
+
+        // SPL Stake Pool WithdrawSol CPI
+        let withdraw_ix = stake_pool_instruction(
+            &stake_pool_program,
+            STAKE_POOL_IX_WITHDRAW_SOL,
+            lst_amount,
+            vec![
+                AccountMeta::new(ctx.accounts.stake_pool.key(), false),
+                AccountMeta::new_readonly(ctx.accounts.stake_pool_withdraw_authority.key(), false),
+                AccountMeta::new_readonly(ctx.accounts.user.key(), true),
+                AccountMeta::new(ctx.accounts.user_lst_account.key(), false),
+                AccountMeta::new(ctx.accounts.reserve_stake.key(), false),
+                AccountMeta::new(ctx.accounts.user.key(), false),
+                AccountMeta::new(ctx.accounts.manager_fee_account.key(), false),
+                AccountMeta::new(ctx.accounts.lst_mint.key(), false),
+                AccountMeta::new_readonly(ctx.accounts.clock.key(), false),
+                AccountMeta::new_readonly(ctx.accounts.stake_history.key(), false),
+                AccountMeta::new_readonly(ctx.accounts.stake_program.key(), false),
+                AccountMeta::new_readonly(ctx.accounts.token_program.key(), false),
+            ],
+        );
+        invoke(
+            &withdraw_ix,
+            &[
+                ctx.accounts.stake_pool.to_account_info(),
+                ctx.accounts.stake_pool_withdraw_authority.to_account_info(),
+                ctx.accounts.user.to_account_info(),
+                ctx.accounts.user_lst_account.to_account_info(),
+                ctx.accounts.reserve_stake.to_account_info(),
+                ctx.accounts.manager_fee_account.to_account_info(),
+                ctx.accounts.lst_mint.to_account_info(),
+                ctx.accounts.clock.to_account_info(),
+                ctx.accounts.stake_history.to_account_info(),
+                ctx.accounts.stake_program.to_account_info(),
+                ctx.accounts.token_program.to_account_info(),
+                ctx.accounts.stake_pool_program.to_account_info(),
+            ],
+        )?;
+
+        // 실제 소각된 LST와 수령한 lamports 확인
+        ctx.accounts.user_lst_account.reload()?;
+        let lst_burned = lst_before.saturating_sub(ctx.accounts.user_lst_account.amount);
+        require!(lst_burned == lst_amount, ForestLabError::LstBalanceMismatch);
+        let sol_received = ctx.accounts.user.lamports().saturating_sub(lamports_before);
+        require!(sol_received > 0, ForestLabError::NoLamportsReceived);
+
+        // 스테이킹 정보 업데이트
+        apply_unstake(
+            &mut ctx.accounts.stake_info,
+            &mut ctx.accounts.project,
+            &mut ctx.accounts.platform,
+            lst_burned,
+        )?;
+        refresh_campaign_phase(&mut ctx.accounts.project)?;
+
+        // 언스테이킹 이벤트 발행
+        emit!(ProjectUnstakedEvent {
+            project: ctx.accounts.project.key(),
+            user: ctx.accounts.user.key(),
+            sol_amount: sol_received,
+            lst_amount: lst_burned,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// 대량 언스테이킹 함수 (SPL Stake Pool WithdrawStake CPI로 스테이크 계정 수령)
+    pub fn unstake_stake(
+        ctx: Context<UnstakeStake>,
+        lst_amount: u64,
+    ) -> Result<()> {
+        // 언스테이킹이 일시 중지되었는지 확인
+        ctx.accounts.platform.require_not_paused(Platform::PAUSE_UNSTAKE)?;
+
+        require!(lst_amount > 0, ForestLabError::InvalidAmount);
+
+        // LST 잔액이 충분한지 확인
+        require!(
+            ctx.accounts.stake_info.current_lst_amount >= lst_amount,
+            ForestLabError::InsufficientLstBalance
+        );
+
+        // 스테이크 풀 계정 검증
+        let stake_pool_program = ctx.accounts.stake_pool_program.key();
+        let pool = StakePoolState::load(&ctx.accounts.stake_pool, &stake_pool_program)?;
+        pool.check_withdraw_authority(
+            &ctx.accounts.stake_pool.key(),
+            &ctx.accounts.stake_pool_withdraw_authority.key(),
+            &stake_pool_program,
+        )?;
+        require_keys_eq!(pool.pool_mint, ctx.accounts.lst_mint.key(), ForestLabError::InvalidStakePool);
+        require_keys_eq!(pool.validator_list, ctx.accounts.validator_list.key(), ForestLabError::InvalidStakePool);
+        require_keys_eq!(
+            pool.manager_fee_account,
+            ctx.accounts.manager_fee_account.key(),
+            ForestLabError::InvalidStakePool
+        );
+
+        // WithdrawStake 전 잔액
+        let lst_before = ctx.accounts.user_lst_account.amount;
+        let lamports_before = ctx.accounts.stake_to_receive.lamports();
+
+        // SPL Stake Pool WithdrawStake CPI (새 스테이크 계정의 권한은 사용자)
+        let withdraw_ix = stake_pool_instruction(
+            &stake_pool_program,
+            STAKE_POOL_IX_WITHDRAW_STAKE,
+            lst_amount,
+            vec![
+                AccountMeta::new(ctx.accounts.stake_pool.key(), false),
+                AccountMeta::new(ctx.accounts.validator_list.key(), false),
+                AccountMeta::new_readonly(ctx.accounts.stake_pool_withdraw_authority.key(), false),
+                AccountMeta::new(ctx.accounts.stake_to_split.key(), false),
+                AccountMeta::new(ctx.accounts.stake_to_receive.key(), false),
+                AccountMeta::new_readonly(ctx.accounts.user.key(), false),
+                AccountMeta::new_readonly(ctx.accounts.user.key(), true),
+                AccountMeta::new(ctx.accounts.user_lst_account.key(), false),
+                AccountMeta::new(ctx.accounts.manager_fee_account.key(), false),
+                AccountMeta::new(ctx.accounts.lst_mint.key(), false),
+                AccountMeta::new_readonly(ctx.accounts.clock.key(), false),
+                AccountMeta::new_readonly(ctx.accounts.token_program.key(), false),
+                AccountMeta::new_readonly(ctx.accounts.stake_program.key(), false),
+            ],
+        );
+        invoke(
+            &withdraw_ix,
+            &[
+                ctx.accounts.stake_pool.to_account_info(),
+                ctx.accounts.validator_list.to_account_info(),
+                ctx.accounts.stake_pool_withdraw_authority.to_account_info(),
+                ctx.accounts.stake_to_split.to_account_info(),
+                ctx.accounts.stake_to_receive.to_account_info(),
+                ctx.accounts.user.to_account_info(),
+                ctx.accounts.user_lst_account.to_account_info(),
+                ctx.accounts.manager_fee_account.to_account_info(),
+                ctx.accounts.lst_mint.to_account_info(),
+                ctx.accounts.clock.to_account_info(),
+                ctx.accounts.token_program.to_account_info(),
+                ctx.accounts.stake_program.to_account_info(),
+                ctx.accounts.stake_pool_program.to_account_info(),
+            ],
+        )?;
+
+        // 실제 소각된 LST와 새 스테이크 계정에 들어온 lamports 확인
+        ctx.accounts.user_lst_account.reload()?;
+        let lst_burned = lst_before.saturating_sub(ctx.accounts.user_lst_account.amount);
+        require!(lst_burned == lst_amount, ForestLabError::LstBalanceMismatch);
+        let sol_received = ctx.accounts.stake_to_receive.lamports().saturating_sub(lamports_before);
+        require!(sol_received > 0, ForestLabError::NoLamportsReceived);
+
+        // 스테이킹 정보 업데이트
+        apply_unstake(
+            &mut ctx.accounts.stake_info,
+            &mut ctx.accounts.project,
+            &mut ctx.accounts.platform,
+            lst_burned,
+        )?;
+        refresh_campaign_phase(&mut ctx.accounts.project)?;
+
+        // 언스테이킹 이벤트 발행
+        emit!(ProjectUnstakedEvent {
+            project: ctx.accounts.project.key(),
+            user: ctx.accounts.user.key(),
+            sol_amount: sol_received,
+            lst_amount: lst_burned,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// 에포크 보상 처리 및 기록 함수
+    pub fn process_epoch_rewards(
+        ctx: Context<ProcessEpochRewards>,
+        epoch: u64,
+        total_rewards: u64,
+    ) -> Result<()> {
+        // 보상 처리가 일시 중지되었는지 확인
+        ctx.accounts.platform.require_not_paused(Platform::PAUSE_REWARDS)?;
+
+        let project = &mut ctx.accounts.project;
+        let platform = &mut ctx.accounts.platform;
+        let rewards_info = &mut ctx.accounts.rewards_info;
+        let authority = &ctx.accounts.authority;
+
+        // 권한 확인 (보상 운영자)
+        require_role(platform, ctx.accounts.roles.as_deref(), &authority.key(), Role::RewardOperator)?;
+
+        // 이 에포크에 이미 처리되었는지 확인 (에포크별 PDA는 init으로 한 번만 생성됨)
+        // 이전 에포크를 뒤늦게 처리하거나 아직 오지 않은 에포크를 처리할 수 없음
+        let rewards_history = &mut ctx.accounts.rewards_history;
+        if rewards_history.total_epochs > 0 {
+            require!(
+                epoch > rewards_history.last_epoch,
+                ForestLabError::AlreadyProcessedForEpoch
+            );
+        }
+        require!(epoch <= Clock::get()?.epoch, ForestLabError::InvalidEpoch);
+
+        // Forest Lab의 수수료 계산 (기본 2.5%)
+        let platform_fee_amount = total_rewards
+            .saturating_mul(platform.platform_fee as u64)
+            .saturating_div(10000);
+            
+        // 프로젝트에 분배할 보상 계산
+        let project_rewards = total_rewards.saturating_sub(platform_fee_amount);
+
+        // 취소된 프로젝트는 프로젝트 몫 없이 전액 스테이커에게 분배
+        let is_cancelled = project.status == ProjectStatus::Cancelled;
+
+        // 리스테이킹 몫 계산
+        let restake_percentage = if is_cancelled {
+            0
+        } else {
+            RestakeConfig::active_percentage_for(&ctx.accounts.restake_config)?
+        };
+        let restake_amount = project_rewards
+            .saturating_mul(restake_percentage as u64)
+            .saturating_div(10000);
+
+        // USDC 스왑용 프로젝트 몫 계산 (나머지는 스테이커에게 분배)
+        let project_share_bps = if is_cancelled {
+            0
+        } else {
+            SwapConfig::project_share_for(&ctx.accounts.swap_config)?
+        };
+        let staker_share = project_rewards.saturating_sub(restake_amount);
+        let swap_amount = staker_share
+            .saturating_mul(project_share_bps as u64)
+            .saturating_div(10000);
+        let staker_rewards = staker_share.saturating_sub(swap_amount);
+
+        // 에포크 보상 정보 초기화
+        rewards_info.project = project.key();
+        rewards_info.epoch = epoch;
+        rewards_info.total_rewards = total_rewards;
+        rewards_info.platform_fee = platform_fee_amount;
+        rewards_info.project_rewards = project_rewards;
+        rewards_info.swap_amount = swap_amount;
+        rewards_info.processed = false; // 스왑 처리 상태 초기화
+        rewards_info.restake_amount = restake_amount;
+        rewards_info.restaked = false;
+        rewards_info.timestamp = Clock::get()?.unix_timestamp;
+        rewards_info.bump = ctx.bumps.rewards_info;
+
+        // 보상 히스토리 링 버퍼에 기록
+        if rewards_history.project == Pubkey::default() {
+            rewards_history.project = project.key();
+            rewards_history.bump = ctx.bumps.rewards_history;
+        }
+        rewards_history.record(EpochRewardSummary {
+            epoch,
+            total_rewards,
+            platform_fee: platform_fee_amount,
+            project_rewards,
+        });
+        
+        // 스테이커별 누적 보상(acc_reward_per_share) 업데이트
+        let distributed = project.distribute_rewards(staker_rewards);
+
+        // 플랫폼 수수료를 트레저리로 이체하고 장부에 기록
+        if platform_fee_amount > 0 {
+            token::transfer(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    token::Transfer {
+                        from: ctx.accounts.reward_source.to_account_info(),
+                        to: ctx.accounts.platform_treasury.to_account_info(),
+                        authority: authority.to_account_info(),
+                    },
+                ),
+                platform_fee_amount,
+            )?;
+
+            let ledger = platform.fee_ledger_mut(project.lst_mint)?;
+            ledger.collected = ledger.collected.saturating_add(platform_fee_amount);
+        }
+
+        // 스테이커에게 분배된 보상을 보상 볼트로 이체
+        if distributed > 0 {
+            token::transfer(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    token::Transfer {
+                        from: ctx.accounts.reward_source.to_account_info(),
+                        to: ctx.accounts.reward_vault.to_account_info(),
+                        authority: authority.to_account_info(),
+                    },
+                ),
+                distributed,
+            )?;
+        }
+
+        // 프로젝트 몫(스왑 및 리스테이킹 대상)은 실행 전까지 프로젝트 몫 볼트에 보관
+        let project_share_deposit = swap_amount.saturating_add(restake_amount);
+        if project_share_deposit > 0 {
+            token::transfer(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    token::Transfer {
+                        from: ctx.accounts.reward_source.to_account_info(),
+                        to: ctx.accounts.project_share_vault.to_account_info(),
+                        authority: authority.to_account_info(),
+                    },
+                ),
+                project_share_deposit,
+            )?;
+        }
+
+        // 프로젝트의 누적 보상 업데이트
+        project.total_rewards_distributed = project.total_rewards_distributed.saturating_add(distributed);
+        
+        // 현재 APY 계산 업데이트 (선택적)
+        if project.funds_raised > 0 {
+            // 간단한 APY 계산 로직 (연간 예상 수익률로 변환)
+            // 주의: 이는 매우 단순화된 계산으로, 실제 APY 계산은 더 복잡할 수 있음
+            let annualized_rewards = project_rewards.saturating_mul(365 * 2); // 약 2일마다 에포크 가정
+            let apy = annualized_rewards
+                .saturating_mul(10000)
+                .saturating_div(project.funds_raised);
+            
+            // APY 업데이트 (가중 평균으로)
+            project.apy_estimate = ((project.apy_estimate as u64).saturating_mul(9).saturating_add(apy) / 10) as u16;
+        }
+
+        // 보상 처리 이벤트 발행
+        emit!(EpochRewardsProcessedEvent {
+            project: project.key(),
+            epoch,
+            total_rewards,
+            platform_fee: platform_fee_amount,
+            project_rewards,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// 에포크 보상을 USDC로 스왑하고 프로젝트에 분배하는 함수 (프로젝트 몫 볼트에 보관된 해당 에포크 몫만 스왑)
+    pub fn swap_and_distribute_rewards<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SwapAndDistributeRewards<'info>>,
+        _epoch: u64,
+        min_out: u64,
+    ) -> Result<()> {
+        // 보상 처리가 일시 중지되었는지 확인
+        ctx.accounts.platform.require_not_paused(Platform::PAUSE_REWARDS)?;
+
+        let authority = &ctx.accounts.authority;
+        let platform = &ctx.accounts.platform;
+        
+        // 권한 확인 (보상 운영자)
+        require_role(platform, ctx.accounts.roles.as_deref(), &authority.key(), Role::RewardOperator)?;
+        
+        // 이미 처리되었는지 확인 (에포크당 한 번만 스왑)
+        require!(!ctx.accounts.rewards_info.processed, ForestLabError::AlreadyProcessed);
+        
+        // 에포크 처리 시 프로젝트 몫 볼트에 적립된 금액만 스왑
+        let reward_amount = ctx.accounts.rewards_info.swap_amount;
+        require!(reward_amount > 0, ForestLabError::InvalidAmount);
+        require!(
+            ctx.accounts.project_share_vault.amount >= reward_amount,
+            ForestLabError::InsufficientRewardVault
+        );
+        
+        // 설정된 스왑 어댑터로 LST -> USDC 스왑 실행 (볼트 권한 PDA 서명)
+        let project_key = ctx.accounts.project.key();
+        let seeds = &[
+            b"vault_authority".as_ref(),
+            project_key.as_ref(),
+            &[ctx.bumps.vault_authority],
+        ];
+        let signer = &[&seeds[..]];
+        let usdc_before = ctx.accounts.usdc_vault.amount;
+        let swap_accounts = SwapAccounts {
+            swap_program: ctx.accounts.swap_program.to_account_info(),
+            transfer_authority: ctx.accounts.vault_authority.to_account_info(),
+            source: ctx.accounts.project_share_vault.to_account_info(),
+            destination: ctx.accounts.usdc_vault.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            pool_accounts: ctx.remaining_accounts,
+        };
+        ctx.accounts.swap_config.adapter.adapter().swap(&swap_accounts, reward_amount, min_out, signer)?;
+        
+        // 실제 수령한 USDC 확인 (슬리피지 한도)
+        ctx.accounts.usdc_vault.reload()?;
+        let usdc_amount = ctx.accounts.usdc_vault.amount.saturating_sub(usdc_before);
+        require!(usdc_amount > 0, ForestLabError::InvalidAmount);
+        require!(usdc_amount >= min_out, ForestLabError::SlippageExceeded);
+        
+        // 프로젝트 수수료 계산
+        let project = &mut ctx.accounts.project;
+        let project_fee = usdc_amount
+            .saturating_mul(project.manager_fee_percentage as u64)
+            .saturating_div(10000);
+        
+        // 프로젝트에 전송할 금액
+        let project_amount = usdc_amount.saturating_sub(project_fee);
+        
+        // USDC를 프로젝트 지갑과 수수료 계정으로 전송
+        if project_amount > 0 {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    token::Transfer {
+                        from: ctx.accounts.usdc_vault.to_account_info(),
+                        to: ctx.accounts.payout_account.to_account_info(),
+                        authority: ctx.accounts.vault_authority.to_account_info(),
+                    },
+                    signer,
+                ),
+                project_amount,
+            )?;
+        }
+        if project_fee > 0 {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    token::Transfer {
+                        from: ctx.accounts.usdc_vault.to_account_info(),
+                        to: ctx.accounts.project_fee_account.to_account_info(),
+                        authority: ctx.accounts.vault_authority.to_account_info(),
+                    },
+                    signer,
+                ),
+                project_fee,
+            )?;
+        }
+        
+        // 보상 정보 업데이트
+        let rewards_info = &mut ctx.accounts.rewards_info;
+        rewards_info.processed = true;
+        rewards_info.swapped_amount = usdc_amount;
+        rewards_info.project_fee = project_fee;
+        rewards_info.project_amount = project_amount;
+        project.funds_claimed = true;
+        
+        // 스왑 및 분배 이벤트 발행
+        emit!(RewardsDistributedEvent {
+            project: project_key,
+            epoch: rewards_info.epoch,
+            lst_amount: reward_amount,
+            usdc_amount,
+            project_fee,
+            project_amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    /// 사용자가 프로젝트 보상을 청구하는 함수 (보상 볼트에서 청구 가능 금액 전액 이체)
+    pub fn claim_project_rewards(
+        ctx: Context<ClaimProjectRewards>,
+    ) -> Result<()> {
+        // 보상 청구가 일시 중지되었는지 확인
+        ctx.accounts.platform.require_not_paused(Platform::PAUSE_CLAIM)?;
+
+        let stake_info = &mut ctx.accounts.stake_info;
+        let user = &ctx.accounts.user;
+        let project = &ctx.accounts.project;
+        
+        // 스테이킹 정보가 존재하는지 확인
+        require!(
+            stake_info.user == user.key() && stake_info.project == project.key(),
+            ForestLabError::StakeInfoNotFound
+        );
+        
+        // 누적 보상 정산 후 청구 가능한 보상이 있는지 확인
+        stake_info.settle_rewards(project.acc_reward_per_share);
+        let reward_token_amount = stake_info.pending_rewards;
+        require!(reward_token_amount > 0, ForestLabError::NoPendingRewards);
+        
+        // 보상 볼트 잔액 확인
+        require!(
+            ctx.accounts.reward_vault.amount >= reward_token_amount,
+            ForestLabError::InsufficientRewardVault
+        );
+        
+        // 보상 볼트에서 사용자 ATA로 이체
+        let project_key = project.key();
+        let seeds = &[
+            b"vault_authority".as_ref(),
+            project_key.as_ref(),
+            &[ctx.bumps.vault_authority],
+        ];
+        let signer = &[&seeds[..]];
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token::Transfer {
+                    from: ctx.accounts.reward_vault.to_account_info(),
+                    to: ctx.accounts.user_reward_account.to_account_info(),
+                    authority: ctx.accounts.vault_authority.to_account_info(),
+                },
+                signer,
+            ),
+            reward_token_amount,
+        )?;
+        
+        // 청구 기록 업데이트
+        stake_info.pending_rewards = 0;
+        stake_info.rewards_claimed = stake_info.rewards_claimed.saturating_add(reward_token_amount);
+        stake_info.last_claim_time = Clock::get()?.unix_timestamp;
+        
+        // 보상 청구 이벤트 발행
+        emit!(RewardsClaimedEvent {
+            project: project_key,
+            user: user.key(),
+            reward_token_amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    /// 프로젝트 수수료 설정 업데이트 함수
+    pub fn update_project_fee(
+        ctx: Context<UpdateProjectSettings>,
+        manager_fee_percentage: u16,
+        payout_wallet: Option<Pubkey>,
+    ) -> Result<()> {
+        // 플랫폼 전체 중지 여부 확인
+        ctx.accounts.platform.require_active()?;
+
+        let project = &mut ctx.accounts.project;
+        let authority = &ctx.accounts.authority;
+        
+        // 권한 확인 (프로젝트 관리자만)
+        require!(
+            project.is_authority(&authority.key()),
+            ForestLabError::Unauthorized
+        );
+        
+        // 멀티시그가 활성화된 경우 제안을 통해서만 변경 가능
+        require!(
+            !MultisigConfig::is_active_for(&ctx.accounts.multisig_config)?,
+            ForestLabError::MultisigRequired
+        );
+        
+        apply_project_fee(project, manager_fee_percentage, payout_wallet)
+    }
+
+    /// 프로젝트 상태 업데이트 함수
+    pub fn update_project_status(
+        ctx: Context<UpdateProjectStatus>,
+        new_status: ProjectStatus,
+        reason: StatusReason,
+    ) -> Result<()> {
+        require!(reason.is_manual(), ForestLabError::InvalidStatusReason);
+
+        let project = &mut ctx.accounts.project;
+        let authority = &ctx.accounts.authority;
+        let platform = &ctx.accounts.platform;
+        
+        // 권한 확인 (프로젝트 관리자 또는 프로젝트 중재자)
+        let is_platform_admin = has_role(
+            platform,
+            ctx.accounts.roles.as_deref(),
+            &authority.key(),
+            Role::ProjectModerator,
+        );
+        require!(
+            project.is_authority(&authority.key()) || is_platform_admin,
+            ForestLabError::Unauthorized
+        );
+        
+        // 프로젝트 관리자 변경은 플랫폼 전체 중지 중 불가 (플랫폼 중재자는 예외)
+        // 멀티시그가 활성화된 경우 프로젝트 관리자 단독 변경 불가
+        if !is_platform_admin {
+            platform.require_active()?;
+            require!(
+                !MultisigConfig::is_active_for(&ctx.accounts.multisig_config)?,
+                ForestLabError::MultisigRequired
+            );
+        }
+        
+        let actor = if is_platform_admin {
+            StatusActor::Platform
+        } else {
+            StatusActor::ProjectAuthority
+        };
+        apply_project_status(project, new_status, reason, actor, authority.key())
+    }
+
+    /// LST 락업 생성 함수
+    pub fn create_lockup(
+        ctx: Context<CreateLockup>,
+        amount: u64,
+        duration: i64,
+    ) -> Result<()> {
+        // 락업이 일시 중지되었는지 확인
+        ctx.accounts.platform.require_not_paused(Platform::PAUSE_LOCKUP)?;
+
+        // 최소 락업 기간 확인 (1일)
+        require!(duration >= 86400, ForestLabError::LockupTooShort);
+
+        // 펀딩 기간 확인
+        require_campaign_open(&ctx.accounts.project)?;
+
+        let lockup = &mut ctx.accounts.lockup;
+        let lockup_counter = &mut ctx.accounts.lockup_counter;
+        let lockup_tier_config = &mut ctx.accounts.lockup_tier_config;
+        let user = &ctx.accounts.user;
+        let project = &mut ctx.accounts.project;
+
+        // 락업 티어 설정 초기화 (프로젝트가 설정하기 전이면 기본 티어 사용)
+        if lockup_tier_config.project == Pubkey::default() {
+            lockup_tier_config.project = project.key();
+            lockup_tier_config.tiers = LockupTierConfig::default_tiers();
+            lockup_tier_config.max_duration = LockupTierConfig::DEFAULT_MAX_DURATION;
+            lockup_tier_config.early_exit_penalty_bps = LockupTierConfig::DEFAULT_EARLY_EXIT_PENALTY_BPS;
+            lockup_tier_config.bump = ctx.bumps.lockup_tier_config;
+        }
+
+        // 락업 포지션 카운터 초기화 (첫 락업인 경우)
+        if lockup_counter.user == Pubkey::default() {
+            lockup_counter.user = user.key();
+            lockup_counter.project = project.key();
+            lockup_counter.bump = ctx.bumps.lockup_counter;
+        }
+        let index = lockup_counter.next_index;
+        lockup_counter.next_index = lockup_counter.next_index.saturating_add(1);
+        lockup_counter.active_count = lockup_counter.active_count.saturating_add(1);
+
+        // 락업 정보 초기화
+        lockup.user = user.key();
+        lockup.project = project.key();
+        lockup.lst_mint = project.lst_mint;
+        lockup.amount = amount;
+        let current_time = Clock::get()?.unix_timestamp;
+        lockup.start_time = current_time;
+        lockup.end_time = current_time
+            .checked_add(duration)
+            .ok_or(ForestLabError::MathOverflow)?;
+        lockup.is_released = false;
+        lockup.release_time = 0;
+        lockup.amount_seconds = (amount as u128).saturating_mul(duration as u128);
+        lockup.index = index;
+        lockup.bump = ctx.bumps.lockup;
+
+        // 락업 기간에 맞는 티어 선택, 보너스 계산 및 보너스 풀 확인
+        reprice_lockup(
+            lockup,
+            lockup_tier_config,
+            project,
+            0,
+            ctx.accounts.bonus_vault.amount,
+        )?;
+
+        // 투표권 체크포인트 갱신 (첫 락업이면 초기화)
+        let voting_power = &mut ctx.accounts.voting_power;
+        if voting_power.user == Pubkey::default() {
+            voting_power.user = user.key();
+            voting_power.project = project.key();
+            voting_power.bump = ctx.bumps.voting_power;
+        }
+        checkpoint_voting_power(voting_power, lockup)?;
+
+        // 사용자의 LST 토큰을 락업 볼트로 전송
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                token::Transfer {
+                    from: ctx.accounts.user_token_account.to_account_info(),
+                    to: ctx.accounts.lockup_vault.to_account_info(),
+                    authority: user.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        // 락업 생성 이벤트 발행
+        emit!(LockupCreatedEvent {
+            lockup: lockup.key(),
+            user: user.key(),
+            project: project.key(),
+            index,
+            amount,
+            start_time: lockup.start_time,
+            end_time: lockup.end_time,
+            bonus_percentage: lockup.bonus_percentage,
+            bonus_amount: lockup.bonus_amount,
+        });
+
+        // 펀딩 시작 경계를 처음 통과한 경우 시작 이벤트 발행
+        refresh_campaign_phase(&mut ctx.accounts.project)?;
+
+        Ok(())
+    }
+
+    /// 락업 해제 함수
+    pub fn release_lockup(
+        ctx: Context<ReleaseLockup>,
+    ) -> Result<()> {
+        // 락업이 일시 중지되었는지 확인
+        ctx.accounts.platform.require_not_paused(Platform::PAUSE_LOCKUP)?;
+
+        let lockup = &mut ctx.accounts.lockup;
+        let user = &ctx.accounts.user;
+        let lockup_vault = &ctx.accounts.lockup_vault;
+
+        // 락업이 이미 해제되었는지 확인
+        require!(!lockup.is_released, ForestLabError::AlreadyReleased);
+
+        // 락업 기간이 끝났는지 확인
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(
+            current_time >= lockup.end_time,
+            ForestLabError::LockupNotExpired
+        );
+
+        // 락업된 LST를 사용자에게 반환
+        let index_bytes = lockup.index.to_le_bytes();
+        let seeds = &[
+            b"lockup".as_ref(),
+            lockup.user.as_ref(),
+            lockup.project.as_ref(),
+            &index_bytes,
+            &[lockup.bump],
+        ];
+        let signer = &[&seeds[..]];
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token::Transfer {
+                    from: lockup_vault.to_account_info(),
+                    to: ctx.accounts.user_token_account.to_account_info(),
+                    authority: lockup.to_account_info(),
+                },
+                signer,
+            ),
+            lockup.amount,
+        )?;
+
+        // 보너스 볼트에서 기간 가중 보너스 지급
+        let project = &mut ctx.accounts.project;
+        if lockup.bonus_amount > 0 {
+            let project_key = project.key();
+            let vault_seeds = &[
+                b"vault_authority".as_ref(),
+                project_key.as_ref(),
+                &[ctx.bumps.vault_authority],
+            ];
+            let vault_signer = &[&vault_seeds[..]];
+
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    token::Transfer {
+                        from: ctx.accounts.bonus_vault.to_account_info(),
+                        to: ctx.accounts.user_token_account.to_account_info(),
+                        authority: ctx.accounts.vault_authority.to_account_info(),
+                    },
+                    vault_signer,
+                ),
+                lockup.bonus_amount,
+            )?;
+        }
+        project.bonus_committed = project.bonus_committed.saturating_sub(lockup.bonus_amount);
+
+        // 락업 정보 업데이트
+        lockup.is_released = true;
+        lockup.release_time = current_time;
+
+        let lockup_counter = &mut ctx.accounts.lockup_counter;
+        lockup_counter.active_count = lockup_counter.active_count.saturating_sub(1);
+
+        // 티어 사용량 반환
+        ctx.accounts.lockup_tier_config.release(lockup.tier_min_duration, lockup.amount);
+
+        // 투표권 체크포인트에서 해제된 락업 제거
+        checkpoint_voting_power(&mut ctx.accounts.voting_power, lockup)?;
+
+        // 락업 해제 이벤트 발행
+        emit!(LockupReleasedEvent {
+            lockup: lockup.key(),
+            user: user.key(),
+            project: lockup.project,
+            index: lockup.index,
+            amount: lockup.amount,
+            bonus_amount: lockup.bonus_amount,
+            release_time: current_time,
+        });
+
+        Ok(())
+    }
+
+    /// 크랭크 업데이트 실행 함수
+    pub fn execute_crank_update(
+        ctx: Context<ExecuteCrankUpdate>,
+        epoch: u64,
+    ) -> Result<()> {
+        // 보상 처리가 일시 중지되었는지 확인
+        ctx.accounts.platform.require_not_paused(Platform::PAUSE_REWARDS)?;
+
+        let crank_info = &mut ctx.accounts.crank_info;
+        let project = &ctx.accounts.project;
+        let authority = &ctx.accounts.authority;
+        let platform = &ctx.accounts.platform;
+        
+        // 권한 확인 (보상 운영자 또는 허가된 크랭커)
+        let is_admin = has_role(
+            platform,
+            ctx.accounts.roles.as_deref(),
+            &authority.key(),
+            Role::RewardOperator,
+        );
+        require!(
+            is_admin || crank_info.authorized_crankers.contains(&authority.key()),
+            ForestLabError::Unauthorized
+        );
+        
+        // 크랭크 정보 업데이트
+        crank_info.last_executed_epoch = epoch;
+        crank_info.last_execution_time = Clock::get()?.unix_timestamp;
+        crank_info.execution_count = crank_info.execution_count.saturating_add(1);
+        
+        // 이 함수는 SPL Stake Pool의 update_stake_pool 명령을 호출하는
+        // 외부 프로그램을 트리거하기 위한 이벤트를 발행함
+        
+        // 크랭크 실행 이벤트 발행
+        emit!(CrankExecutedEvent {
+            project: project.key(),
+            epoch,
+            executor: authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    /// 플랫폼 설정 업데이트 함수
+    pub fn update_platform_settings(
+        ctx: Context<UpdatePlatformSettings>,
+        platform_fee: Option<u16>,
+        min_stake_amount: Option<u64>,
+        admin_wallet: Option<Pubkey>,
+        is_active: Option<bool>,
+        settings_timelock: Option<i64>,
+    ) -> Result<()> {
+        let platform = &mut ctx.accounts.platform;
+        let authority = &ctx.accounts.authority;
+        let roles = ctx.accounts.roles.as_deref();
+        let current_time = Clock::get()?.unix_timestamp;
+
+        // 권한 확인 (수수료는 수수료 관리자, 나머지 설정은 최고 관리자)
+        if platform_fee.is_some() {
+            require_role(platform, roles, &authority.key(), Role::FeeManager)?;
+        }
+        if min_stake_amount.is_some()
+            || admin_wallet.is_some()
+            || is_active.is_some()
+            || settings_timelock.is_some()
+        {
+            require_role(platform, roles, &authority.key(), Role::SuperAdmin)?;
+        }
+
+        // 즉시 적용되는 설정 업데이트
+        if let Some(amount) = min_stake_amount {
+            platform.min_stake_amount = amount;
+        }
+
+        if let Some(active) = is_active {
+            platform.is_active = active;
+        }
+
+        // 수수료, 관리자 지갑, 대기 시간 변경은 대기 시간 이후 적용되도록 예약
+        if platform_fee.is_some() || admin_wallet.is_some() || settings_timelock.is_some() {
+            // 항목별로 대기 시간을 따로 시작 (다른 항목의 예약은 영향 없음)
+            let effective_at = current_time.saturating_add(platform.settings_timelock);
+
+            if let Some(fee) = platform_fee {
+                require!(fee <= 250, ForestLabError::InvalidFeePercentage); // 최대 2.5%
+                platform.pending_platform_fee = Some(fee);
+                platform.pending_platform_fee_effective_at = effective_at;
+            }
+
+            if let Some(admin) = admin_wallet {
+                platform.pending_admin_wallet = Some(admin);
+                platform.pending_admin_wallet_effective_at = effective_at;
+            }
+
+            if let Some(delay) = settings_timelock {
+                require!(
+                    (0..=Platform::MAX_SETTINGS_TIMELOCK).contains(&delay),
+                    ForestLabError::InvalidSettingsTimelock
+                );
+                platform.pending_settings_timelock = Some(delay);
+                platform.pending_settings_timelock_effective_at = effective_at;
+            }
+
+            // 설정 변경 예약 이벤트 발행
+            emit!(PlatformSettingsQueuedEvent {
+                platform: platform.key(),
+                authority: authority.key(),
+                pending_platform_fee: platform.pending_platform_fee,
+                pending_admin_wallet: platform.pending_admin_wallet,
+                pending_settings_timelock: platform.pending_settings_timelock,
+                platform_fee_effective_at: platform.pending_platform_fee_effective_at,
+                admin_wallet_effective_at: platform.pending_admin_wallet_effective_at,
+                settings_timelock_effective_at: platform.pending_settings_timelock_effective_at,
+                timestamp: current_time,
+            });
+        }
+
+        // 설정 업데이트 이벤트 발행
+        emit!(PlatformSettingsUpdatedEvent {
+            platform: platform.key(),
+            authority: authority.key(),
+            platform_fee: platform.platform_fee,
+            min_stake_amount: platform.min_stake_amount,
+            admin_wallet: platform.admin_wallet,
+            is_active: platform.is_active,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    /// 예약된 플랫폼 설정 변경 실행 함수 (대기 시간 이후 누구나 실행 가능)
+    pub fn execute_platform_settings(ctx: Context<ExecutePlatformSettings>) -> Result<()> {
+        let platform = &mut ctx.accounts.platform;
+        let current_time = Clock::get()?.unix_timestamp;
+
+        require!(platform.has_pending_settings(), ForestLabError::NoPendingSettingsChange);
+
+        // 대기 시간이 지난 항목만 적용 (나머지는 예약 유지)
+        let mut executed = false;
+        if let Some(fee) = platform.pending_platform_fee {
+            if current_time >= platform.pending_platform_fee_effective_at {
+                platform.platform_fee = fee;
+                platform.pending_platform_fee = None;
+                platform.pending_platform_fee_effective_at = 0;
+                executed = true;
+            }
+        }
+        if let Some(admin) = platform.pending_admin_wallet {
+            if current_time >= platform.pending_admin_wallet_effective_at {
+                // 관리자 지갑 기본 역할을 새 관리자 지갑으로 이전
+                let roles = &mut ctx.accounts.roles;
+                roles.revoke(&platform.admin_wallet, Roles::admin_wallet_mask());
+                roles.grant(admin, Roles::admin_wallet_mask())?;
+                platform.admin_wallet = admin;
+                platform.pending_admin_wallet = None;
+                platform.pending_admin_wallet_effective_at = 0;
+                executed = true;
+            }
+        }
+        if let Some(delay) = platform.pending_settings_timelock {
+            if current_time >= platform.pending_settings_timelock_effective_at {
+                platform.settings_timelock = delay;
+                platform.pending_settings_timelock = None;
+                platform.pending_settings_timelock_effective_at = 0;
+                executed = true;
+            }
+        }
+        require!(executed, ForestLabError::SettingsTimelockNotElapsed);
+
+        // 설정 변경 실행 이벤트 발행
+        emit!(PlatformSettingsExecutedEvent {
+            platform: platform.key(),
+            executor: ctx.accounts.executor.key(),
+            platform_fee: platform.platform_fee,
+            admin_wallet: platform.admin_wallet,
+            settings_timelock: platform.settings_timelock,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    /// 예약된 플랫폼 설정 변경 취소 함수 (항목별 취소, 예약과 같은 역할 필요)
+    pub fn cancel_platform_settings(
+        ctx: Context<CancelPlatformSettings>,
+        platform_fee: bool,
+        admin_wallet: bool,
+        settings_timelock: bool,
+    ) -> Result<()> {
+        let platform = &mut ctx.accounts.platform;
+        let authority = &ctx.accounts.authority;
+        let roles = ctx.accounts.roles.as_deref();
+
+        require!(
+            platform_fee || admin_wallet || settings_timelock,
+            ForestLabError::NoPendingSettingsChange
+        );
+
+        // 권한 확인 (예약과 같은 역할: 수수료는 수수료 관리자, 나머지 설정은 최고 관리자)
+        if platform_fee {
+            require_role(platform, roles, &authority.key(), Role::FeeManager)?;
+        }
+        if admin_wallet || settings_timelock {
+            require_role(platform, roles, &authority.key(), Role::SuperAdmin)?;
+        }
+
+        // 선택한 항목만 취소
+        let mut cancelled_platform_fee = None;
+        let mut cancelled_admin_wallet = None;
+        let mut cancelled_settings_timelock = None;
+        if platform_fee {
+            cancelled_platform_fee = platform.pending_platform_fee.take();
+            require!(cancelled_platform_fee.is_some(), ForestLabError::NoPendingSettingsChange);
+            platform.pending_platform_fee_effective_at = 0;
+        }
+        if admin_wallet {
+            cancelled_admin_wallet = platform.pending_admin_wallet.take();
+            require!(cancelled_admin_wallet.is_some(), ForestLabError::NoPendingSettingsChange);
+            platform.pending_admin_wallet_effective_at = 0;
+        }
+        if settings_timelock {
+            cancelled_settings_timelock = platform.pending_settings_timelock.take();
+            require!(cancelled_settings_timelock.is_some(), ForestLabError::NoPendingSettingsChange);
+            platform.pending_settings_timelock_effective_at = 0;
+        }
+
+        // 설정 변경 취소 이벤트 발행
+        emit!(PlatformSettingsCancelledEvent {
+            platform: platform.key(),
+            authority: authority.key(),
+            cancelled_platform_fee,
+            cancelled_admin_wallet,
+            cancelled_settings_timelock,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// 크랭커 권한 관리 함수
+    pub fn manage_crankers(
+        ctx: Context<ManageCrankers>,
+        add_crankers: Vec<Pubkey>,
+        remove_crankers: Vec<Pubkey>,
+    ) -> Result<()> {
+        let crank_info = &mut ctx.accounts.crank_info;
+        let authority = &ctx.accounts.authority;
+        let platform = &ctx.accounts.platform;
+        
+        // 권한 확인 (최고 관리자)
+        require_role(platform, ctx.accounts.roles.as_deref(), &authority.key(), Role::SuperAdmin)?;
+        
+        // 크랭커 추가
+        for cranker in add_crankers {
+            if !crank_info.authorized_crankers.contains(&cranker) {
+                crank_info.authorized_crankers.push(cranker);
+            }
+        }
+        
+        // 크랭커 제거
+        for cranker in remove_crankers {
+            crank_info.authorized_crankers.retain(|&x| x != cranker);
+        }
+        
+        // 크랭커 관리 이벤트 발행
+        emit!(CrankersUpdatedEvent {
+            platform: platform.key(),
+            authorized_crankers: crank_info.authorized_crankers.clone(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    /// 리스테이킹 설정 함수
+    pub fn setup_restaking(
+        ctx: Context<SetupRestaking>,
+        target_lst_mint: Pubkey,
+        restake_percentage: u16,
+    ) -> Result<()> {
+        // 플랫폼 전체 중지 여부 확인
+        ctx.accounts.platform.require_active()?;
+
+        let restake_config = &mut ctx.accounts.restake_config;
+        let project = &ctx.accounts.project;
+        let authority = &ctx.accounts.authority;
+        
+        // 권한 확인 (프로젝트 관리자만)
+        require!(
+            project.is_authority(&authority.key()),
+            ForestLabError::Unauthorized
+        );
+        
+        // 멀티시그가 활성화된 경우 제안을 통해서만 변경 가능
+        require!(
+            !MultisigConfig::is_active_for(&ctx.accounts.multisig_config)?,
+            ForestLabError::MultisigRequired
+        );
+        
+        apply_restaking(
+            restake_config,
+            project,
+            target_lst_mint,
+            restake_percentage,
+            ctx.bumps.restake_config,
+        )
+    }
+
+    /// 멀티시그 프로젝트 관리 설정 함수
+    pub fn setup_multisig_management(
+        ctx: Context<SetupMultisigManagement>,
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        // 플랫폼 전체 중지 여부 확인
+        ctx.accounts.platform.require_active()?;
+
+        let multisig_config = &mut ctx.accounts.multisig_config;
+        let project = &ctx.accounts.project;
+        let authority = &ctx.accounts.authority;
+        
+        // 권한 확인 (프로젝트 관리자만)
+        require!(
+            project.is_authority(&authority.key()),
+            ForestLabError::Unauthorized
+        );
+        
+        // 서명자 수 유효성 검증
+        require!(!signers.is_empty(), ForestLabError::InvalidSignerCount);
+        require!(signers.len() <= MultisigConfig::MAX_SIGNERS, ForestLabError::TooManySigners);
+        require!(
+            !MultisigConfig::has_duplicate_signers(&signers),
+            ForestLabError::DuplicateSigner
+        );
+        
+        // 임계값 유효성 검증
+        require!(threshold > 0, ForestLabError::InvalidThreshold);
+        require!(
+            threshold as usize <= signers.len(),
+            ForestLabError::ThresholdTooHigh
+        );
+        
+        // 멀티시그 설정 초기화
+        multisig_config.project = project.key();
+        multisig_config.signers = signers.clone();
+        multisig_config.threshold = threshold;
+        multisig_config.is_active = true;
+        multisig_config.proposal_count = 0;
+        multisig_config.bump = ctx.bumps.multisig_config;
+        
+        // 멀티시그 설정 이벤트 발행
+        emit!(MultisigConfiguredEvent {
+            project: project.key(),
+            signers: signers.clone(),
+            threshold,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    /// 보상 스왑 프로그램 및 어댑터 설정 함수
+    pub fn configure_swap(
+        ctx: Context<ConfigureSwap>,
+        swap_program: Pubkey,
+        adapter: SwapAdapterKind,
+        usdc_mint: Pubkey,
+        project_share_bps: u16,
+    ) -> Result<()> {
+        require!(project_share_bps <= 10000, ForestLabError::InvalidPercentage);
+
+        let swap_config = &mut ctx.accounts.swap_config;
+        let platform = &ctx.accounts.platform;
+
+        // 권한 확인 (최고 관리자)
+        require_role(
+            platform,
+            ctx.accounts.roles.as_deref(),
+            &ctx.accounts.authority.key(),
+            Role::SuperAdmin,
+        )?;
+
+        // 스왑 설정 초기화 또는 업데이트
+        swap_config.platform = platform.key();
+        swap_config.swap_program = swap_program;
+        swap_config.adapter = adapter;
+        swap_config.usdc_mint = usdc_mint;
+        swap_config.project_share_bps = project_share_bps;
+        swap_config.bump = ctx.bumps.swap_config;
+
+        // 스왑 설정 이벤트 발행
+        emit!(SwapConfiguredEvent {
+            platform: platform.key(),
+            swap_program,
+            adapter,
+            usdc_mint,
+            project_share_bps,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// 플랫폼 수수료 출금 함수 (관리자 지갑으로 전송)
+    pub fn withdraw_platform_fees(
+        ctx: Context<WithdrawPlatformFees>,
+        amount: u64,
+    ) -> Result<()> {
+        // 수수료 출금이 일시 중지되었는지 확인
+        ctx.accounts.platform.require_not_paused(Platform::PAUSE_FEES)?;
+
+        let platform = &mut ctx.accounts.platform;
+        let mint = ctx.accounts.mint.key();
+
+        // 권한 확인 (수수료 관리자)
+        require_role(
+            platform,
+            ctx.accounts.roles.as_deref(),
+            &ctx.accounts.authority.key(),
+            Role::FeeManager,
+        )?;
+
+        // 출금 금액 확인
+        require!(amount > 0, ForestLabError::InvalidAmount);
+        require!(
+            ctx.accounts.platform_treasury.amount >= amount,
+            ForestLabError::InsufficientFunds
+        );
+
+        // 트레저리에서 관리자 지갑으로 이체
+        let seeds = &[b"platform".as_ref(), &[platform.bump]];
+        let signer = &[&seeds[..]];
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token::Transfer {
+                    from: ctx.accounts.platform_treasury.to_account_info(),
+                    to: ctx.accounts.admin_token_account.to_account_info(),
+                    authority: platform.to_account_info(),
+                },
+                signer,
+            ),
+            amount,
+        )?;
+
+        // 수수료 장부 업데이트
+        let ledger = platform.fee_ledger_mut(mint)?;
+        ledger.withdrawn = ledger.withdrawn.saturating_add(amount);
+
+        // 수수료 출금 이벤트 발행
+        emit!(PlatformFeesWithdrawnEvent {
+            platform: platform.key(),
+            mint,
+            amount,
+            destination: ctx.accounts.admin_token_account.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// 멀티시그 제안 생성 함수 (제안자는 자동 승인)
+    pub fn create_proposal(
+        ctx: Context<CreateProposal>,
+        action: ProjectAction,
+    ) -> Result<()> {
+        // 플랫폼 전체 중지 여부 확인
+        ctx.accounts.platform.require_active()?;
+
+        let multisig_config = &mut ctx.accounts.multisig_config;
+        let proposal = &mut ctx.accounts.proposal;
+        let proposer = &ctx.accounts.proposer;
+
+        // 멀티시그 활성화 및 서명자 확인
+        require!(multisig_config.is_active, ForestLabError::MultisigInactive);
+        require!(
+            multisig_config.is_signer(&proposer.key()),
+            ForestLabError::NotMultisigSigner
+        );
+
+        // 작업 인자 검증
+        action.validate()?;
+        multisig_config.check_signer_change(&action)?;
+
+        // 제안 초기화
+        let now = Clock::get()?.unix_timestamp;
+        proposal.multisig_config = multisig_config.key();
+        proposal.project = ctx.accounts.project.key();
+        proposal.proposer = proposer.key();
+        proposal.index = multisig_config.proposal_count;
+        proposal.action = action.clone();
+        proposal.approvals = vec![proposer.key()];
+        proposal.executed = false;
+        proposal.created_at = now;
+        proposal.executed_at = 0;
+        proposal.bump = ctx.bumps.proposal;
+
+        multisig_config.proposal_count = multisig_config.proposal_count.saturating_add(1);
+
+        // 제안 생성 이벤트 발행
+        emit!(ProposalCreatedEvent {
+            project: proposal.project,
+            proposal: proposal.key(),
+            index: proposal.index,
+            proposer: proposer.key(),
+            action,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+
+    /// 멀티시그 제안 승인 함수
+    pub fn approve_proposal(
+        ctx: Context<ApproveProposal>,
+    ) -> Result<()> {
+        // 플랫폼 전체 중지 여부 확인
+        ctx.accounts.platform.require_active()?;
+
+        let multisig_config = &ctx.accounts.multisig_config;
+        let proposal = &mut ctx.accounts.proposal;
+        let signer = &ctx.accounts.signer;
+
+        // 멀티시그 활성화 및 서명자 확인
+        require!(multisig_config.is_active, ForestLabError::MultisigInactive);
+        require!(
+            multisig_config.is_signer(&signer.key()),
+            ForestLabError::NotMultisigSigner
+        );
+
+        // 제안 상태 확인
+        require!(!proposal.executed, ForestLabError::ProposalAlreadyExecuted);
+        require!(
+            !proposal.approvals.contains(&signer.key()),
+            ForestLabError::AlreadyApproved
+        );
+
+        // 승인 기록
+        proposal.approvals.push(signer.key());
+
+        // 제안 승인 이벤트 발행
+        emit!(ProposalApprovedEvent {
+            project: proposal.project,
+            proposal: proposal.key(),
+            signer: signer.key(),
+            approvals: multisig_config.count_approvals(&proposal.approvals) as u8,
+            threshold: multisig_config.threshold,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// 멀티시그 제안 실행 함수 (임계값 충족 시 작업 적용)
+    pub fn execute_proposal(
+        ctx: Context<ExecuteProposal>,
+    ) -> Result<()> {
+        // 플랫폼 전체 중지 여부 확인
+        ctx.accounts.platform.require_active()?;
+
+        let multisig_config = &mut ctx.accounts.multisig_config;
+        let proposal = &mut ctx.accounts.proposal;
+        let project = &mut ctx.accounts.project;
+        let executor = &ctx.accounts.executor;
+
+        // 제안 상태 및 임계값 확인 (현재 서명자의 승인만 인정)
+        require!(multisig_config.is_active, ForestLabError::MultisigInactive);
+        require!(!proposal.executed, ForestLabError::ProposalAlreadyExecuted);
+        require!(
+            multisig_config.count_approvals(&proposal.approvals) >= multisig_config.threshold as usize,
+            ForestLabError::ThresholdNotMet
+        );
+
+        // 작업 적용
+        match proposal.action.clone() {
+            ProjectAction::UpdateFee { manager_fee_percentage, payout_wallet } => {
+                apply_project_fee(project, manager_fee_percentage, payout_wallet)?;
+            }
+            ProjectAction::SetupRestaking { target_lst_mint, restake_percentage } => {
+                let restake_config = ctx
+                    .accounts
+                    .restake_config
+                    .as_mut()
+                    .ok_or(ForestLabError::MissingRestakeConfig)?;
+                let bump = ctx.bumps.restake_config.ok_or(ForestLabError::MissingRestakeConfig)?;
+                apply_restaking(restake_config, project, target_lst_mint, restake_percentage, bump)?;
+            }
+            ProjectAction::UpdateStatus { new_status, reason } => {
+                apply_project_status(
+                    project,
+                    new_status,
+                    reason,
+                    StatusActor::ProjectAuthority,
+                    multisig_config.key(),
+                )?;
+            }
+            ProjectAction::WithdrawRestake { target_mint, amount } => {
+                // 보상 처리가 일시 중지되었는지 확인
+                ctx.accounts.platform.require_not_paused(Platform::PAUSE_REWARDS)?;
+
+                let (
+                    Some(vault_authority),
+                    Some(restake_vault),
+                    Some(restake_position),
+                    Some(destination),
+                    Some(token_program),
+                    Some(vault_authority_bump),
+                ) = (
+                    ctx.accounts.vault_authority.as_ref(),
+                    ctx.accounts.restake_vault.as_ref(),
+                    ctx.accounts.restake_position.as_mut(),
+                    ctx.accounts.restake_destination.as_ref(),
+                    ctx.accounts.token_program.as_ref(),
+                    ctx.bumps.vault_authority,
+                ) else {
+                    return err!(ForestLabError::MissingRestakeAccounts);
+                };
+                require_keys_eq!(restake_vault.mint, target_mint, ForestLabError::MissingRestakeAccounts);
+                require_keys_eq!(restake_position.target_lst_mint, target_mint, ForestLabError::MissingRestakeAccounts);
+                require_keys_eq!(destination.mint, target_mint, ForestLabError::MissingRestakeAccounts);
+
+                apply_restake_withdrawal(
+                    restake_vault,
+                    restake_position,
+                    destination,
+                    vault_authority,
+                    &token_program.to_account_info(),
+                    vault_authority_bump,
+                    multisig_config.key(),
+                    amount,
+                )?;
+            }
+            action @ (ProjectAction::AddSigner { .. }
+            | ProjectAction::RemoveSigner { .. }
+            | ProjectAction::ChangeThreshold { .. }) => {
+                multisig_config.apply_signer_change(&action)?;
+
+                // 서명자 변경 이벤트 발행
+                emit!(MultisigSignersChangedEvent {
+                    project: project.key(),
+                    proposal: proposal.key(),
+                    signers: multisig_config.signers.clone(),
+                    threshold: multisig_config.threshold,
+                    timestamp: Clock::get()?.unix_timestamp,
+                });
+            }
+        }
+
+        // 제안 실행 기록
+        let now = Clock::get()?.unix_timestamp;
+        proposal.executed = true;
+        proposal.executed_at = now;
+
+        // 제안 실행 이벤트 발행
+        emit!(ProposalExecutedEvent {
+            project: project.key(),
+            proposal: proposal.key(),
+            executor: executor.key(),
+            action: proposal.action.clone(),
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+
+    /// 리스테이킹 실행 함수 (에포크 보상 중 리스테이킹 몫을 타겟 LST로 전환)
+    pub fn execute_restake<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteRestake<'info>>,
+        epoch: u64,
+        min_out: u64,
+    ) -> Result<()> {
+        // 보상 처리가 일시 중지되었는지 확인
+        ctx.accounts.platform.require_not_paused(Platform::PAUSE_REWARDS)?;
+
+        let platform = &ctx.accounts.platform;
+        let authority = &ctx.accounts.authority;
+        let project_key = ctx.accounts.project.key();
+
+        // 권한 확인 (보상 운영자 또는 허가된 크랭커)
+        let is_admin = has_role(
+            platform,
+            ctx.accounts.roles.as_deref(),
+            &authority.key(),
+            Role::RewardOperator,
+        );
+        let is_cranker = ctx
+            .accounts
+            .crankers
+            .as_ref()
+            .is_some_and(|crankers| crankers.authorized_crankers.contains(&authority.key()));
+        require!(is_admin || is_cranker, ForestLabError::Unauthorized);
+
+        // 리스테이킹 설정 및 에포크 상태 확인
+        require!(ctx.accounts.restake_config.is_active, ForestLabError::RestakingInactive);
+        require!(!ctx.accounts.rewards_info.restaked, ForestLabError::AlreadyRestaked);
+        let source_amount = ctx.accounts.rewards_info.restake_amount;
+        require!(source_amount > 0, ForestLabError::InvalidAmount);
+        require!(
+            ctx.accounts.project_share_vault.amount >= source_amount,
+            ForestLabError::InsufficientRewardVault
+        );
+
+        // 타겟 스테이크 풀 검증 (가치 평가용)
+        let target_pool = StakePoolState::load(
+            &ctx.accounts.target_stake_pool,
+            &ctx.accounts.target_stake_pool_program.key(),
+        )?;
+        require_keys_eq!(
+            target_pool.pool_mint,
+            ctx.accounts.target_lst_mint.key(),
+            ForestLabError::InvalidStakePool
+        );
+
+        // 프로젝트 몫 볼트의 소스 LST를 타겟 LST로 스왑 (볼트 권한 PDA 서명)
+        let target_before = ctx.accounts.restake_vault.amount;
+        let seeds = &[
+            b"vault_authority".as_ref(),
+            project_key.as_ref(),
+            &[ctx.bumps.vault_authority],
+        ];
+        let signer = &[&seeds[..]];
+        let swap_accounts = SwapAccounts {
+            swap_program: ctx.accounts.swap_program.to_account_info(),
+            transfer_authority: ctx.accounts.vault_authority.to_account_info(),
+            source: ctx.accounts.project_share_vault.to_account_info(),
+            destination: ctx.accounts.restake_vault.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            pool_accounts: ctx.remaining_accounts,
+        };
+        ctx.accounts.swap_config.adapter.adapter().swap(&swap_accounts, source_amount, min_out, signer)?;
+
+        // 실제 수령한 타겟 LST 확인 (슬리피지 한도)
+        ctx.accounts.restake_vault.reload()?;
+        let target_amount = ctx.accounts.restake_vault.amount.saturating_sub(target_before);
+        require!(target_amount > 0, ForestLabError::InvalidAmount);
+        require!(target_amount >= min_out, ForestLabError::SlippageExceeded);
+
+        // 리스테이킹 포지션 업데이트 (원금 및 수익 평가)
+        let restake_position = &mut ctx.accounts.restake_position;
+        if restake_position.project == Pubkey::default() {
+            restake_position.project = project_key;
+            restake_position.target_lst_mint = ctx.accounts.target_lst_mint.key();
+            restake_position.bump = ctx.bumps.restake_position;
+        }
+        restake_position.source_lst_restaked = restake_position.source_lst_restaked.saturating_add(source_amount);
+        restake_position.target_lst_received = restake_position.target_lst_received.saturating_add(target_amount);
+        restake_position.principal_lamports = restake_position
+            .principal_lamports
+            .saturating_add(target_pool.pool_tokens_to_lamports(target_amount));
+        restake_position.last_valuation_lamports =
+            target_pool.pool_tokens_to_lamports(ctx.accounts.restake_vault.amount);
+        restake_position.yield_lamports = restake_position
+            .last_valuation_lamports
+            .saturating_sub(restake_position.principal_lamports);
+        restake_position.last_restaked_epoch = epoch;
+        restake_position.restake_count = restake_position.restake_count.saturating_add(1);
+
+        // 에포크 리스테이킹 완료 기록
+        ctx.accounts.rewards_info.restaked = true;
+
+        // 리스테이킹 실행 이벤트 발행
+        emit!(RestakeExecutedEvent {
+            project: project_key,
+            epoch,
+            executor: authority.key(),
+            source_amount,
+            target_amount,
+            principal_lamports: restake_position.principal_lamports,
+            yield_lamports: restake_position.yield_lamports,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// 리스테이킹 포지션 출금 함수 (프로젝트 관리자만, 수익금 지불 지갑으로 전송)
+    pub fn withdraw_restake(
+        ctx: Context<WithdrawRestake>,
+        amount: u64,
+    ) -> Result<()> {
+        // 보상 처리(리스테이킹)가 일시 중지되었는지 확인
+        ctx.accounts.platform.require_not_paused(Platform::PAUSE_REWARDS)?;
+
+        let project = &ctx.accounts.project;
+        let authority = &ctx.accounts.authority;
+
+        // 권한 확인 (프로젝트 관리자만)
+        require!(
+            project.is_authority(&authority.key()),
+            ForestLabError::Unauthorized
+        );
+
+        // 멀티시그가 활성화된 경우 제안을 통해서만 변경 가능
+        require!(
+            !MultisigConfig::is_active_for(&ctx.accounts.multisig_config)?,
+            ForestLabError::MultisigRequired
+        );
+
+        apply_restake_withdrawal(
+            &ctx.accounts.restake_vault,
+            &mut ctx.accounts.restake_position,
+            &ctx.accounts.destination,
+            &ctx.accounts.vault_authority,
+            &ctx.accounts.token_program.to_account_info(),
+            ctx.bumps.vault_authority,
+            authority.key(),
+            amount,
+        )
+    }
+
+    /// 락업 보너스 볼트 충전 함수
+    pub fn fund_bonus_vault(
+        ctx: Context<FundBonusVault>,
+        amount: u64,
+    ) -> Result<()> {
+        // 락업 작업이 일시 중지되었는지 확인 (보너스 볼트는 락업 보너스 재원)
+        ctx.accounts.platform.require_not_paused(Platform::PAUSE_LOCKUP)?;
+
+        require!(amount > 0, ForestLabError::InvalidAmount);
+
+        // 충전자의 LST를 보너스 볼트로 전송
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                token::Transfer {
+                    from: ctx.accounts.funder_token_account.to_account_info(),
+                    to: ctx.accounts.bonus_vault.to_account_info(),
+                    authority: ctx.accounts.funder.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+        ctx.accounts.bonus_vault.reload()?;
+
+        // 보너스 볼트 충전 이벤트 발행
+        emit!(BonusVaultFundedEvent {
+            project: ctx.accounts.project.key(),
+            funder: ctx.accounts.funder.key(),
+            amount,
+            vault_balance: ctx.accounts.bonus_vault.amount,
+            bonus_committed: ctx.accounts.project.bonus_committed,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// 프로젝트 락업 티어 설정 함수
+    pub fn configure_lockup_tiers(
+        ctx: Context<ConfigureLockupTiers>,
+        tiers: Vec<LockupTierParams>,
+        max_duration: i64,
+        early_exit_penalty_bps: u16,
+    ) -> Result<()> {
+        // 플랫폼 전체 중지 여부 확인
+        ctx.accounts.platform.require_active()?;
+
+        LockupTierConfig::validate(&tiers, max_duration, early_exit_penalty_bps)?;
+
+        let lockup_tier_config = &mut ctx.accounts.lockup_tier_config;
+
+        // 같은 최소 기간의 기존 티어는 사용량을 유지
+        let previous = std::mem::take(&mut lockup_tier_config.tiers);
+        lockup_tier_config.tiers = tiers
+            .iter()
+            .map(|params| LockupTier {
+                min_duration: params.min_duration,
+                bonus_bps: params.bonus_bps,
+                max_capacity: params.max_capacity,
+                used_capacity: previous
+                    .iter()
+                    .find(|tier| tier.min_duration == params.min_duration)
+                    .map_or(0, |tier| tier.used_capacity),
+            })
+            .collect();
+        lockup_tier_config.project = ctx.accounts.project.key();
+        lockup_tier_config.max_duration = max_duration;
+        lockup_tier_config.early_exit_penalty_bps = early_exit_penalty_bps;
+        lockup_tier_config.bump = ctx.bumps.lockup_tier_config;
+
+        // 락업 티어 설정 이벤트 발행
+        emit!(LockupTiersConfiguredEvent {
+            project: ctx.accounts.project.key(),
+            authority: ctx.accounts.authority.key(),
+            tiers: lockup_tier_config.tiers.clone(),
+            max_duration,
+            early_exit_penalty_bps,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// 락업 조기 해제 함수 (페널티 차감, 보너스 몰수)
+    pub fn early_release_lockup(
+        ctx: Context<EarlyReleaseLockup>,
+    ) -> Result<()> {
+        // 락업이 일시 중지되었는지 확인
+        ctx.accounts.platform.require_not_paused(Platform::PAUSE_LOCKUP)?;
+
+        let lockup = &mut ctx.accounts.lockup;
+        let project = &mut ctx.accounts.project;
+
+        // 만료 전 락업만 조기 해제 가능
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(
+            current_time < lockup.end_time,
+            ForestLabError::LockupAlreadyExpired
+        );
+
+        // 남은 기간에 비례한 페널티 계산 (취소된 프로젝트는 페널티 면제)
+        let penalty_amount = if project.status == ProjectStatus::Cancelled {
+            0
+        } else {
+            lockup.early_exit_penalty(
+                current_time,
+                ctx.accounts.lockup_tier_config.early_exit_penalty_bps,
+            )
+        };
+        let returned_amount = lockup.amount.saturating_sub(penalty_amount);
+
+        let index_bytes = lockup.index.to_le_bytes();
+        let seeds = &[
+            b"lockup".as_ref(),
+            lockup.user.as_ref(),
+            lockup.project.as_ref(),
+            &index_bytes,
+            &[lockup.bump],
+        ];
+        let signer = &[&seeds[..]];
+
+        // 페널티를 제외한 원금을 사용자에게 반환
+        if returned_amount > 0 {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    token::Transfer {
+                        from: ctx.accounts.lockup_vault.to_account_info(),
+                        to: ctx.accounts.user_token_account.to_account_info(),
+                        authority: lockup.to_account_info(),
+                    },
+                    signer,
+                ),
+                returned_amount,
+            )?;
+        }
+
+        // 페널티는 보상 볼트로 보내 남은 스테이커에게 분배
+        // (스테이커가 없으면 보너스 볼트로 적립)
+        if penalty_amount > 0 {
+            let penalty_destination = if project.total_staked_lst > 0 {
+                project.distribute_rewards(penalty_amount);
+                ctx.accounts.reward_vault.to_account_info()
+            } else {
+                ctx.accounts.bonus_vault.to_account_info()
+            };
+
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    token::Transfer {
+                        from: ctx.accounts.lockup_vault.to_account_info(),
+                        to: penalty_destination,
+                        authority: lockup.to_account_info(),
+                    },
+                    signer,
+                ),
+                penalty_amount,
+            )?;
+        }
+
+        // 약속된 보너스 몰수
+        let forfeited_bonus = lockup.bonus_amount;
+        project.bonus_committed = project.bonus_committed.saturating_sub(forfeited_bonus);
+        lockup.bonus_amount = 0;
+
+        // 락업 정보 업데이트
+        lockup.is_released = true;
+        lockup.release_time = current_time;
+
+        let lockup_counter = &mut ctx.accounts.lockup_counter;
+        lockup_counter.active_count = lockup_counter.active_count.saturating_sub(1);
+
+        // 티어 사용량 반환
+        ctx.accounts.lockup_tier_config.release(lockup.tier_min_duration, lockup.amount);
+
+        // 투표권 체크포인트에서 해제된 락업 제거
+        checkpoint_voting_power(&mut ctx.accounts.voting_power, lockup)?;
+
+        // 락업 조기 해제 이벤트 발행
+        emit!(LockupEarlyReleasedEvent {
+            lockup: lockup.key(),
+            user: ctx.accounts.user.key(),
+            project: lockup.project,
+            index: lockup.index,
+            amount: lockup.amount,
+            penalty_amount,
+            returned_amount,
+            forfeited_bonus,
+            remaining_time: lockup.end_time - current_time,
+            release_time: current_time,
+        });
+
+        Ok(())
+    }
+
+    /// 락업 기간 연장 함수 (보너스 티어 재평가)
+    pub fn extend_lockup(
+        ctx: Context<ExtendLockup>,
+        additional_duration: i64,
+    ) -> Result<()> {
+        // 락업이 일시 중지되었는지 확인
+        ctx.accounts.platform.require_not_paused(Platform::PAUSE_LOCKUP)?;
+
+        require!(additional_duration > 0, ForestLabError::InvalidAmount);
+
+        let lockup = &mut ctx.accounts.lockup;
+
+        // 만료 전 락업만 연장 가능
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(
+            current_time < lockup.end_time,
+            ForestLabError::LockupAlreadyExpired
+        );
+
+        // 종료 시간 연장 및 늘어난 기간만큼 누적 락업량 증가
+        let previous_end_time = lockup.end_time;
+        lockup.end_time = lockup
+            .end_time
+            .checked_add(additional_duration)
+            .ok_or(ForestLabError::MathOverflow)?;
+        lockup.amount_seconds = lockup.amount_seconds.saturating_add(
+            (lockup.amount as u128).saturating_mul(additional_duration as u128),
+        );
+
+        // 티어 재평가 및 보너스 재계산
+        let amount = lockup.amount;
+        reprice_lockup(
+            lockup,
+            &mut ctx.accounts.lockup_tier_config,
+            &mut ctx.accounts.project,
+            amount,
+            ctx.accounts.bonus_vault.amount,
+        )?;
+        checkpoint_voting_power(&mut ctx.accounts.voting_power, lockup)?;
 
-    /// Forest Lab 플랫폼 초기화 함수
-    pub fn initialize_platform(
-        ctx: Context<InitializePlatform>,
-        platform_fee: u16,
-        min_stake_amount: u64,
-        admin_wallet: Pubkey,
+        // 락업 연장 이벤트 발행
+        emit!(LockupExtendedEvent {
+            lockup: lockup.key(),
+            user: ctx.accounts.user.key(),
+            project: lockup.project,
+            index: lockup.index,
+            previous_end_time,
+            end_time: lockup.end_time,
+            bonus_percentage: lockup.bonus_percentage,
+            bonus_amount: lockup.bonus_amount,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    /// 기존 락업에 LST 추가 예치 함수 (추가분은 남은 기간만큼 보너스 적용)
+    pub fn increase_lockup(
+        ctx: Context<IncreaseLockup>,
+        amount: u64,
     ) -> Result<()> {
-        // 수수료 비율 검증 (최대 2.5%)
-        require!(platform_fee <= 250, ForestLabError::InvalidFeePercentage);
+        // 락업이 일시 중지되었는지 확인
+        ctx.accounts.platform.require_not_paused(Platform::PAUSE_LOCKUP)?;
 
-        let platform = &mut ctx.accounts.platform;
-        let payer = &ctx.accounts.payer;
+        require!(amount > 0, ForestLabError::InvalidAmount);
 
-        // 플랫폼 상태 초기화
-        platform.authority = payer.key();
-        platform.admin_wallet = admin_wallet;
-        platform.platform_fee = platform_fee;
-        platform.min_stake_amount = min_stake_amount;
-        platform.is_active = true;
-        platform.created_at = Clock::get()?.unix_timestamp;
-        platform.bump = ctx.bumps.platform;
-        platform.total_projects = 0;
-        platform.total_staked_sol = 0;
+        // 펀딩 기간 확인
+        require_campaign_open(&ctx.accounts.project)?;
 
-        // 플랫폼 생성 이벤트 발행
-        emit!(PlatformCreatedEvent {
-            platform: platform.key(),
-            authority: platform.authority,
-            admin_wallet,
-            platform_fee,
-            min_stake_amount,
+        let lockup = &mut ctx.accounts.lockup;
+
+        // 만료 전 락업에만 추가 예치 가능
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(
+            current_time < lockup.end_time,
+            ForestLabError::LockupAlreadyExpired
+        );
+
+        // 추가 금액과 남은 기간만큼 누적 락업량 증가
+        let previous_amount = lockup.amount;
+        lockup.amount = lockup.amount.checked_add(amount).ok_or(ForestLabError::InvalidAmount)?;
+        lockup.amount_seconds = lockup.amount_seconds.saturating_add(
+            (amount as u128).saturating_mul((lockup.end_time - current_time) as u128),
+        );
+
+        // 티어 한도 재예약 및 보너스 재계산
+        reprice_lockup(
+            lockup,
+            &mut ctx.accounts.lockup_tier_config,
+            &mut ctx.accounts.project,
+            previous_amount,
+            ctx.accounts.bonus_vault.amount,
+        )?;
+        checkpoint_voting_power(&mut ctx.accounts.voting_power, lockup)?;
+
+        // 사용자의 LST 토큰을 기존 락업 볼트로 전송
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                token::Transfer {
+                    from: ctx.accounts.user_token_account.to_account_info(),
+                    to: ctx.accounts.lockup_vault.to_account_info(),
+                    authority: ctx.accounts.user.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        // 락업 추가 예치 이벤트 발행
+        emit!(LockupIncreasedEvent {
+            lockup: lockup.key(),
+            user: ctx.accounts.user.key(),
+            project: lockup.project,
+            index: lockup.index,
+            added_amount: amount,
+            amount: lockup.amount,
+            bonus_percentage: lockup.bonus_percentage,
+            bonus_amount: lockup.bonus_amount,
+            timestamp: current_time,
         });
 
+        // 펀딩 시작 경계를 처음 통과한 경우 시작 이벤트 발행
+        refresh_campaign_phase(&mut ctx.accounts.project)?;
+
         Ok(())
     }
 
-    /// 프로젝트 등록 함수
-    pub fn register_project(
-        ctx: Context<RegisterProject>,
-        name: String,
-        symbol: String,
-        description: String,
-        website: String,
-        image_uri: String,
-        funding_goal: u64,
-        duration: i64,
-        lst_mint: Pubkey,
-        apy_estimate: u16,
+    /// 프로젝트 거버넌스 제안 생성 함수 (생성 시점에 투표권 스냅샷)
+    pub fn create_project_proposal(
+        ctx: Context<CreateProjectProposal>,
+        title: String,
+        description_uri: String,
+        options: Vec<String>,
+        voting_start: i64,
+        voting_end: i64,
     ) -> Result<()> {
-        // 플랫폼이 활성화되어 있는지 확인
-        require!(ctx.accounts.platform.is_active, ForestLabError::PlatformInactive);
+        // 플랫폼 전체 중지 여부 확인
+        ctx.accounts.platform.require_active()?;
 
-        // 기본 검증
-        require!(!name.is_empty(), ForestLabError::EmptyName);
-        require!(!symbol.is_empty(), ForestLabError::EmptySymbol);
-        require!(funding_goal > 0, ForestLabError::InvalidFundingGoal);
-        require!(duration > 0, ForestLabError::InvalidDuration);
+        let current_time = Clock::get()?.unix_timestamp;
+
+        // 제안 내용 검증
+        require!(
+            !title.is_empty() && title.len() <= ProjectProposal::MAX_TITLE_LEN,
+            ForestLabError::InvalidProposal
+        );
+        require!(
+            description_uri.len() <= ProjectProposal::MAX_URI_LEN,
+            ForestLabError::InvalidProposal
+        );
+        require!(
+            options.len() >= 2 && options.len() <= ProjectProposal::MAX_OPTIONS,
+            ForestLabError::InvalidProposal
+        );
+        require!(
+            options
+                .iter()
+                .all(|option| !option.is_empty() && option.len() <= ProjectProposal::MAX_OPTION_LEN),
+            ForestLabError::InvalidProposal
+        );
+        require!(
+            voting_start >= current_time && voting_end > voting_start,
+            ForestLabError::InvalidProposal
+        );
 
         let project = &mut ctx.accounts.project;
-        let creator = &ctx.accounts.creator;
-        let platform = &mut ctx.accounts.platform;
+        let proposal = &mut ctx.accounts.proposal;
 
-        // 프로젝트 정보 초기화
-        project.creator = creator.key();
-        project.platform = platform.key();
-        project.name = name.clone();
-        project.symbol = symbol.clone();
-        project.description = description;
-        project.website = website;
-        project.image_uri = image_uri;
-        project.funding_goal = funding_goal;
-        project.funds_raised = 0;
-        project.supporters_count = 0;
-        project.lst_mint = lst_mint;
-        project.status = ProjectStatus::Active;
-        project.created_at = Clock::get()?.unix_timestamp;
-        project.end_time = Clock::get()?.unix_timestamp + duration;
-        project.funds_claimed = false;
-        project.manager_fee_percentage = 250; // 기본 2.5%
-        project.payout_wallet = creator.key(); // 기본값은 생성자 지갑
-        project.apy_estimate = apy_estimate; // 예상 APY (100 = 1%)
-        project.total_rewards_distributed = 0;
-        project.bump = ctx.bumps.project;
+        // 제안 정보 초기화
+        proposal.project = project.key();
+        proposal.proposer = ctx.accounts.proposer.key();
+        proposal.index = project.proposal_count;
+        proposal.title = title;
+        proposal.description_uri = description_uri;
+        proposal.votes = vec![0; options.len()];
+        proposal.options = options;
+        proposal.total_weight = 0;
+        proposal.snapshot_time = current_time;
+        proposal.voting_start = voting_start;
+        proposal.voting_end = voting_end;
+        proposal.finalized = false;
+        proposal.winning_option = None;
+        proposal.finalized_at = 0;
+        proposal.bump = ctx.bumps.proposal;
 
-        // 플랫폼 통계 업데이트
-        platform.total_projects = platform.total_projects.saturating_add(1);
+        project.proposal_count = project.proposal_count.saturating_add(1);
 
-        // 프로젝트 등록 이벤트 발행
-        emit!(ProjectRegisteredEvent {
+        // 거버넌스 제안 생성 이벤트 발행
+        emit!(ProjectProposalCreatedEvent {
+            proposal: proposal.key(),
             project: project.key(),
-            creator: creator.key(),
-            platform: platform.key(),
-            name,
-            symbol: project.symbol.clone(),
-            funding_goal,
-            end_time: project.end_time,
-            lst_mint,
-            apy_estimate,
+            proposer: proposal.proposer,
+            index: proposal.index,
+            title: proposal.title.clone(),
+            options: proposal.options.clone(),
+            snapshot_time: current_time,
+            voting_start,
+            voting_end,
         });
 
         Ok(())
     }
 
-    /// 스테이킹 기록 함수 (Sanctum API 호출 후 실행)
-    pub fn record_project_stake(
-        ctx: Context<RecordProjectStake>,
-        amount: u64,
-        lst_amount: u64,
+    /// 거버넌스 투표 함수 (스테이킹 수량 또는 락업 투표권 가중치)
+    pub fn cast_vote(
+        ctx: Context<CastVote>,
+        option: u8,
+        source: VoteWeightSource,
     ) -> Result<()> {
-        let project = &mut ctx.accounts.project;
-        let user = &ctx.accounts.user;
-        let platform = &mut ctx.accounts.platform;
+        // 플랫폼 전체 중지 여부 확인
+        ctx.accounts.platform.require_active()?;
 
-        // 프로젝트가 활성 상태인지 확인
+        let proposal = &mut ctx.accounts.proposal;
+
+        // 투표 기간 및 선택지 확인
+        let current_time = Clock::get()?.unix_timestamp;
         require!(
-            project.status == ProjectStatus::Active,
-            ForestLabError::ProjectInactive
+            current_time >= proposal.voting_start && current_time < proposal.voting_end,
+            ForestLabError::VotingNotActive
         );
+        require!(
+            (option as usize) < proposal.options.len(),
+            ForestLabError::InvalidVoteOption
+        );
+
+        // 스냅샷 시점 기준 가중치 (이후 변경은 기록으로 되돌려 전송을 통한 이중 투표 방지)
+        let weight = match source {
+            VoteWeightSource::Stake => {
+                let stake_info = ctx
+                    .accounts
+                    .stake_info
+                    .as_ref()
+                    .ok_or(ForestLabError::MissingVoteWeightAccount)?;
+                stake_info
+                    .lst_amount_at(proposal.snapshot_time)
+                    .ok_or(ForestLabError::VoteSnapshotUnavailable)?
+            }
+            VoteWeightSource::Lockup => {
+                let voting_power = ctx
+                    .accounts
+                    .voting_power
+                    .as_ref()
+                    .ok_or(ForestLabError::MissingVoteWeightAccount)?;
+                voting_power
+                    .snapshot_power_at(proposal.snapshot_time)
+                    .ok_or(ForestLabError::VoteSnapshotUnavailable)?
+            }
+        };
+        require!(weight > 0, ForestLabError::NoVotingPower);
 
-        // 플랫폼이 활성화되어 있는지 확인
-        require!(platform.is_active, ForestLabError::PlatformInactive);
+        // 득표 반영
+        proposal.votes[option as usize] = proposal.votes[option as usize].saturating_add(weight);
+        proposal.total_weight = proposal.total_weight.saturating_add(weight);
 
-        // 최소 스테이킹 금액 확인
+        // 투표 기록 저장
+        let vote_record = &mut ctx.accounts.vote_record;
+        vote_record.proposal = proposal.key();
+        vote_record.voter = ctx.accounts.voter.key();
+        vote_record.option = option;
+        vote_record.weight = weight;
+        vote_record.source = source;
+        vote_record.voted_at = current_time;
+        vote_record.bump = ctx.bumps.vote_record;
+
+        // 거버넌스 투표 이벤트 발행
+        emit!(VoteCastEvent {
+            proposal: proposal.key(),
+            voter: vote_record.voter,
+            option,
+            weight,
+            source,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    /// 거버넌스 제안 결과 확정 함수 (투표 종료 후 누구나 호출 가능)
+    pub fn finalize_proposal(
+        ctx: Context<FinalizeProposal>,
+    ) -> Result<()> {
+        // 플랫폼 전체 중지 여부 확인
+        ctx.accounts.platform.require_active()?;
+
+        let proposal = &mut ctx.accounts.proposal;
+
+        // 투표 종료 확인
+        let current_time = Clock::get()?.unix_timestamp;
         require!(
-            amount >= platform.min_stake_amount,
-            ForestLabError::BelowMinimumStakeAmount
+            current_time >= proposal.voting_end,
+            ForestLabError::VotingNotEnded
         );
 
-        // 스테이킹 기록 생성 또는 업데이트
-        let stake_info = &mut ctx.accounts.stake_info;
-        
-        let is_new_stake = stake_info.user == Pubkey::default();
-        
-        if is_new_stake {
-            // 새 스테이킹 기록 초기화
-            stake_info.user = user.key();
-            stake_info.project = project.key();
-            stake_info.initial_stake_amount = amount;
-            stake_info.current_lst_amount = lst_amount;
-            stake_info.first_stake_time = Clock::get()?.unix_timestamp;
-            stake_info.bump = ctx.bumps.stake_info;
-            stake_info.rewards_claimed = 0;
-            stake_info.last_claim_time = 0;
-        } else {
-            // 기존 스테이킹 기록 업데이트
-            stake_info.initial_stake_amount = stake_info.initial_stake_amount.saturating_add(amount);
-            stake_info.current_lst_amount = stake_info.current_lst_amount.saturating_add(lst_amount);
-        }
+        // 결과 기록
+        proposal.winning_option = proposal.leading_option();
+        proposal.finalized = true;
+        proposal.finalized_at = current_time;
+
+        // 거버넌스 제안 결과 확정 이벤트 발행
+        emit!(ProjectProposalFinalizedEvent {
+            proposal: proposal.key(),
+            project: proposal.project,
+            votes: proposal.votes.clone(),
+            total_weight: proposal.total_weight,
+            winning_option: proposal.winning_option,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    /// 펀딩 기간 종료 후 프로젝트 정산 함수 (누구나 호출 가능)
+    ///
+    /// 목표 금액을 달성하면 Completed, 아니면 Cancelled로 전환합니다.
+    /// 취소된 프로젝트는 보상을 지급받지 못하며 스테이커는 언스테이킹과
+    /// 페널티 없는 락업 조기 해제로 자금을 회수합니다. 아직 스왑·리스테이킹되지
+    /// 않은 프로젝트 몫은 스테이커 보상으로 돌려놓습니다.
+    pub fn finalize_project(
+        ctx: Context<FinalizeProject>,
+    ) -> Result<()> {
+        // 정산 작업이 일시 중지되었는지 확인
+        ctx.accounts.platform.require_not_paused(Platform::PAUSE_SETTLEMENT)?;
+
+        let project = &mut ctx.accounts.project;
+
+        // 펀딩 기간 종료 확인
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(
+            current_time >= project.end_time,
+            ForestLabError::CampaignNotEnded
+        );
+        refresh_campaign_phase(project)?;
 
-        stake_info.last_stake_time = Clock::get()?.unix_timestamp;
+        // 목표 달성 여부에 따라 상태 결정
+        let (new_status, reason) = if project.funds_raised >= project.funding_goal {
+            (ProjectStatus::Completed, StatusReason::FundingGoalReached)
+        } else {
+            (ProjectStatus::Cancelled, StatusReason::FundingGoalMissed)
+        };
+        apply_project_status(
+            project,
+            new_status,
+            reason,
+            StatusActor::Settlement,
+            ctx.accounts.authority.key(),
+        )?;
 
-        // 프로젝트 정보 업데이트
-        let is_new_supporter = is_new_stake;
-        if is_new_supporter {
-            project.supporters_count = project.supporters_count.saturating_add(1);
+        // 취소된 프로젝트의 미실행 프로젝트 몫을 스테이커에게 반환
+        if new_status == ProjectStatus::Cancelled {
+            return_project_share_to_stakers(
+                project,
+                &ctx.accounts.project_share_vault,
+                &ctx.accounts.reward_vault,
+                &ctx.accounts.vault_authority,
+                &ctx.accounts.token_program.to_account_info(),
+                ctx.bumps.vault_authority,
+            )?;
         }
-        project.funds_raised = project.funds_raised.saturating_add(amount);
-        
-        // 플랫폼 통계 업데이트
-        platform.total_staked_sol = platform.total_staked_sol.saturating_add(amount);
 
-        // 스테이킹 이벤트 발행
-        emit!(ProjectStakedEvent {
+        // 프로젝트 펀딩 정산 이벤트 발행
+        emit!(ProjectFinalizedEvent {
             project: project.key(),
-            user: user.key(),
-            amount,
-            lst_amount,
-            is_new_supporter,
+            status: new_status,
+            funds_raised: project.funds_raised,
+            funding_goal: project.funding_goal,
+            finalized_by: ctx.accounts.authority.key(),
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    /// 취소된 프로젝트의 미실행 프로젝트 몫을 스테이커 보상으로 돌려놓는 함수 (누구나 호출 가능)
+    ///
+    /// 정산 외의 경로(플랫폼 결정, 멀티시그 제안)로 취소된 프로젝트나 취소 이후
+    /// 남은 몫을 처리합니다.
+    pub fn return_project_share(ctx: Context<ReturnProjectShare>) -> Result<()> {
+        // 정산 작업이 일시 중지되었는지 확인
+        ctx.accounts.platform.require_not_paused(Platform::PAUSE_SETTLEMENT)?;
+
+        let returned = return_project_share_to_stakers(
+            &mut ctx.accounts.project,
+            &ctx.accounts.project_share_vault,
+            &ctx.accounts.reward_vault,
+            &ctx.accounts.vault_authority,
+            &ctx.accounts.token_program.to_account_info(),
+            ctx.bumps.vault_authority,
+        )?;
+        require!(returned > 0, ForestLabError::InvalidAmount);
+
+        Ok(())
+    }
+
+    /// 플랫폼 관리자 이전 제안 함수 (새 관리자가 수락해야 완료)
+    pub fn propose_platform_authority(
+        ctx: Context<ProposePlatformAuthority>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        let platform = &mut ctx.accounts.platform;
+
+        // 대기 중인 관리자 설정 (기존 제안은 덮어씀)
+        platform.pending_authority = Some(new_authority);
+
+        // 플랫폼 관리자 이전 제안 이벤트 발행
+        emit!(PlatformAuthorityProposedEvent {
+            platform: platform.key(),
+            authority: platform.authority,
+            pending_authority: new_authority,
             timestamp: Clock::get()?.unix_timestamp,
         });
 
         Ok(())
     }
 
-    /// 언스테이킹 기록 함수 (Sanctum API 호출 후 실행)
-    pub fn record_project_unstake(
-        ctx: Context<RecordProjectUnstake>,
-        amount: u64,
-        lst_amount: u64,
+    /// 플랫폼 관리자 이전 수락 함수 (새 관리자 서명 필요)
+    pub fn accept_platform_authority(
+        ctx: Context<AcceptPlatformAuthority>,
     ) -> Result<()> {
-        let project = &mut ctx.accounts.project;
-        let user = &ctx.accounts.user;
         let platform = &mut ctx.accounts.platform;
-        let stake_info = &mut ctx.accounts.stake_info;
 
-        // 스테이킹 정보가 존재하는지 확인
+        // 관리자 변경 및 대기 상태 해제
+        let previous_authority = platform.authority;
+        platform.authority = ctx.accounts.new_authority.key();
+        platform.pending_authority = None;
+
+        // 플랫폼 관리자 이전 완료 이벤트 발행
+        emit!(PlatformAuthorityTransferredEvent {
+            platform: platform.key(),
+            previous_authority,
+            new_authority: platform.authority,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// 플랫폼 관리자 이전 취소 함수
+    pub fn cancel_platform_authority_transfer(
+        ctx: Context<CancelPlatformAuthorityTransfer>,
+    ) -> Result<()> {
+        let platform = &mut ctx.accounts.platform;
+
+        let cancelled_authority = platform
+            .pending_authority
+            .take()
+            .ok_or(ForestLabError::NoPendingAuthority)?;
+
+        // 플랫폼 관리자 이전 취소 이벤트 발행
+        emit!(PlatformAuthorityTransferCancelledEvent {
+            platform: platform.key(),
+            authority: platform.authority,
+            cancelled_authority,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// 프로젝트 관리자 이전 제안 함수 (새 관리자가 수락해야 완료)
+    ///
+    /// 프로젝트 PDA 시드는 생성자 기준으로 유지되므로 기존 StakeInfo/Lockup PDA는 그대로입니다.
+    pub fn transfer_project_authority(
+        ctx: Context<TransferProjectAuthority>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        // 플랫폼 전체 중지 여부 확인
+        ctx.accounts.platform.require_active()?;
+
+        // 멀티시그가 활성화된 경우 프로젝트 관리자 단독 이전 불가
         require!(
-            stake_info.user == user.key() && stake_info.project == project.key(),
-            ForestLabError::StakeInfoNotFound
+            !MultisigConfig::is_active_for(&ctx.accounts.multisig_config)?,
+            ForestLabError::MultisigRequired
         );
 
-        // LST 잔액이 충분한지 확인
-        require!(
-            stake_info.current_lst_amount >= lst_amount,
-            ForestLabError::InsufficientLstBalance
-        );
+        let project = &mut ctx.accounts.project;
+
+        // 대기 중인 관리자 설정 (기존 제안은 덮어씀)
+        project.pending_authority = Some(new_authority);
+
+        // 프로젝트 관리자 이전 제안 이벤트 발행
+        emit!(ProjectAuthorityProposedEvent {
+            project: project.key(),
+            authority: ctx.accounts.authority.key(),
+            pending_authority: new_authority,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// 프로젝트 관리자 이전 수락 함수 (새 관리자 서명 필요)
+    pub fn accept_project_authority(
+        ctx: Context<AcceptProjectAuthority>,
+    ) -> Result<()> {
+        // 플랫폼 전체 중지 여부 확인
+        ctx.accounts.platform.require_active()?;
+
+        let project = &mut ctx.accounts.project;
+
+        // 관리자 변경 및 대기 상태 해제
+        let previous_authority = if project.authority == Pubkey::default() {
+            project.creator
+        } else {
+            project.authority
+        };
+        project.authority = ctx.accounts.new_authority.key();
+        project.pending_authority = None;
+
+        // 프로젝트 관리자 이전 완료 이벤트 발행
+        emit!(ProjectAuthorityTransferredEvent {
+            project: project.key(),
+            previous_authority,
+            new_authority: project.authority,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
 
-        // 스테이킹 정보 업데이트
-        let proportion = lst_amount as f64 / stake_info.current_lst_amount as f64;
-        let sol_amount_to_remove = (stake_info.initial_stake_amount as f64 * proportion) as u64;
+    /// 프로젝트 관리자 이전 취소 함수
+    pub fn cancel_project_authority_transfer(
+        ctx: Context<CancelProjectAuthorityTransfer>,
+    ) -> Result<()> {
+        let project = &mut ctx.accounts.project;
 
-        stake_info.initial_stake_amount = stake_info.initial_stake_amount.saturating_sub(sol_amount_to_remove);
-        stake_info.current_lst_amount = stake_info.current_lst_amount.saturating_sub(lst_amount);
-        
-        // 프로젝트 정보 업데이트
-        project.funds_raised = project.funds_raised.saturating_sub(sol_amount_to_remove);
-        
-        // 스테이킹 정보가 0이 되면 지원자 수 감소
-        if stake_info.current_lst_amount == 0 {
-            project.supporters_count = project.supporters_count.saturating_sub(1);
-        }
-        
-        // 플랫폼 통계 업데이트
-        platform.total_staked_sol = platform.total_staked_sol.saturating_sub(sol_amount_to_remove);
+        let cancelled_authority = project
+            .pending_authority
+            .take()
+            .ok_or(ForestLabError::NoPendingAuthority)?;
 
-        // 언스테이킹 이벤트 발행
-        emit!(ProjectUnstakedEvent {
+        // 프로젝트 관리자 이전 취소 이벤트 발행
+        emit!(ProjectAuthorityTransferCancelledEvent {
             project: project.key(),
-            user: user.key(),
-            sol_amount: sol_amount_to_remove,
-            lst_amount,
+            authority: ctx.accounts.authority.key(),
+            cancelled_authority,
             timestamp: Clock::get()?.unix_timestamp,
         });
 
         Ok(())
     }
 
-    /// 에포크 보상 처리 및 기록 함수
-    pub fn process_epoch_rewards(
-        ctx: Context<ProcessEpochRewards>,
-        epoch: u64,
-        total_rewards: u64,
+    /// 플랫폼 역할 부여 함수 (최고 관리자만)
+    pub fn grant_role(
+        ctx: Context<GrantRole>,
+        member: Pubkey,
+        role: Role,
     ) -> Result<()> {
-        let project = &mut ctx.accounts.project;
         let platform = &ctx.accounts.platform;
-        let rewards_info = &mut ctx.accounts.rewards_info;
         let authority = &ctx.accounts.authority;
+        let roles = &mut ctx.accounts.roles;
 
-        // 권한 확인 (플랫폼 관리자만)
-        require!(
-            platform.authority == authority.key() || platform.admin_wallet == authority.key(),
-            ForestLabError::Unauthorized
-        );
-
-        // 이 에포크에 이미 처리되었는지 확인
-        if rewards_info.epoch > 0 {
-            require!(
-                rewards_info.epoch != epoch,
-                ForestLabError::AlreadyProcessedForEpoch
-            );
+        // 역할 목록 초기화 (플랫폼 초기화 이전에 생성된 플랫폼의 첫 부여인 경우)
+        if roles.platform == Pubkey::default() {
+            initialize_roles(roles, platform.key(), platform.admin_wallet, ctx.bumps.roles, authority.key())?;
         }
 
-        // Forest Lab의 수수료 계산 (기본 2.5%)
-        let platform_fee_amount = total_rewards
-            .saturating_mul(platform.platform_fee as u64)
-            .saturating_div(10000);
-            
-        // 프로젝트에 분배할 보상 계산
-        let project_rewards = total_rewards.saturating_sub(platform_fee_amount);
+        // 권한 확인 (최고 관리자)
+        require_role(platform, Some(roles), &authority.key(), Role::SuperAdmin)?;
 
-        // 보상 정보 초기화 또는 업데이트
-        rewards_info.project = project.key();
-        rewards_info.epoch = epoch;
-        rewards_info.total_rewards = total_rewards;
-        rewards_info.platform_fee = platform_fee_amount;
-        rewards_info.project_rewards = project_rewards;
-        rewards_info.processed = false; // 스왑 처리 상태 초기화
-        rewards_info.timestamp = Clock::get()?.unix_timestamp;
-        if rewards_info.bump == 0 {
-            rewards_info.bump = ctx.bumps.rewards_info;
-        }
-        
-        // 프로젝트의 누적 보상 업데이트
-        project.total_rewards_distributed = project.total_rewards_distributed.saturating_add(project_rewards);
-        
-        // 현재 APY 계산 업데이트 (선택적)
-        if project.funds_raised > 0 {
-            // 간단한 APY 계산 로직 (연간 예상 수익률로 변환)
-            // 주의: 이는 매우 단순화된 계산으로, 실제 APY 계산은 더 복잡할 수 있음
-            let annualized_rewards = project_rewards.saturating_mul(365 * 2); // 약 2일마다 에포크 가정
-            let apy = annualized_rewards
-                .saturating_mul(10000)
-                .saturating_div(project.funds_raised);
-            
-            // APY 업데이트 (가중 평균으로)
-            project.apy_estimate = ((project.apy_estimate as u64).saturating_mul(9).saturating_add(apy as u64) / 10) as u16;
-        }
+        // 역할 비트 추가
+        roles.grant(member, role.bit())?;
 
-        // 보상 처리 이벤트 발행
-        emit!(EpochRewardsProcessedEvent {
-            project: project.key(),
-            epoch,
-            total_rewards,
-            platform_fee: platform_fee_amount,
-            project_rewards,
+        // 역할 부여 이벤트 발행
+        emit!(RoleGrantedEvent {
+            platform: platform.key(),
+            member,
+            role,
+            granted_by: authority.key(),
             timestamp: Clock::get()?.unix_timestamp,
         });
 
         Ok(())
     }
 
-    /// 에포크 보상을 USDC로 스왑하고 프로젝트에 분배하는 함수
-    pub fn swap_and_distribute_rewards(
-        ctx: Context<SwapAndDistributeRewards>,
-        reward_amount: u64,
-        usdc_amount: u64,
+    /// 플랫폼 역할 회수 함수 (최고 관리자만)
+    pub fn revoke_role(
+        ctx: Context<RevokeRole>,
+        member: Pubkey,
+        role: Role,
     ) -> Result<()> {
-        let rewards_info = &mut ctx.accounts.rewards_info;
-        let project = &ctx.accounts.project;
-        let authority = &ctx.accounts.authority;
         let platform = &ctx.accounts.platform;
-        
-        // 권한 확인 (플랫폼 관리자만)
-        require!(
-            platform.authority == authority.key() || platform.admin_wallet == authority.key(),
-            ForestLabError::Unauthorized
-        );
-        
-        // 이미 처리되었는지 확인
-        require!(!rewards_info.processed, ForestLabError::AlreadyProcessed);
-        
-        // 스왑된 USDC 금액 확인
-        require!(usdc_amount > 0, ForestLabError::InvalidAmount);
-        
-        // 프로젝트 수수료 계산
-        let project_fee = usdc_amount
-            .saturating_mul(project.manager_fee_percentage as u64)
-            .saturating_div(10000);
-        
-        // 프로젝트에 전송할 금액
-        let project_amount = usdc_amount.saturating_sub(project_fee);
-        
-        // USDC를 프로젝트 지갑으로 전송
-        // 여기서는 이미 스왑이 완료되었다고 가정하고, 분배만 기록
-        
-        // 보상 정보 업데이트
-        rewards_info.processed = true;
-        rewards_info.swapped_amount = usdc_amount;
-        rewards_info.project_fee = project_fee;
-        rewards_info.project_amount = project_amount;
-        
-        // 스왑 및 분배 이벤트 발행
-        emit!(RewardsDistributedEvent {
-            project: project.key(),
-            epoch: rewards_info.epoch,
-            lst_amount: reward_amount,
-            usdc_amount,
-            project_fee,
-            project_amount,
+        let authority = &ctx.accounts.authority;
+        let roles = &mut ctx.accounts.roles;
+
+        // 권한 확인 (최고 관리자)
+        require_role(platform, Some(roles), &authority.key(), Role::SuperAdmin)?;
+
+        // 역할 비트 제거 (남은 역할이 없으면 항목 삭제)
+        roles.revoke(&member, role.bit());
+
+        // 역할 회수 이벤트 발행
+        emit!(RoleRevokedEvent {
+            platform: platform.key(),
+            member,
+            role,
+            revoked_by: authority.key(),
             timestamp: Clock::get()?.unix_timestamp,
         });
-        
+
         Ok(())
     }
 
-    /// 사용자가 프로젝트 보상을 청구하는 함수
-    pub fn claim_project_rewards(
-        ctx: Context<ClaimProjectRewards>,
-        reward_token_amount: u64,
+    /// 작업 일시 중지 함수 (일시 중지 권한자 또는 최고 관리자)
+    pub fn pause_operations(
+        ctx: Context<PauseOperations>,
+        operations: u8,
     ) -> Result<()> {
-        let stake_info = &mut ctx.accounts.stake_info;
-        let user = &ctx.accounts.user;
-        let project = &ctx.accounts.project;
-        
-        // 스테이킹 정보가 존재하는지 확인
-        require!(
-            stake_info.user == user.key() && stake_info.project == project.key(),
-            ForestLabError::StakeInfoNotFound
-        );
-        
-        // 청구 가능한 보상이 있는지 확인
-        require!(
-            reward_token_amount > 0,
-            ForestLabError::InvalidAmount
-        );
-        
-        // 토큰 이체는 프론트엔드에서 처리 (청구 기록만 업데이트)
-        stake_info.rewards_claimed = stake_info.rewards_claimed.saturating_add(reward_token_amount);
-        stake_info.last_claim_time = Clock::get()?.unix_timestamp;
-        
-        // 보상 청구 이벤트 발행
-        emit!(RewardsClaimedEvent {
-            project: project.key(),
-            user: user.key(),
-            reward_token_amount,
+        // 모든 비트가 정의된 플래그이므로 빈 값만 거부
+        require!(operations != 0, ForestLabError::InvalidPauseFlags);
+
+        let platform = &mut ctx.accounts.platform;
+        let authority = &ctx.accounts.authority;
+
+        // 권한 확인 (일시 중지 권한자)
+        require_role(platform, ctx.accounts.roles.as_deref(), &authority.key(), Role::Pauser)?;
+
+        platform.paused_operations |= operations;
+
+        // 작업 일시 중지 이벤트 발행
+        emit!(OperationsPausedEvent {
+            platform: platform.key(),
+            operations,
+            paused_operations: platform.paused_operations,
+            paused_by: authority.key(),
             timestamp: Clock::get()?.unix_timestamp,
         });
-        
+
         Ok(())
     }
 
-    /// 프로젝트 수수료 설정 업데이트 함수
-    pub fn update_project_fee(
-        ctx: Context<UpdateProjectSettings>,
-        manager_fee_percentage: u16,
-        payout_wallet: Option<Pubkey>,
+    /// 작업 재개 함수 (최고 관리자만, 일시 중지 권한자는 재개 불가)
+    pub fn unpause_operations(
+        ctx: Context<UnpauseOperations>,
+        operations: u8,
     ) -> Result<()> {
-        let project = &mut ctx.accounts.project;
+        // 모든 비트가 정의된 플래그이므로 빈 값만 거부
+        require!(operations != 0, ForestLabError::InvalidPauseFlags);
+
+        let platform = &mut ctx.accounts.platform;
         let authority = &ctx.accounts.authority;
-        
-        // 권한 확인 (프로젝트 생성자만)
-        require!(
-            project.creator == authority.key(),
+
+        // 권한 확인 (최고 관리자)
+        require_role(platform, ctx.accounts.roles.as_deref(), &authority.key(), Role::SuperAdmin)?;
+
+        platform.paused_operations &= !operations;
+
+        // 작업 재개 이벤트 발행
+        emit!(OperationsUnpausedEvent {
+            platform: platform.key(),
+            operations,
+            paused_operations: platform.paused_operations,
+            unpaused_by: authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// 구버전 플랫폼 계정을 현재 레이아웃으로 마이그레이션 (플랫폼 관리자만)
+    pub fn migrate_platform(ctx: Context<MigratePlatform>) -> Result<()> {
+        let platform_info = ctx.accounts.platform.to_account_info();
+        let old_size = platform_info.data_len();
+        let legacy: LegacyPlatform =
+            load_legacy_account(&platform_info, &Platform::DISCRIMINATOR, Platform::SPACE)?;
+
+        // 권한 확인 (플랫폼 관리자)
+        require_keys_eq!(
+            legacy.authority,
+            ctx.accounts.authority.key(),
             ForestLabError::Unauthorized
         );
-        
-        // 수수료 비율 유효성 검증 (최대 100%)
-        require!(
-            manager_fee_percentage <= 10000,
-            ForestLabError::InvalidFeePercentage
-        );
-        
-        // 프로젝트 수수료 설정 업데이트
-        project.manager_fee_percentage = manager_fee_percentage;
-        
-        // 지불 지갑 업데이트 (선택적)
-        if let Some(wallet) = payout_wallet {
-            project.payout_wallet = wallet;
+
+        // 추가된 필드는 신규 플랫폼과 같은 기본값으로 채움
+        let mut platform = Platform {
+            authority: legacy.authority,
+            admin_wallet: legacy.admin_wallet,
+            platform_fee: legacy.platform_fee,
+            min_stake_amount: legacy.min_stake_amount,
+            is_active: legacy.is_active,
+            total_projects: legacy.total_projects,
+            total_staked_sol: legacy.total_staked_sol,
+            created_at: legacy.created_at,
+            fees_collected: Vec::new(),
+            pending_authority: None,
+            paused_operations: 0,
+            settings_timelock: Platform::DEFAULT_SETTINGS_TIMELOCK,
+            bump: legacy.bump,
+            ..Default::default()
+        };
+        platform.clear_pending_settings();
+
+        rewrite_migrated_account(
+            &platform_info,
+            &ctx.accounts.authority.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            Platform::SPACE,
+            &platform,
+        )?;
+
+        // 역할 목록이 없던 플랫폼은 관리자 지갑의 기존 운영 권한을 역할로 부여
+        if ctx.accounts.roles.platform == Pubkey::default() {
+            initialize_roles(
+                &mut ctx.accounts.roles,
+                platform_info.key(),
+                platform.admin_wallet,
+                ctx.bumps.roles,
+                ctx.accounts.authority.key(),
+            )?;
         }
-        
-        // 설정 업데이트 이벤트 발행
-        emit!(ProjectSettingsUpdatedEvent {
-            project: project.key(),
-            manager_fee_percentage,
-            payout_wallet: project.payout_wallet,
+
+        // 계정 마이그레이션 이벤트 발행
+        emit!(AccountMigratedEvent {
+            account: platform_info.key(),
+            old_size: old_size as u64,
+            new_size: Platform::SPACE as u64,
+            migrated_by: ctx.accounts.authority.key(),
             timestamp: Clock::get()?.unix_timestamp,
         });
-        
+
         Ok(())
     }
 
-    /// 프로젝트 상태 업데이트 함수
-    pub fn update_project_status(
-        ctx: Context<UpdateProjectStatus>,
-        new_status: ProjectStatus,
-    ) -> Result<()> {
+    /// 구버전 프로젝트 계정을 현재 레이아웃으로 마이그레이션 (누구나 실행 가능, 결과는 결정적)
+    pub fn migrate_project(ctx: Context<MigrateProject>) -> Result<()> {
+        let project_info = ctx.accounts.project.to_account_info();
+        let old_size = project_info.data_len();
+        let legacy: LegacyProject =
+            load_legacy_account(&project_info, &Project::DISCRIMINATOR, Project::SPACE)?;
+
+        // 프로젝트 PDA 검증
+        let expected = Pubkey::create_program_address(
+            &[b"project", legacy.name.as_bytes(), legacy.creator.as_ref(), &[legacy.bump]],
+            ctx.program_id,
+        )
+        .map_err(|_| error!(ForestLabError::InvalidLegacyAccount))?;
+        require_keys_eq!(expected, project_info.key(), ForestLabError::InvalidLegacyAccount);
+
+        // 구버전 프로젝트는 생성 즉시 펀딩이 시작되었고, 일시 중지 주체를 알 수 없으므로 플랫폼 결정으로 간주
+        let (status_reason, status_actor) = match legacy.status {
+            ProjectStatus::Active => (StatusReason::Registered, StatusActor::ProjectAuthority),
+            _ => (StatusReason::PlatformDecision, StatusActor::Platform),
+        };
+        let project = Project {
+            creator: legacy.creator,
+            platform: legacy.platform,
+            name: legacy.name,
+            symbol: legacy.symbol,
+            description: legacy.description,
+            website: legacy.website,
+            image_uri: legacy.image_uri,
+            funding_goal: legacy.funding_goal,
+            funds_raised: legacy.funds_raised,
+            supporters_count: legacy.supporters_count,
+            lst_mint: legacy.lst_mint,
+            status: legacy.status,
+            created_at: legacy.created_at,
+            end_time: legacy.end_time,
+            funds_claimed: legacy.funds_claimed,
+            manager_fee_percentage: legacy.manager_fee_percentage,
+            payout_wallet: legacy.payout_wallet,
+            apy_estimate: legacy.apy_estimate,
+            total_rewards_distributed: legacy.total_rewards_distributed,
+            // 스테이킹 LST 합계는 각 StakeInfo 마이그레이션 시 누적
+            total_staked_lst: 0,
+            acc_reward_per_share: 0,
+            bonus_committed: 0,
+            proposal_count: 0,
+            start_time: legacy.created_at,
+            max_raise: 0,
+            campaign_started: true,
+            campaign_ended: false,
+            status_reason,
+            status_updated_at: legacy.created_at,
+            status_actor,
+            authority: legacy.creator,
+            pending_authority: None,
+            bump: legacy.bump,
+        };
+
+        rewrite_migrated_account(
+            &project_info,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            Project::SPACE,
+            &project,
+        )?;
+
+        // 계정 마이그레이션 이벤트 발행
+        emit!(AccountMigratedEvent {
+            account: project_info.key(),
+            old_size: old_size as u64,
+            new_size: Project::SPACE as u64,
+            migrated_by: ctx.accounts.payer.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// 구버전 스테이킹 정보 계정을 현재 레이아웃으로 마이그레이션 (누구나 실행 가능, 결과는 결정적)
+    pub fn migrate_stake_info(ctx: Context<MigrateStakeInfo>) -> Result<()> {
+        let stake_info_info = ctx.accounts.stake_info.to_account_info();
+        let old_size = stake_info_info.data_len();
+        let legacy: LegacyStakeInfo =
+            load_legacy_account(&stake_info_info, &StakeInfo::DISCRIMINATOR, StakeInfo::SPACE)?;
+
+        // 스테이킹 정보 PDA 및 프로젝트 검증
         let project = &mut ctx.accounts.project;
-        let authority = &ctx.accounts.authority;
-        let platform = &ctx.accounts.platform;
-        
-        // 권한 확인 (프로젝트 생성자 또는 플랫폼 관리자)
-        require!(
-            project.creator == authority.key() || 
-            platform.authority == authority.key() || 
-            platform.admin_wallet == authority.key(),
-            ForestLabError::Unauthorized
-        );
-        
-        // 이전 상태 저장
-        let previous_status = project.status;
-        
-        // 상태 업데이트
-        project.status = new_status;
-        
-        // 상태 업데이트 이벤트 발행
-        emit!(ProjectStatusUpdatedEvent {
-            project: project.key(),
-            previous_status,
-            new_status,
-            updated_by: authority.key(),
+        require_keys_eq!(legacy.project, project.key(), ForestLabError::InvalidLegacyAccount);
+        let expected = Pubkey::create_program_address(
+            &[b"stake_info", legacy.user.as_ref(), legacy.project.as_ref(), &[legacy.bump]],
+            ctx.program_id,
+        )
+        .map_err(|_| error!(ForestLabError::InvalidLegacyAccount))?;
+        require_keys_eq!(expected, stake_info_info.key(), ForestLabError::InvalidLegacyAccount);
+
+        let mut stake_info = StakeInfo {
+            user: legacy.user,
+            project: legacy.project,
+            initial_stake_amount: legacy.initial_stake_amount,
+            current_lst_amount: legacy.current_lst_amount,
+            first_stake_time: legacy.first_stake_time,
+            last_stake_time: legacy.last_stake_time,
+            rewards_claimed: legacy.rewards_claimed,
+            last_claim_time: legacy.last_claim_time,
+            pending_rewards: 0,
+            bump: legacy.bump,
+            ..Default::default()
+        };
+
+        // 마이그레이션 시점부터 보상 분배 대상에 포함 (이전 누적 보상은 소급하지 않음)
+        stake_info.reset_reward_debt(project.acc_reward_per_share);
+        project.total_staked_lst = project.total_staked_lst.saturating_add(legacy.current_lst_amount);
+
+        rewrite_migrated_account(
+            &stake_info_info,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            StakeInfo::SPACE,
+            &stake_info,
+        )?;
+
+        // 계정 마이그레이션 이벤트 발행
+        emit!(AccountMigratedEvent {
+            account: stake_info_info.key(),
+            old_size: old_size as u64,
+            new_size: StakeInfo::SPACE as u64,
+            migrated_by: ctx.accounts.payer.key(),
             timestamp: Clock::get()?.unix_timestamp,
         });
-        
+
         Ok(())
     }
 
-    /// LST 락업 생성 함수
-    pub fn create_lockup(
-        ctx: Context<CreateLockup>,
-        amount: u64,
-        duration: i64,
-    ) -> Result<()> {
-        // 최소 락업 기간 확인 (1일)
-        require!(duration >= 86400, ForestLabError::LockupTooShort);
+    /// 구버전 에포크 보상 정보 계정을 현재 레이아웃으로 마이그레이션 (누구나 실행 가능, 결과는 결정적)
+    pub fn migrate_rewards_info(ctx: Context<MigrateRewardsInfo>) -> Result<()> {
+        let rewards_info_info = ctx.accounts.rewards_info.to_account_info();
+        let old_size = rewards_info_info.data_len();
+        let legacy: LegacyRewardsInfo =
+            load_legacy_account(&rewards_info_info, &RewardsInfo::DISCRIMINATOR, RewardsInfo::SPACE)?;
 
-        let lockup = &mut ctx.accounts.lockup;
-        let user = &ctx.accounts.user;
-        let project = &ctx.accounts.project;
+        // 에포크 보상 정보 PDA 검증
+        let expected = Pubkey::create_program_address(
+            &[
+                b"rewards_info",
+                legacy.project.as_ref(),
+                &legacy.epoch.to_le_bytes(),
+                &[legacy.bump],
+            ],
+            ctx.program_id,
+        )
+        .map_err(|_| error!(ForestLabError::InvalidLegacyAccount))?;
+        require_keys_eq!(expected, rewards_info_info.key(), ForestLabError::InvalidLegacyAccount);
 
-        // 락업 정보 초기화
-        lockup.user = user.key();
-        lockup.project = project.key();
-        lockup.lst_mint = project.lst_mint;
-        lockup.amount = amount;
-        lockup.start_time = Clock::get()?.unix_timestamp;
-        lockup.end_time = Clock::get()?.unix_timestamp + duration;
-        lockup.is_released = false;
-        lockup.release_time = 0;
-        lockup.bonus_percentage = match duration {
-            d if d >= 365 * 86400 => 1000, // 1년 이상: 10% 보너스
-            d if d >= 180 * 86400 => 500,  // 6개월 이상: 5% 보너스
-            d if d >= 90 * 86400 => 250,   // 3개월 이상: 2.5% 보너스
-            _ => 0,                        // 기본: 보너스 없음
+        // 구버전 에포크는 스왑 및 리스테이킹 대상 금액 없음
+        let rewards_info = RewardsInfo {
+            project: legacy.project,
+            epoch: legacy.epoch,
+            total_rewards: legacy.total_rewards,
+            platform_fee: legacy.platform_fee,
+            project_rewards: legacy.project_rewards,
+            swap_amount: 0,
+            processed: legacy.processed,
+            swapped_amount: legacy.swapped_amount,
+            project_fee: legacy.project_fee,
+            project_amount: legacy.project_amount,
+            restake_amount: 0,
+            restaked: false,
+            timestamp: legacy.timestamp,
+            bump: legacy.bump,
         };
-        lockup.bump = ctx.bumps.lockup;
 
-        // 사용자의 LST 토큰을 락업 볼트로 전송
-        token::transfer(
-            CpiContext::new(
-                ctx.accounts.token_program.to_account_info(),
-                token::Transfer {
-                    from: ctx.accounts.user_token_account.to_account_info(),
-                    to: ctx.accounts.lockup_vault.to_account_info(),
-                    authority: user.to_account_info(),
-                },
-            ),
-            amount,
+        rewrite_migrated_account(
+            &rewards_info_info,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            RewardsInfo::SPACE,
+            &rewards_info,
+        )?;
+
+        // 계정 마이그레이션 이벤트 발행
+        emit!(AccountMigratedEvent {
+            account: rewards_info_info.key(),
+            old_size: old_size as u64,
+            new_size: RewardsInfo::SPACE as u64,
+            migrated_by: ctx.accounts.payer.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// 구버전 멀티시그 설정 계정을 현재 레이아웃으로 마이그레이션 (누구나 실행 가능, 결과는 결정적)
+    pub fn migrate_multisig_config(ctx: Context<MigrateMultisigConfig>) -> Result<()> {
+        let multisig_info = ctx.accounts.multisig_config.to_account_info();
+        let old_size = multisig_info.data_len();
+        let legacy: LegacyMultisigConfig =
+            load_legacy_account(&multisig_info, &MultisigConfig::DISCRIMINATOR, MultisigConfig::SPACE)?;
+
+        // 멀티시그 설정 PDA 검증
+        let expected = Pubkey::create_program_address(
+            &[b"multisig_config", legacy.project.as_ref(), &[legacy.bump]],
+            ctx.program_id,
+        )
+        .map_err(|_| error!(ForestLabError::InvalidLegacyAccount))?;
+        require_keys_eq!(expected, multisig_info.key(), ForestLabError::InvalidLegacyAccount);
+
+        let multisig_config = MultisigConfig {
+            project: legacy.project,
+            signers: legacy.signers,
+            threshold: legacy.threshold,
+            is_active: legacy.is_active,
+            proposal_count: 0,
+            bump: legacy.bump,
+        };
+
+        rewrite_migrated_account(
+            &multisig_info,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            MultisigConfig::SPACE,
+            &multisig_config,
         )?;
 
-        // 락업 생성 이벤트 발행
-        emit!(LockupCreatedEvent {
-            lockup: lockup.key(),
-            user: user.key(),
-            project: project.key(),
-            amount,
-            start_time: lockup.start_time,
-            end_time: lockup.end_time,
-            bonus_percentage: lockup.bonus_percentage,
+        // 계정 마이그레이션 이벤트 발행
+        emit!(AccountMigratedEvent {
+            account: multisig_info.key(),
+            old_size: old_size as u64,
+            new_size: MultisigConfig::SPACE as u64,
+            migrated_by: ctx.accounts.payer.key(),
+            timestamp: Clock::get()?.unix_timestamp,
         });
 
         Ok(())
     }
 
-    /// 락업 해제 함수
-    pub fn release_lockup(
-        ctx: Context<ReleaseLockup>,
-    ) -> Result<()> {
-        let lockup = &mut ctx.accounts.lockup;
+    /// 구버전 락업 해제 함수 (인덱스 도입 이전 시드의 락업, 원금 반환 후 계정 정리)
+    pub fn release_legacy_lockup(ctx: Context<ReleaseLegacyLockup>) -> Result<()> {
+        // 락업 작업이 일시 중지되었는지 확인
+        ctx.accounts.platform.require_not_paused(Platform::PAUSE_LOCKUP)?;
+
+        let lockup_info = ctx.accounts.lockup.to_account_info();
+        let legacy: LegacyLockup =
+            load_legacy_account(&lockup_info, &Lockup::DISCRIMINATOR, Lockup::SPACE)?;
         let user = &ctx.accounts.user;
-        let lockup_vault = &ctx.accounts.lockup_vault;
 
-        // 락업이 이미 해제되었는지 확인
-        require!(!lockup.is_released, ForestLabError::AlreadyReleased);
+        require_keys_eq!(legacy.user, user.key(), ForestLabError::Unauthorized);
+        require_keys_eq!(legacy.project, ctx.accounts.project.key(), ForestLabError::InvalidLegacyAccount);
+        require_keys_eq!(legacy.lst_mint, ctx.accounts.lst_mint.key(), ForestLabError::InvalidLegacyAccount);
+        require!(!legacy.is_released, ForestLabError::AlreadyReleased);
 
         // 락업 기간이 끝났는지 확인
         let current_time = Clock::get()?.unix_timestamp;
-        require!(
-            current_time >= lockup.end_time,
-            ForestLabError::LockupNotExpired
-        );
+        require!(current_time >= legacy.end_time, ForestLabError::LockupNotExpired);
 
-        // 락업된 LST를 사용자에게 반환
+        let user_key = user.key();
+        let project_key = ctx.accounts.project.key();
         let seeds = &[
             b"lockup".as_ref(),
-            lockup.user.as_ref(),
-            lockup.project.as_ref(),
-            &[lockup.bump],
+            user_key.as_ref(),
+            project_key.as_ref(),
+            &[ctx.bumps.lockup],
         ];
         let signer = &[&seeds[..]];
 
+        // 볼트 잔액 전체를 사용자에게 반환 (구버전 락업은 보너스 없음)
         token::transfer(
             CpiContext::new_with_signer(
                 ctx.accounts.token_program.to_account_info(),
                 token::Transfer {
-                    from: lockup_vault.to_account_info(),
+                    from: ctx.accounts.lockup_vault.to_account_info(),
                     to: ctx.accounts.user_token_account.to_account_info(),
-                    authority: lockup.to_account_info(),
+                    authority: lockup_info.clone(),
                 },
                 signer,
             ),
-            lockup.amount,
+            ctx.accounts.lockup_vault.amount,
         )?;
 
-        // 락업 정보 업데이트
-        lockup.is_released = true;
-        lockup.release_time = current_time;
+        // 락업 볼트와 락업 계정을 닫고 렌트를 사용자에게 반환
+        token::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::CloseAccount {
+                account: ctx.accounts.lockup_vault.to_account_info(),
+                destination: user.to_account_info(),
+                authority: lockup_info.clone(),
+            },
+            signer,
+        ))?;
+        let user_info = user.to_account_info();
+        let user_lamports = user_info.lamports().saturating_add(lockup_info.lamports());
+        **user_info.try_borrow_mut_lamports()? = user_lamports;
+        **lockup_info.try_borrow_mut_lamports()? = 0;
+        lockup_info.assign(&System::id());
+        lockup_info.realloc(0, false)?;
 
         // 락업 해제 이벤트 발행
         emit!(LockupReleasedEvent {
-            lockup: lockup.key(),
-            user: user.key(),
-            project: lockup.project,
-            amount: lockup.amount,
+            lockup: lockup_info.key(),
+            user: user_key,
+            project: project_key,
+            index: Lockup::LEGACY_INDEX,
+            amount: legacy.amount,
+            bonus_amount: 0,
             release_time: current_time,
         });
 
         Ok(())
     }
+}
 
-    /// 크랭크 업데이트 실행 함수
-    pub fn execute_crank_update(
-        ctx: Context<ExecuteCrankUpdate>,
-        epoch: u64,
-    ) -> Result<()> {
-        let crank_info = &mut ctx.accounts.crank_info;
-        let project = &ctx.accounts.project;
-        let authority = &ctx.accounts.authority;
-        let platform = &ctx.accounts.platform;
-        
-        // 권한 확인 (플랫폼 관리자 또는 허가된 크랭커)
-        let is_admin = platform.authority == authority.key() || platform.admin_wallet == authority.key();
-        require!(
-            is_admin || crank_info.authorized_crankers.contains(&authority.key()),
-            ForestLabError::Unauthorized
-        );
-        
-        // 크랭크 정보 업데이트
-        crank_info.last_executed_epoch = epoch;
-        crank_info.last_execution_time = Clock::get()?.unix_timestamp;
-        crank_info.execution_count = crank_info.execution_count.saturating_add(1);
-        
-        // 이 함수는 SPL Stake Pool의 update_stake_pool 명령을 호출하는
-        // 외부 프로그램을 트리거하기 위한 이벤트를 발행함
-        
-        // 크랭크 실행 이벤트 발행
-        emit!(CrankExecutedEvent {
-            project: project.key(),
-            epoch,
-            executor: authority.key(),
-            timestamp: Clock::get()?.unix_timestamp,
-        });
-        
-        Ok(())
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    /// 플랫폼 설정 업데이트 함수
-    pub fn update_platform_settings(
-        ctx: Context<UpdatePlatformSettings>,
-        platform_fee: Option<u16>,
-        min_stake_amount: Option<u64>,
-        admin_wallet: Option<Pubkey>,
-        is_active: Option<bool>,
-    ) -> Result<()> {
-        let platform = &mut ctx.accounts.platform;
-        let authority = &ctx.accounts.authority;
+    #[test]
+    fn project_status_transitions() {
+        use ProjectStatus::*;
+        use StatusActor::*;
 
-        // 권한 확인
-        require!(
-            platform.authority == authority.key(),
-            ForestLabError::Unauthorized
-        );
+        let reason = StatusReason::Maintenance;
+        assert!(Active.check_transition(Paused, ProjectAuthority, ProjectAuthority, reason).is_ok());
+        assert!(Active.check_transition(Paused, Settlement, ProjectAuthority, reason).is_err());
+        assert!(Paused.check_transition(Active, ProjectAuthority, ProjectAuthority, reason).is_ok());
+        assert!(Paused
+            .check_transition(Active, Platform, Platform, StatusReason::SecurityIncident)
+            .is_ok());
+        assert!(Paused
+            .check_transition(Active, ProjectAuthority, ProjectAuthority, StatusReason::SecurityIncident)
+            .is_err());
+        // 플랫폼이 일시 중지한 프로젝트는 사유와 관계없이 플랫폼만 재개
+        assert!(Paused.check_transition(Active, ProjectAuthority, Platform, reason).is_err());
+        assert!(Paused.check_transition(Active, Platform, Platform, reason).is_ok());
+        assert!(Active.check_transition(Completed, Settlement, ProjectAuthority, reason).is_ok());
+        assert!(Active.check_transition(Completed, Platform, ProjectAuthority, reason).is_err());
+        assert!(Cancelled.check_transition(Active, Platform, ProjectAuthority, reason).is_ok());
+        assert!(Cancelled.check_transition(Active, ProjectAuthority, ProjectAuthority, reason).is_err());
+        assert!(Completed.check_transition(Active, Platform, Settlement, reason).is_err());
+    }
 
-        // 설정 업데이트
-        if let Some(fee) = platform_fee {
-            require!(fee <= 250, ForestLabError::InvalidFeePercentage); // 최대 2.5%
-            platform.platform_fee = fee;
-        }
+    #[test]
+    fn inactive_platform_pauses_every_operation() {
+        let mut platform = Platform {
+            is_active: true,
+            paused_operations: Platform::PAUSE_STAKE,
+            ..Default::default()
+        };
+        assert!(platform.require_not_paused(Platform::PAUSE_STAKE).is_err());
+        assert!(platform.require_not_paused(Platform::PAUSE_FEES).is_ok());
 
-        if let Some(amount) = min_stake_amount {
-            platform.min_stake_amount = amount;
+        platform.is_active = false;
+        platform.paused_operations = 0;
+        for flag in 0..8 {
+            assert!(platform.require_not_paused(1 << flag).is_err());
         }
+    }
 
-        if let Some(admin) = admin_wallet {
-            platform.admin_wallet = admin;
-        }
+    #[test]
+    fn roles_grant_and_revoke_admin_wallet_roles() {
+        let admin = Pubkey::new_unique();
+        let mut roles = Roles::default();
+        roles.grant(admin, Roles::admin_wallet_mask()).unwrap();
 
-        if let Some(active) = is_active {
-            platform.is_active = active;
-        }
+        assert!(roles.has_role(&admin, Role::RewardOperator));
+        assert!(roles.has_role(&admin, Role::ProjectModerator));
+        assert!(!roles.has_role(&admin, Role::FeeManager));
+        assert!(!roles.has_role(&admin, Role::SuperAdmin));
 
-        // 설정 업데이트 이벤트 발행
-        emit!(PlatformSettingsUpdatedEvent {
-            platform: platform.key(),
-            authority: authority.key(),
-            platform_fee: platform.platform_fee,
-            min_stake_amount: platform.min_stake_amount,
-            admin_wallet: platform.admin_wallet,
-            is_active: platform.is_active,
-            timestamp: Clock::get()?.unix_timestamp,
-        });
+        roles.revoke(&admin, Role::RewardOperator.bit());
+        assert!(!roles.has_role(&admin, Role::RewardOperator));
+        assert!(roles.has_role(&admin, Role::ProjectModerator));
 
-        Ok(())
+        roles.revoke(&admin, Roles::admin_wallet_mask());
+        assert!(roles.members.is_empty());
     }
 
-    /// 크랭커 권한 관리 함수
-    pub fn manage_crankers(
-        ctx: Context<ManageCrankers>,
-        add_crankers: Vec<Pubkey>,
-        remove_crankers: Vec<Pubkey>,
-    ) -> Result<()> {
-        let crank_info = &mut ctx.accounts.crank_info;
-        let authority = &ctx.accounts.authority;
-        let platform = &ctx.accounts.platform;
-        
-        // 권한 확인 (플랫폼 관리자만)
-        require!(
-            platform.authority == authority.key() || platform.admin_wallet == authority.key(),
-            ForestLabError::Unauthorized
+    #[test]
+    fn spl_token_swap_instruction_layout() {
+        let keys: Vec<Pubkey> = (0..7).map(|_| Pubkey::new_unique()).collect();
+        let (program, authority, source, destination, token_program) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
         );
-        
-        // 크랭커 추가
-        for cranker in add_crankers {
-            if !crank_info.authorized_crankers.contains(&cranker) {
-                crank_info.authorized_crankers.push(cranker);
-            }
+
+        let ix = SplTokenSwapAdapter::swap_instruction(
+            &program,
+            &authority,
+            &source,
+            &destination,
+            &token_program,
+            &keys[..6],
+            1_000,
+            950,
+        )
+        .unwrap();
+        assert_eq!(ix.program_id, program);
+        assert_eq!(ix.data[0], 1);
+        assert_eq!(ix.data[1..9], 1_000u64.to_le_bytes());
+        assert_eq!(ix.data[9..17], 950u64.to_le_bytes());
+
+        let order: Vec<Pubkey> = ix.accounts.iter().map(|meta| meta.pubkey).collect();
+        assert_eq!(
+            order,
+            vec![
+                keys[0], keys[1], authority, source, keys[2], keys[3], destination, keys[4], keys[5],
+                token_program,
+            ]
+        );
+        assert!(ix.accounts[2].is_signer);
+        assert_eq!(ix.accounts.iter().filter(|meta| meta.is_signer).count(), 1);
+        assert!(!ix.accounts[0].is_writable && !ix.accounts[1].is_writable);
+        assert!(ix.accounts[3..9].iter().all(|meta| meta.is_writable));
+
+        // 호스트 수수료 계정은 마지막에 추가
+        let ix = SplTokenSwapAdapter::swap_instruction(
+            &program, &authority, &source, &destination, &token_program, &keys, 1_000, 950,
+        )
+        .unwrap();
+        assert_eq!(ix.accounts.last().unwrap().pubkey, keys[6]);
+
+        assert!(SplTokenSwapAdapter::swap_instruction(
+            &program, &authority, &source, &destination, &token_program, &keys[..5], 1_000, 950,
+        )
+        .is_err());
+    }
+
+    fn summary(epoch: u64) -> EpochRewardSummary {
+        EpochRewardSummary {
+            epoch,
+            total_rewards: epoch * 10,
+            ..Default::default()
         }
-        
-        // 크랭커 제거
-        for cranker in remove_crankers {
-            crank_info.authorized_crankers.retain(|&x| x != cranker);
+    }
+
+    #[test]
+    fn rewards_history_record_wraps_oldest_entry() {
+        let mut history = RewardsHistory::default();
+        for epoch in 0..RewardsHistory::MAX_ENTRIES as u64 {
+            history.record(summary(epoch));
         }
-        
-        // 크랭커 관리 이벤트 발행
-        emit!(CrankersUpdatedEvent {
-            platform: platform.key(),
-            authorized_crankers: crank_info.authorized_crankers.clone(),
-            timestamp: Clock::get()?.unix_timestamp,
-        });
-        
-        Ok(())
+        assert_eq!(history.entries.len(), RewardsHistory::MAX_ENTRIES);
+        assert_eq!(history.head, 0);
+
+        // 가득 찬 경우 가장 오래된 항목(에포크 0)을 덮어씀
+        history.record(summary(32));
+        assert_eq!(history.entries.len(), RewardsHistory::MAX_ENTRIES);
+        assert_eq!(history.entries[0].epoch, 32);
+        assert_eq!(history.entries[1].epoch, 1);
+        assert_eq!(history.head, 1);
+        assert_eq!(history.last_epoch, 32);
+        assert_eq!(history.total_epochs, 33);
     }
-    
-    /// 리스테이킹 설정 함수
-    pub fn setup_restaking(
-        ctx: Context<SetupRestaking>,
-        target_lst_mint: Pubkey,
-        restake_percentage: u16,
-    ) -> Result<()> {
-        let restake_config = &mut ctx.accounts.restake_config;
-        let project = &ctx.accounts.project;
-        let authority = &ctx.accounts.authority;
-        
-        // 권한 확인 (프로젝트 생성자만)
-        require!(
-            project.creator == authority.key(),
-            ForestLabError::Unauthorized
-        );
-        
-        // 리스테이킹 비율 유효성 검증 (최대 100%)
-        require!(
-            restake_percentage <= 10000,
-            ForestLabError::InvalidPercentage
-        );
-        
-        // 리스테이킹 설정 초기화 또는 업데이트
-        restake_config.project = project.key();
-        restake_config.source_lst_mint = project.lst_mint;
-        restake_config.target_lst_mint = target_lst_mint;
-        restake_config.restake_percentage = restake_percentage;
-        restake_config.is_active = true;
-        restake_config.bump = ctx.bumps.restake_config;
-        
-        // 리스테이킹 설정 이벤트 발행
-        emit!(RestakingConfiguredEvent {
-            project: project.key(),
-            source_lst_mint: project.lst_mint,
-            target_lst_mint,
-            restake_percentage,
-            timestamp: Clock::get()?.unix_timestamp,
-        });
-        
-        Ok(())
+
+    #[test]
+    fn stake_info_rewards_follow_reward_per_share() {
+        let mut project = Project {
+            total_staked_lst: 1_000,
+            ..Default::default()
+        };
+        let mut alice = StakeInfo {
+            current_lst_amount: 750,
+            ..Default::default()
+        };
+        let mut bob = StakeInfo {
+            current_lst_amount: 250,
+            ..Default::default()
+        };
+
+        assert_eq!(project.distribute_rewards(400), 400);
+        assert_eq!(alice.claimable_rewards(project.acc_reward_per_share), 300);
+        assert_eq!(bob.claimable_rewards(project.acc_reward_per_share), 100);
+
+        // 수량 변경 전 정산하면 이후 분배분만 새 수량 기준으로 누적
+        alice.settle_rewards(project.acc_reward_per_share);
+        alice.current_lst_amount = 1_750;
+        alice.reset_reward_debt(project.acc_reward_per_share);
+        project.total_staked_lst = 2_000;
+
+        project.distribute_rewards(200);
+        assert_eq!(alice.claimable_rewards(project.acc_reward_per_share), 300 + 175);
+        assert_eq!(bob.claimable_rewards(project.acc_reward_per_share), 100 + 25);
+
+        bob.settle_rewards(project.acc_reward_per_share);
+        assert_eq!(bob.pending_rewards, 125);
+        assert_eq!(bob.claimable_rewards(project.acc_reward_per_share), 125);
     }
 
-    /// 멀티시그 프로젝트 관리 설정 함수
-    pub fn setup_multisig_management(
-        ctx: Context<SetupMultisigManagement>,
-        signers: Vec<Pubkey>,
-        threshold: u8,
-    ) -> Result<()> {
-        let multisig_config = &mut ctx.accounts.multisig_config;
-        let project = &ctx.accounts.project;
-        let authority = &ctx.accounts.authority;
-        
-        // 권한 확인 (프로젝트 생성자만)
-        require!(
-            project.creator == authority.key(),
-            ForestLabError::Unauthorized
-        );
-        
-        // 서명자 수 유효성 검증
-        require!(!signers.is_empty(), ForestLabError::InvalidSignerCount);
-        require!(signers.len() <= 10, ForestLabError::TooManySigners);
-        
-        // 임계값 유효성 검증
-        require!(threshold > 0, ForestLabError::InvalidThreshold);
-        require!(
-            threshold as usize <= signers.len(),
-            ForestLabError::ThresholdTooHigh
-        );
-        
-        // 멀티시그 설정 초기화
-        multisig_config.project = project.key();
-        multisig_config.signers = signers.clone();
-        multisig_config.threshold = threshold;
-        multisig_config.is_active = true;
-        multisig_config.bump = ctx.bumps.multisig_config;
-        
-        // 멀티시그 설정 이벤트 발행
-        emit!(MultisigConfiguredEvent {
-            project: project.key(),
-            signers: signers.clone(),
-            threshold,
-            timestamp: Clock::get()?.unix_timestamp,
-        });
-        
-        Ok(())
+    #[test]
+    fn distribute_rewards_without_stakers_is_noop() {
+        let mut project = Project::default();
+        assert_eq!(project.distribute_rewards(100), 0);
+        assert_eq!(project.acc_reward_per_share, 0);
+    }
+
+    #[test]
+    fn compute_bonus_is_annualized() {
+        let year = Lockup::SECONDS_PER_YEAR as u128;
+        assert_eq!(Lockup::compute_bonus(1_000 * year, 1000), 100);
+        assert_eq!(Lockup::compute_bonus(1_000 * year / 2, 1000), 50);
+        assert_eq!(Lockup::compute_bonus(1_000 * year, 0), 0);
+    }
+
+    #[test]
+    fn lockup_tier_config_validate() {
+        let tier = |days: i64, bonus_bps: u16| LockupTierParams {
+            min_duration: days * 86400,
+            bonus_bps,
+            max_capacity: 0,
+        };
+        let max_duration = LockupTierConfig::DEFAULT_MAX_DURATION;
+
+        assert!(LockupTierConfig::validate(&[tier(90, 250), tier(180, 500)], max_duration, 5000).is_ok());
+        // 최소 기간 오름차순이 아님
+        assert!(LockupTierConfig::validate(&[tier(180, 500), tier(90, 250)], max_duration, 5000).is_err());
+        // 1일 미만 티어
+        assert!(LockupTierConfig::validate(&[tier(0, 250)], max_duration, 5000).is_err());
+        // 최대 기간 초과 티어
+        assert!(LockupTierConfig::validate(&[tier(90, 250)], 30 * 86400, 5000).is_err());
+        // 보너스 및 페널티 비율 상한
+        assert!(LockupTierConfig::validate(&[tier(90, 10001)], max_duration, 5000).is_err());
+        assert!(LockupTierConfig::validate(&[tier(90, 250)], max_duration, 10001).is_err());
+        // 티어 개수 상한
+        let too_many: Vec<_> = (1..=LockupTierConfig::MAX_TIERS as i64 + 1).map(|days| tier(days, 100)).collect();
+        assert!(LockupTierConfig::validate(&too_many, max_duration, 5000).is_err());
+    }
+
+    #[test]
+    fn lockup_tier_config_select_and_reserve() {
+        let mut config = LockupTierConfig {
+            tiers: LockupTierConfig::default_tiers(),
+            ..Default::default()
+        };
+        assert_eq!(config.select_tier(89 * 86400), None);
+        assert_eq!(config.select_tier(90 * 86400), Some(0));
+        assert_eq!(config.select_tier(200 * 86400), Some(1));
+        assert_eq!(config.select_tier(400 * 86400), Some(2));
+
+        config.tiers[0].max_capacity = 100;
+        assert!(config.reserve(0, 60).is_ok());
+        assert!(config.reserve(0, 50).is_err());
+        config.release(90 * 86400, 60);
+        assert_eq!(config.tiers[0].used_capacity, 0);
+    }
+
+    #[test]
+    fn early_exit_penalty_scales_with_remaining_time() {
+        let lockup = Lockup {
+            amount: 1_000,
+            start_time: 0,
+            end_time: 100,
+            ..Default::default()
+        };
+        assert_eq!(lockup.early_exit_penalty(0, 5000), 500);
+        assert_eq!(lockup.early_exit_penalty(50, 5000), 250);
+        assert_eq!(lockup.early_exit_penalty(100, 5000), 0);
+        assert_eq!(lockup.early_exit_penalty(-10, 5000), 500);
+    }
+
+    #[test]
+    fn voting_power_decays_linearly() {
+        let max = VotingPower::MAX_LOCK_TIME;
+        let voting_power = VotingPower {
+            locks: vec![
+                LockPoint {
+                    index: 0,
+                    amount: 1_000,
+                    start_time: 0,
+                    end_time: max,
+                    updated_at: 0,
+                },
+                LockPoint {
+                    index: 1,
+                    amount: 400,
+                    start_time: max / 2,
+                    end_time: max,
+                    updated_at: max / 2,
+                },
+            ],
+            ..Default::default()
+        };
+        assert_eq!(voting_power.power_at(-1), 0);
+        assert_eq!(voting_power.power_at(0), 1_000);
+        assert_eq!(voting_power.power_at(max / 2), 500 + 200);
+        assert_eq!(voting_power.power_at(max), 0);
+    }
+
+    #[test]
+    fn stake_amount_is_read_as_of_snapshot() {
+        let mut stake_info = StakeInfo::default();
+        stake_info.record_stake_change(10);
+        stake_info.current_lst_amount = 500;
+        stake_info.record_stake_change(30);
+        stake_info.current_lst_amount = 800;
+
+        // 스냅샷 이후 변경이 있어도 스냅샷 시점 수량을 사용
+        assert_eq!(stake_info.lst_amount_at(5), Some(0));
+        assert_eq!(stake_info.lst_amount_at(20), Some(500));
+        assert_eq!(stake_info.lst_amount_at(30), Some(800));
+
+        // 기록이 밀려난 시점은 조회 불가
+        for timestamp in 40..40 + StakeInfo::MAX_STAKE_HISTORY as i64 {
+            stake_info.record_stake_change(timestamp);
+        }
+        assert_eq!(stake_info.lst_amount_at(20), None);
+        assert_eq!(stake_info.lst_amount_at(45), Some(800));
+    }
+
+    #[test]
+    fn voting_power_is_read_as_of_snapshot() {
+        let max = VotingPower::MAX_LOCK_TIME;
+        let mut voting_power = VotingPower::default();
+        let mut lockup = Lockup {
+            index: 0,
+            amount: 1_000,
+            start_time: 0,
+            end_time: max / 2,
+            ..Default::default()
+        };
+        voting_power.sync_lockup(&lockup, 0).unwrap();
+
+        // 스냅샷 이후 연장 및 추가 예치
+        lockup.amount = 2_000;
+        lockup.end_time = max;
+        voting_power.sync_lockup(&lockup, 100).unwrap();
+        assert_eq!(voting_power.snapshot_power_at(0), Some(500));
+        assert_eq!(voting_power.snapshot_power_at(100), Some(voting_power.power_at(100)));
+        assert!(voting_power.power_at(100) > 1_900);
+
+        // 스냅샷 이후 해제
+        lockup.is_released = true;
+        voting_power.sync_lockup(&lockup, 200).unwrap();
+        assert!(voting_power.locks.is_empty());
+        assert_eq!(voting_power.snapshot_power_at(0), Some(500));
+        assert_eq!(voting_power.power_at(200), 0);
     }
-}
\ No newline at end of file
+}
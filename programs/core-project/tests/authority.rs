@@ -0,0 +1,267 @@
+mod common;
+
+use anchor_lang::{prelude::*, solana_program::system_program};
+use common::*;
+use core_project::{ForestLabError, Platform, Project};
+
+fn propose_platform_authority(
+    fixture: &mut Fixture,
+    authority: Pubkey,
+    new_authority: Pubkey,
+) -> std::result::Result<(), ProgramError> {
+    fixture.env.call(
+        core_project::accounts::ProposePlatformAuthority {
+            platform: fixture.platform,
+            authority,
+        },
+        core_project::instruction::ProposePlatformAuthority { new_authority },
+        &[authority],
+    )
+}
+
+fn accept_platform_authority(fixture: &mut Fixture, new_authority: Pubkey) -> std::result::Result<(), ProgramError> {
+    fixture.env.call(
+        core_project::accounts::AcceptPlatformAuthority {
+            platform: fixture.platform,
+            new_authority,
+        },
+        core_project::instruction::AcceptPlatformAuthority {},
+        &[new_authority],
+    )
+}
+
+fn cancel_platform_authority_transfer(fixture: &mut Fixture, authority: Pubkey) -> std::result::Result<(), ProgramError> {
+    fixture.env.call(
+        core_project::accounts::CancelPlatformAuthorityTransfer {
+            platform: fixture.platform,
+            authority,
+        },
+        core_project::instruction::CancelPlatformAuthorityTransfer {},
+        &[authority],
+    )
+}
+
+#[test]
+fn platform_authority_moves_only_after_new_authority_accepts() {
+    let mut fixture = Fixture::new();
+    let (old_authority, new_authority) = (fixture.authority, fixture.env.new_wallet(LAMPORTS_PER_SOL));
+
+    // 제안만으로는 관리자가 바뀌지 않음
+    propose_platform_authority(&mut fixture, old_authority, new_authority).unwrap();
+    let platform: Platform = fixture.env.fetch(&fixture.platform);
+    assert_eq!(platform.authority, old_authority);
+    assert_eq!(platform.pending_authority, Some(new_authority));
+
+    accept_platform_authority(&mut fixture, new_authority).unwrap();
+    let platform: Platform = fixture.env.fetch(&fixture.platform);
+    assert_eq!(platform.authority, new_authority);
+    assert_eq!(platform.pending_authority, None);
+
+    // 이전 관리자는 더 이상 관리자 권한이 없고 새 관리자는 권한을 행사함
+    let result = propose_platform_authority(&mut fixture, old_authority, old_authority);
+    assert_error(result, ForestLabError::Unauthorized);
+    propose_platform_authority(&mut fixture, new_authority, old_authority).unwrap();
+}
+
+#[test]
+fn only_pending_authority_can_accept_platform_transfer() {
+    let mut fixture = Fixture::new();
+    let authority = fixture.authority;
+    let new_authority = fixture.env.new_wallet(LAMPORTS_PER_SOL);
+    let outsider = fixture.env.new_wallet(LAMPORTS_PER_SOL);
+
+    // 대기 중인 제안이 없으면 누구도 수락할 수 없음
+    let result = accept_platform_authority(&mut fixture, new_authority);
+    assert_error(result, ForestLabError::Unauthorized);
+
+    propose_platform_authority(&mut fixture, authority, new_authority).unwrap();
+    let result = accept_platform_authority(&mut fixture, outsider);
+    assert_error(result, ForestLabError::Unauthorized);
+    let result = accept_platform_authority(&mut fixture, authority);
+    assert_error(result, ForestLabError::Unauthorized);
+
+    let platform: Platform = fixture.env.fetch(&fixture.platform);
+    assert_eq!(platform.authority, authority);
+    assert_eq!(platform.pending_authority, Some(new_authority));
+}
+
+#[test]
+fn only_platform_authority_can_propose_or_cancel_transfer() {
+    let mut fixture = Fixture::new();
+    let authority = fixture.authority;
+    let new_authority = fixture.env.new_wallet(LAMPORTS_PER_SOL);
+    let outsider = fixture.env.new_wallet(LAMPORTS_PER_SOL);
+
+    let result = propose_platform_authority(&mut fixture, outsider, outsider);
+    assert_error(result, ForestLabError::Unauthorized);
+
+    propose_platform_authority(&mut fixture, authority, new_authority).unwrap();
+    let result = cancel_platform_authority_transfer(&mut fixture, outsider);
+    assert_error(result, ForestLabError::Unauthorized);
+
+    // 취소 후에는 기존 제안 대상이 수락할 수 없음
+    cancel_platform_authority_transfer(&mut fixture, authority).unwrap();
+    let platform: Platform = fixture.env.fetch(&fixture.platform);
+    assert_eq!(platform.pending_authority, None);
+    let result = accept_platform_authority(&mut fixture, new_authority);
+    assert_error(result, ForestLabError::Unauthorized);
+
+    let result = cancel_platform_authority_transfer(&mut fixture, authority);
+    assert_error(result, ForestLabError::NoPendingAuthority);
+}
+
+/// 관리자가 생성자인 프로젝트
+fn project_setup() -> (Fixture, Pubkey, Pubkey) {
+    let mut fixture = Fixture::new();
+    let lst_mint = fixture.env.create_mint(&Pubkey::new_unique(), 9);
+    let creator = fixture.env.new_wallet(10 * LAMPORTS_PER_SOL);
+    let project = fixture.register_project(&creator, "forest", 100 * LAMPORTS_PER_SOL, &lst_mint);
+    (fixture, project, creator)
+}
+
+fn transfer_project_authority(
+    fixture: &mut Fixture,
+    project: Pubkey,
+    authority: Pubkey,
+    new_authority: Pubkey,
+) -> std::result::Result<(), ProgramError> {
+    fixture.env.call(
+        core_project::accounts::TransferProjectAuthority {
+            project,
+            platform: fixture.platform,
+            authority,
+            multisig_config: pda(&[b"multisig_config", project.as_ref()]),
+        },
+        core_project::instruction::TransferProjectAuthority { new_authority },
+        &[authority],
+    )
+}
+
+fn accept_project_authority(
+    fixture: &mut Fixture,
+    project: Pubkey,
+    new_authority: Pubkey,
+) -> std::result::Result<(), ProgramError> {
+    fixture.env.call(
+        core_project::accounts::AcceptProjectAuthority {
+            project,
+            platform: fixture.platform,
+            new_authority,
+        },
+        core_project::instruction::AcceptProjectAuthority {},
+        &[new_authority],
+    )
+}
+
+fn cancel_project_authority_transfer(
+    fixture: &mut Fixture,
+    project: Pubkey,
+    authority: Pubkey,
+) -> std::result::Result<(), ProgramError> {
+    fixture.env.call(
+        core_project::accounts::CancelProjectAuthorityTransfer { project, authority },
+        core_project::instruction::CancelProjectAuthorityTransfer {},
+        &[authority],
+    )
+}
+
+fn update_project_fee(
+    fixture: &mut Fixture,
+    project: Pubkey,
+    authority: Pubkey,
+    manager_fee_percentage: u16,
+) -> std::result::Result<(), ProgramError> {
+    fixture.env.call(
+        core_project::accounts::UpdateProjectSettings {
+            project,
+            platform: fixture.platform,
+            authority,
+            multisig_config: pda(&[b"multisig_config", project.as_ref()]),
+            system_program: system_program::ID,
+        },
+        core_project::instruction::UpdateProjectFee {
+            manager_fee_percentage,
+            payout_wallet: None,
+        },
+        &[authority],
+    )
+}
+
+#[test]
+fn project_authority_moves_only_after_new_authority_accepts() {
+    let (mut fixture, project, creator) = project_setup();
+    let new_authority = fixture.env.new_wallet(LAMPORTS_PER_SOL);
+
+    // 제안만으로는 관리자가 바뀌지 않음
+    transfer_project_authority(&mut fixture, project, creator, new_authority).unwrap();
+    let result = update_project_fee(&mut fixture, project, new_authority, 500);
+    assert_error(result, anchor_lang::error::ErrorCode::ConstraintRaw);
+
+    accept_project_authority(&mut fixture, project, new_authority).unwrap();
+    let data: Project = fixture.env.fetch(&project);
+    assert_eq!(data.authority, new_authority);
+    assert_eq!(data.pending_authority, None);
+    assert_eq!(data.creator, creator);
+
+    // 새 관리자만 설정을 변경할 수 있음
+    update_project_fee(&mut fixture, project, new_authority, 500).unwrap();
+    let result = update_project_fee(&mut fixture, project, creator, 300);
+    assert_error(result, anchor_lang::error::ErrorCode::ConstraintRaw);
+    let result = transfer_project_authority(&mut fixture, project, creator, creator);
+    assert_error(result, ForestLabError::Unauthorized);
+    let data: Project = fixture.env.fetch(&project);
+    assert_eq!(data.manager_fee_percentage, 500);
+}
+
+#[test]
+fn only_pending_authority_can_accept_project_transfer() {
+    let (mut fixture, project, creator) = project_setup();
+    let new_authority = fixture.env.new_wallet(LAMPORTS_PER_SOL);
+    let outsider = fixture.env.new_wallet(LAMPORTS_PER_SOL);
+
+    transfer_project_authority(&mut fixture, project, creator, new_authority).unwrap();
+    let result = accept_project_authority(&mut fixture, project, outsider);
+    assert_error(result, ForestLabError::Unauthorized);
+    let result = accept_project_authority(&mut fixture, project, creator);
+    assert_error(result, ForestLabError::Unauthorized);
+
+    let data: Project = fixture.env.fetch(&project);
+    assert_eq!(data.authority, creator);
+    assert_eq!(data.pending_authority, Some(new_authority));
+}
+
+#[test]
+fn only_project_authority_can_propose_or_cancel_transfer() {
+    let (mut fixture, project, creator) = project_setup();
+    let new_authority = fixture.env.new_wallet(LAMPORTS_PER_SOL);
+    let outsider = fixture.env.new_wallet(LAMPORTS_PER_SOL);
+
+    let result = transfer_project_authority(&mut fixture, project, outsider, outsider);
+    assert_error(result, ForestLabError::Unauthorized);
+
+    transfer_project_authority(&mut fixture, project, creator, new_authority).unwrap();
+    let result = cancel_project_authority_transfer(&mut fixture, project, outsider);
+    assert_error(result, ForestLabError::Unauthorized);
+
+    // 취소 후에는 기존 제안 대상이 수락할 수 없음
+    cancel_project_authority_transfer(&mut fixture, project, creator).unwrap();
+    let result = accept_project_authority(&mut fixture, project, new_authority);
+    assert_error(result, ForestLabError::Unauthorized);
+    let result = cancel_project_authority_transfer(&mut fixture, project, creator);
+    assert_error(result, ForestLabError::NoPendingAuthority);
+}
+
+#[test]
+fn active_multisig_blocks_direct_project_transfer() {
+    let (mut fixture, project, creator) = project_setup();
+    let signers = [
+        fixture.env.new_wallet(LAMPORTS_PER_SOL),
+        fixture.env.new_wallet(LAMPORTS_PER_SOL),
+    ];
+    fixture.setup_multisig(&project, &creator, &signers, 2).unwrap();
+
+    let result = transfer_project_authority(&mut fixture, project, creator, signers[0]);
+    assert_error(result, ForestLabError::MultisigRequired);
+    let data: Project = fixture.env.fetch(&project);
+    assert_eq!(data.pending_authority, None);
+}
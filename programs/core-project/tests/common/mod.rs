@@ -0,0 +1,1211 @@
+//! 네이티브 테스트 하네스
+//!
+//! solana-program-test 없이 프로그램을 실행하기 위한 최소 런타임입니다.
+//! - 계정은 BPF 입력 형식으로 직렬화해 `entrypoint::deserialize`로 복원 (realloc 여유 공간 포함)
+//! - CPI는 `SyscallStubs`에서 가로채 시스템 프로그램, SPL Token, ATA, core-project 및 테스트용 대역 프로그램으로 전달
+//! - 스테이크 풀과 스왑 풀은 SPL 명령어 레이아웃을 따르는 테스트용 대역 프로그램
+#![allow(dead_code)]
+
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    sync::Once,
+};
+
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        entrypoint::{deserialize, ProgramResult, BPF_ALIGN_OF_U128, MAX_PERMITTED_DATA_INCREASE, SUCCESS},
+        instruction::Instruction,
+        program::{invoke, invoke_signed},
+        program_error::ProgramError,
+        program_option::COption,
+        program_pack::Pack,
+        program_stubs::{set_syscall_stubs, SyscallStubs},
+        program_utils::limited_deserialize,
+        system_instruction::SystemInstruction,
+        system_program, sysvar,
+    },
+    AccountDeserialize, InstructionData, ToAccountMetas,
+};
+use anchor_spl::{
+    associated_token::{get_associated_token_address, spl_associated_token_account},
+    token::spl_token,
+};
+use core_project::SPL_STAKE_POOL_PROGRAM_ID;
+
+/// 테스트용 상수 곱 스왑 프로그램 ID
+pub const SWAP_PROGRAM_ID: Pubkey = Pubkey::new_from_array([7; 32]);
+/// 스왑 슬리피지 초과 오류 (SPL Token Swap SwapError::ExceededSlippage)
+pub const SWAP_EXCEEDED_SLIPPAGE: u32 = 16;
+/// 테스트 시작 시간 (Unix timestamp)
+pub const START_TIME: i64 = 1_700_000_000;
+pub const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
+
+type ProcessFn = fn(&Pubkey, &[AccountInfo], &[u8]) -> ProgramResult;
+
+thread_local! {
+    static CLOCK: RefCell<Clock> = RefCell::new(Clock::default());
+    static PROGRAM_STACK: RefCell<Vec<Pubkey>> = const { RefCell::new(Vec::new()) };
+    static RETURN_DATA: RefCell<Option<(Pubkey, Vec<u8>)>> = const { RefCell::new(None) };
+    static LOGS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+struct Stubs;
+
+impl SyscallStubs for Stubs {
+    fn sol_log(&self, message: &str) {
+        LOGS.with(|logs| logs.borrow_mut().push(message.to_string()));
+    }
+
+    fn sol_log_data(&self, _fields: &[&[u8]]) {}
+
+    fn sol_invoke_signed(
+        &self,
+        instruction: &Instruction,
+        account_infos: &[AccountInfo],
+        signers_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        cross_program_invoke(instruction, account_infos, signers_seeds)
+    }
+
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        let clock = CLOCK.with(|clock| clock.borrow().clone());
+        unsafe { *(var_addr as *mut Clock) = clock };
+        SUCCESS
+    }
+
+    fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+        unsafe { *(var_addr as *mut Rent) = Rent::default() };
+        SUCCESS
+    }
+
+    fn sol_get_return_data(&self) -> Option<(Pubkey, Vec<u8>)> {
+        RETURN_DATA.with(|data| data.borrow().clone())
+    }
+
+    fn sol_set_return_data(&self, data: &[u8]) {
+        let program_id = current_program();
+        RETURN_DATA.with(|slot| *slot.borrow_mut() = Some((program_id, data.to_vec())));
+    }
+
+    fn sol_get_stack_height(&self) -> u64 {
+        PROGRAM_STACK.with(|stack| stack.borrow().len() as u64)
+    }
+}
+
+fn current_program() -> Pubkey {
+    PROGRAM_STACK.with(|stack| *stack.borrow().last().expect("no program is executing"))
+}
+
+/// 프로그램 ID별 처리 함수
+fn program_processor(program_id: &Pubkey) -> Option<ProcessFn> {
+    if *program_id == system_program::ID {
+        Some(process_system)
+    } else if *program_id == spl_token::ID {
+        Some(spl_token::processor::Processor::process)
+    } else if *program_id == spl_associated_token_account::ID {
+        Some(spl_associated_token_account::processor::process_instruction)
+    } else if *program_id == core_project::ID {
+        Some(process_core_project)
+    } else if *program_id == SPL_STAKE_POOL_PROGRAM_ID {
+        Some(stake_pool::process)
+    } else if *program_id == SWAP_PROGRAM_ID {
+        Some(constant_product::process)
+    } else {
+        None
+    }
+}
+
+/// core-project 진입점 (계정 슬라이스 수명이 'info와 같아야 하므로 복사본을 고정)
+fn process_core_project(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let accounts: &[AccountInfo] = Box::leak(accounts.to_vec().into_boxed_slice());
+    core_project::entry(program_id, accounts, data)
+}
+
+fn dispatch(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let processor = program_processor(program_id).ok_or(ProgramError::IncorrectProgramId)?;
+    PROGRAM_STACK.with(|stack| stack.borrow_mut().push(*program_id));
+    let result = processor(program_id, accounts, data);
+    PROGRAM_STACK.with(|stack| stack.borrow_mut().pop());
+    result
+}
+
+/// CPI 처리 (PDA 서명 및 권한 상승 검증 후 호출 대상 프로그램 실행)
+fn cross_program_invoke(
+    instruction: &Instruction,
+    account_infos: &[AccountInfo],
+    signers_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let caller = current_program();
+    let signed_pdas = signers_seeds
+        .iter()
+        .map(|seeds| Pubkey::create_program_address(seeds, &caller))
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .map_err(|_| ProgramError::InvalidSeeds)?;
+
+    let mut accounts = Vec::with_capacity(instruction.accounts.len());
+    for meta in &instruction.accounts {
+        let info = account_infos
+            .iter()
+            .find(|info| *info.key == meta.pubkey)
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        let is_signer = instruction
+            .accounts
+            .iter()
+            .any(|other| other.pubkey == meta.pubkey && other.is_signer);
+        let is_writable = instruction
+            .accounts
+            .iter()
+            .any(|other| other.pubkey == meta.pubkey && other.is_writable);
+        if is_signer && !info.is_signer && !signed_pdas.contains(info.key) {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+        if is_writable && !info.is_writable {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let mut callee_info = info.clone();
+        callee_info.is_signer = is_signer;
+        callee_info.is_writable = is_writable;
+        accounts.push(callee_info);
+    }
+
+    RETURN_DATA.with(|data| *data.borrow_mut() = None);
+    dispatch(&instruction.program_id, &accounts, &instruction.data)
+}
+
+/// 시스템 프로그램 (계정 생성, 전송, 할당, 소유자 변경)
+fn process_system(_program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let instruction: SystemInstruction =
+        limited_deserialize(data, 1232).map_err(|_| ProgramError::InvalidInstructionData)?;
+    let account = |index: usize| accounts.get(index).ok_or(ProgramError::NotEnoughAccountKeys);
+
+    match instruction {
+        SystemInstruction::CreateAccount { lamports, space, owner } => {
+            let (from, to) = (account(0)?, account(1)?);
+            if !from.is_signer || !to.is_signer {
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+            if to.lamports() > 0 || !to.data_is_empty() || *to.owner != system_program::ID {
+                return Err(ProgramError::AccountAlreadyInitialized);
+            }
+            move_lamports(from, to, lamports)?;
+            to.realloc(space as usize, true)?;
+            to.assign(&owner);
+        }
+        SystemInstruction::Transfer { lamports } => {
+            let (from, to) = (account(0)?, account(1)?);
+            if !from.is_signer {
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+            if *from.owner != system_program::ID || !from.data_is_empty() {
+                return Err(ProgramError::InvalidArgument);
+            }
+            move_lamports(from, to, lamports)?;
+        }
+        SystemInstruction::Allocate { space } => {
+            let target = account(0)?;
+            if !target.is_signer {
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+            if *target.owner != system_program::ID || !target.data_is_empty() {
+                return Err(ProgramError::AccountAlreadyInitialized);
+            }
+            target.realloc(space as usize, true)?;
+        }
+        SystemInstruction::Assign { owner } => {
+            let target = account(0)?;
+            if !target.is_signer {
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+            target.assign(&owner);
+        }
+        _ => return Err(ProgramError::InvalidInstructionData),
+    }
+    Ok(())
+}
+
+/// lamports 직접 이동 (호출한 프로그램이 from 계정을 소유한 경우에만 사용)
+pub fn move_lamports(from: &AccountInfo, to: &AccountInfo, lamports: u64) -> ProgramResult {
+    let remaining = from
+        .lamports()
+        .checked_sub(lamports)
+        .ok_or(ProgramError::InsufficientFunds)?;
+    **from.try_borrow_mut_lamports()? = remaining;
+    **to.try_borrow_mut_lamports()? += lamports;
+    Ok(())
+}
+
+/// 테스트 계정 상태
+#[derive(Clone, Debug, Default)]
+pub struct TestAccount {
+    pub lamports: u64,
+    pub data: Vec<u8>,
+    pub owner: Pubkey,
+    pub executable: bool,
+}
+
+/// 테스트용 스테이크 풀 계정 모음
+#[derive(Clone, Copy, Debug)]
+pub struct StakePoolKeys {
+    pub pool: Pubkey,
+    pub withdraw_authority: Pubkey,
+    pub reserve: Pubkey,
+    pub validator_list: Pubkey,
+    pub mint: Pubkey,
+    pub manager_fee: Pubkey,
+}
+
+/// 테스트용 상수 곱 스왑 풀 계정 모음
+#[derive(Clone, Copy, Debug)]
+pub struct SwapPoolKeys {
+    pub swap: Pubkey,
+    pub authority: Pubkey,
+    pub reserve_a: Pubkey,
+    pub reserve_b: Pubkey,
+    pub pool_mint: Pubkey,
+    pub pool_fee: Pubkey,
+}
+
+impl SwapPoolKeys {
+    /// SplTokenSwapAdapter의 pool_accounts 순서 (swap, authority, pool_source, pool_destination, pool_mint, pool_fee)
+    pub fn remaining_accounts(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.swap, false),
+            AccountMeta::new_readonly(self.authority, false),
+            AccountMeta::new(self.reserve_a, false),
+            AccountMeta::new(self.reserve_b, false),
+            AccountMeta::new(self.pool_mint, false),
+            AccountMeta::new(self.pool_fee, false),
+        ]
+    }
+}
+
+/// 인메모리 계정 저장소와 시계를 가진 테스트 환경
+pub struct TestEnv {
+    pub accounts: HashMap<Pubkey, TestAccount>,
+    pub unix_timestamp: i64,
+    pub epoch: u64,
+}
+
+impl TestEnv {
+    pub fn new() -> Self {
+        static INIT: Once = Once::new();
+        INIT.call_once(|| {
+            set_syscall_stubs(Box::new(Stubs));
+        });
+
+        let mut env = Self {
+            accounts: HashMap::new(),
+            unix_timestamp: START_TIME,
+            epoch: 100,
+        };
+        for program_id in [
+            system_program::ID,
+            spl_token::ID,
+            spl_associated_token_account::ID,
+            core_project::ID,
+            SPL_STAKE_POOL_PROGRAM_ID,
+            SWAP_PROGRAM_ID,
+            anchor_lang::solana_program::stake::program::ID,
+        ] {
+            env.set_account(
+                program_id,
+                TestAccount {
+                    lamports: 1,
+                    data: Vec::new(),
+                    owner: anchor_lang::solana_program::bpf_loader::ID,
+                    executable: true,
+                },
+            );
+        }
+
+        let rent = Rent::default();
+        let mut rent_data = Vec::new();
+        rent_data.extend_from_slice(&rent.lamports_per_byte_year.to_le_bytes());
+        rent_data.extend_from_slice(&rent.exemption_threshold.to_le_bytes());
+        rent_data.push(rent.burn_percent);
+        env.set_account(
+            sysvar::rent::ID,
+            TestAccount {
+                lamports: 1,
+                data: rent_data,
+                owner: sysvar::ID,
+                executable: false,
+            },
+        );
+        env.set_account(
+            sysvar::stake_history::ID,
+            TestAccount {
+                lamports: 1,
+                data: vec![0; 8],
+                owner: sysvar::ID,
+                executable: false,
+            },
+        );
+        env.sync_clock();
+        env
+    }
+
+    fn clock(&self) -> Clock {
+        Clock {
+            slot: self.epoch * 432_000,
+            epoch_start_timestamp: self.unix_timestamp,
+            epoch: self.epoch,
+            leader_schedule_epoch: self.epoch + 1,
+            unix_timestamp: self.unix_timestamp,
+        }
+    }
+
+    fn sync_clock(&mut self) {
+        let clock = self.clock();
+        let mut data = Vec::with_capacity(40);
+        data.extend_from_slice(&clock.slot.to_le_bytes());
+        data.extend_from_slice(&clock.epoch_start_timestamp.to_le_bytes());
+        data.extend_from_slice(&clock.epoch.to_le_bytes());
+        data.extend_from_slice(&clock.leader_schedule_epoch.to_le_bytes());
+        data.extend_from_slice(&clock.unix_timestamp.to_le_bytes());
+        self.set_account(
+            sysvar::clock::ID,
+            TestAccount {
+                lamports: 1,
+                data,
+                owner: sysvar::ID,
+                executable: false,
+            },
+        );
+        CLOCK.with(|slot| *slot.borrow_mut() = clock);
+    }
+
+    /// 시계를 앞으로 이동
+    pub fn warp(&mut self, seconds: i64) {
+        self.unix_timestamp += seconds;
+    }
+
+    pub fn set_account(&mut self, key: Pubkey, account: TestAccount) {
+        self.accounts.insert(key, account);
+    }
+
+    pub fn account(&self, key: &Pubkey) -> Option<&TestAccount> {
+        self.accounts.get(key)
+    }
+
+    pub fn lamports(&self, key: &Pubkey) -> u64 {
+        self.account(key).map_or(0, |account| account.lamports)
+    }
+
+    pub fn airdrop(&mut self, key: &Pubkey, lamports: u64) {
+        self.accounts
+            .entry(*key)
+            .or_insert_with(|| TestAccount {
+                owner: system_program::ID,
+                ..Default::default()
+            })
+            .lamports += lamports;
+    }
+
+    /// 시스템 계정 생성 후 SOL 지급
+    pub fn new_wallet(&mut self, lamports: u64) -> Pubkey {
+        let key = Pubkey::new_unique();
+        self.airdrop(&key, lamports);
+        key
+    }
+
+    /// Anchor 계정 역직렬화
+    pub fn fetch<T: AccountDeserialize>(&self, key: &Pubkey) -> T {
+        let account = self.account(key).unwrap_or_else(|| panic!("account {key} not found"));
+        T::try_deserialize(&mut account.data.as_slice()).expect("account deserialization failed")
+    }
+
+    pub fn exists(&self, key: &Pubkey) -> bool {
+        self.account(key).is_some_and(|account| account.lamports > 0)
+    }
+
+    pub fn create_mint(&mut self, authority: &Pubkey, decimals: u8) -> Pubkey {
+        let key = Pubkey::new_unique();
+        let mut data = vec![0; spl_token::state::Mint::LEN];
+        spl_token::state::Mint {
+            mint_authority: COption::Some(*authority),
+            supply: 0,
+            decimals,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        }
+        .pack_into_slice(&mut data);
+        self.set_account(
+            key,
+            TestAccount {
+                lamports: Rent::default().minimum_balance(data.len()),
+                data,
+                owner: spl_token::ID,
+                executable: false,
+            },
+        );
+        key
+    }
+
+    /// 토큰 계정을 지정한 주소에 생성 (PDA 볼트 미리 만들기 등)
+    pub fn set_token_account(&mut self, key: Pubkey, mint: &Pubkey, owner: &Pubkey, amount: u64) {
+        let mut data = vec![0; spl_token::state::Account::LEN];
+        spl_token::state::Account {
+            mint: *mint,
+            owner: *owner,
+            amount,
+            delegate: COption::None,
+            state: spl_token::state::AccountState::Initialized,
+            is_native: COption::None,
+            delegated_amount: 0,
+            close_authority: COption::None,
+        }
+        .pack_into_slice(&mut data);
+        self.set_account(
+            key,
+            TestAccount {
+                lamports: Rent::default().minimum_balance(data.len()),
+                data,
+                owner: spl_token::ID,
+                executable: false,
+            },
+        );
+
+        // 민트 공급량 반영
+        if amount > 0 {
+            let mint_account = self.accounts.get_mut(mint).expect("mint not found");
+            let mut state = spl_token::state::Mint::unpack(&mint_account.data).unwrap();
+            state.supply += amount;
+            state.pack_into_slice(&mut mint_account.data);
+        }
+    }
+
+    pub fn create_token_account(&mut self, mint: &Pubkey, owner: &Pubkey, amount: u64) -> Pubkey {
+        let key = Pubkey::new_unique();
+        self.set_token_account(key, mint, owner, amount);
+        key
+    }
+
+    pub fn token_balance(&self, key: &Pubkey) -> u64 {
+        let account = self.account(key).unwrap_or_else(|| panic!("token account {key} not found"));
+        spl_token::state::Account::unpack(&account.data).unwrap().amount
+    }
+
+    pub fn mint_supply(&self, mint: &Pubkey) -> u64 {
+        spl_token::state::Mint::unpack(&self.account(mint).unwrap().data).unwrap().supply
+    }
+
+    /// 테스트용 스테이크 풀 생성 (LST 민트 권한은 출금 권한 PDA, 교환 비율은 total_lamports / pool_token_supply)
+    pub fn create_stake_pool(&mut self, total_lamports: u64, pool_token_supply: u64) -> StakePoolKeys {
+        let pool = Pubkey::new_unique();
+        let (withdraw_authority, withdraw_bump) =
+            Pubkey::find_program_address(&[pool.as_ref(), b"withdraw"], &SPL_STAKE_POOL_PROGRAM_ID);
+        let reserve = Pubkey::new_unique();
+        let validator_list = Pubkey::new_unique();
+        let mint = self.create_mint(&withdraw_authority, 9);
+        let manager = Pubkey::new_unique();
+        let manager_fee = self.create_token_account(&mint, &manager, 0);
+
+        let mut data = vec![0; stake_pool::POOL_LEN];
+        data[0] = 1;
+        data[97] = withdraw_bump;
+        data[98..130].copy_from_slice(validator_list.as_ref());
+        data[130..162].copy_from_slice(reserve.as_ref());
+        data[162..194].copy_from_slice(mint.as_ref());
+        data[194..226].copy_from_slice(manager_fee.as_ref());
+        data[226..258].copy_from_slice(spl_token::ID.as_ref());
+        data[258..266].copy_from_slice(&total_lamports.to_le_bytes());
+        data[266..274].copy_from_slice(&pool_token_supply.to_le_bytes());
+        self.set_account(
+            pool,
+            TestAccount {
+                lamports: Rent::default().minimum_balance(data.len()),
+                data,
+                owner: SPL_STAKE_POOL_PROGRAM_ID,
+                executable: false,
+            },
+        );
+        for key in [reserve, validator_list] {
+            self.set_account(
+                key,
+                TestAccount {
+                    lamports: total_lamports,
+                    data: Vec::new(),
+                    owner: SPL_STAKE_POOL_PROGRAM_ID,
+                    executable: false,
+                },
+            );
+        }
+
+        StakePoolKeys {
+            pool,
+            withdraw_authority,
+            reserve,
+            validator_list,
+            mint,
+            manager_fee,
+        }
+    }
+
+    /// 테스트용 상수 곱 스왑 풀 생성 (수수료는 bps 단위)
+    pub fn create_swap_pool(
+        &mut self,
+        mint_a: &Pubkey,
+        reserve_a: u64,
+        mint_b: &Pubkey,
+        reserve_b: u64,
+        fee_bps: u16,
+    ) -> SwapPoolKeys {
+        let swap = Pubkey::new_unique();
+        let (authority, bump) = Pubkey::find_program_address(&[swap.as_ref()], &SWAP_PROGRAM_ID);
+        let mut data = vec![bump];
+        data.extend_from_slice(&fee_bps.to_le_bytes());
+        self.set_account(
+            swap,
+            TestAccount {
+                lamports: Rent::default().minimum_balance(data.len()),
+                data,
+                owner: SWAP_PROGRAM_ID,
+                executable: false,
+            },
+        );
+        let pool_mint = self.create_mint(&authority, 9);
+        SwapPoolKeys {
+            swap,
+            authority,
+            reserve_a: self.create_token_account(mint_a, &authority, reserve_a),
+            reserve_b: self.create_token_account(mint_b, &authority, reserve_b),
+            pool_mint,
+            pool_fee: self.create_token_account(&pool_mint, &authority, 0),
+        }
+    }
+
+    /// 명령어 실행 (실패하면 계정 상태를 변경하지 않음)
+    pub fn process(&mut self, instruction: Instruction, signers: &[Pubkey]) -> std::result::Result<(), ProgramError> {
+        for meta in instruction.accounts.iter().filter(|meta| meta.is_signer) {
+            if !signers.contains(&meta.pubkey) {
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+        }
+        self.sync_clock();
+        LOGS.with(|logs| logs.borrow_mut().clear());
+
+        let mut input = Vec::new();
+        input.extend_from_slice(&(instruction.accounts.len() as u64).to_le_bytes());
+        for (index, meta) in instruction.accounts.iter().enumerate() {
+            if let Some(first) = instruction.accounts[..index]
+                .iter()
+                .position(|other| other.pubkey == meta.pubkey)
+            {
+                input.push(first as u8);
+                input.extend_from_slice(&[0; 7]);
+                continue;
+            }
+
+            let is_signer = instruction
+                .accounts
+                .iter()
+                .any(|other| other.pubkey == meta.pubkey && other.is_signer);
+            let is_writable = instruction
+                .accounts
+                .iter()
+                .any(|other| other.pubkey == meta.pubkey && other.is_writable);
+            let account = self.accounts.get(&meta.pubkey).cloned().unwrap_or(TestAccount {
+                owner: system_program::ID,
+                ..Default::default()
+            });
+
+            input.push(u8::MAX);
+            input.push(is_signer as u8);
+            input.push(is_writable as u8);
+            input.push(account.executable as u8);
+            input.extend_from_slice(&(account.data.len() as u32).to_le_bytes());
+            input.extend_from_slice(meta.pubkey.as_ref());
+            input.extend_from_slice(account.owner.as_ref());
+            input.extend_from_slice(&account.lamports.to_le_bytes());
+            input.extend_from_slice(&(account.data.len() as u64).to_le_bytes());
+            input.extend_from_slice(&account.data);
+            input.resize(input.len() + MAX_PERMITTED_DATA_INCREASE, 0);
+            input.resize(input.len().next_multiple_of(BPF_ALIGN_OF_U128), 0);
+            input.extend_from_slice(&u64::MAX.to_le_bytes());
+        }
+        input.extend_from_slice(&(instruction.data.len() as u64).to_le_bytes());
+        input.extend_from_slice(&instruction.data);
+        input.extend_from_slice(instruction.program_id.as_ref());
+
+        // u128 정렬 버퍼에 복사 후 BPF 입력으로 역직렬화
+        let mut buffer = vec![0u128; input.len().div_ceil(16)];
+        unsafe {
+            std::ptr::copy_nonoverlapping(input.as_ptr(), buffer.as_mut_ptr() as *mut u8, input.len());
+        }
+        let (program_id, account_infos, data) = unsafe { deserialize(buffer.as_mut_ptr() as *mut u8) };
+
+        let result = dispatch(program_id, &account_infos, data);
+        if result.is_ok() {
+            for info in account_infos.iter().filter(|info| info.is_writable) {
+                let lamports = info.lamports();
+                if lamports == 0 {
+                    self.accounts.remove(info.key);
+                    continue;
+                }
+                self.accounts.insert(
+                    *info.key,
+                    TestAccount {
+                        lamports,
+                        data: info.data.borrow().to_vec(),
+                        owner: *info.owner,
+                        executable: info.executable,
+                    },
+                );
+            }
+        }
+        drop(account_infos);
+        drop(buffer);
+        result
+    }
+
+    /// core-project 명령어 실행
+    pub fn call(
+        &mut self,
+        accounts: impl ToAccountMetas,
+        args: impl InstructionData,
+        signers: &[Pubkey],
+    ) -> std::result::Result<(), ProgramError> {
+        self.call_with_remaining(accounts, args, Vec::new(), signers)
+    }
+
+    /// remaining_accounts를 포함한 core-project 명령어 실행
+    pub fn call_with_remaining(
+        &mut self,
+        accounts: impl ToAccountMetas,
+        args: impl InstructionData,
+        remaining_accounts: Vec<AccountMeta>,
+        signers: &[Pubkey],
+    ) -> std::result::Result<(), ProgramError> {
+        let mut metas = accounts.to_account_metas(None);
+        metas.extend(remaining_accounts);
+        self.process(
+            Instruction {
+                program_id: core_project::ID,
+                accounts: metas,
+                data: args.data(),
+            },
+            signers,
+        )
+    }
+
+    /// 마지막 명령어 실행 로그
+    pub fn logs(&self) -> Vec<String> {
+        LOGS.with(|logs| logs.borrow().clone())
+    }
+}
+
+impl Default for TestEnv {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 플랫폼과 역할 목록이 초기화된 테스트 환경
+pub struct Fixture {
+    pub env: TestEnv,
+    /// 플랫폼 관리자
+    pub authority: Pubkey,
+    /// 관리자 지갑 (보상 운영자, 프로젝트 중재자 역할)
+    pub admin_wallet: Pubkey,
+    pub platform: Pubkey,
+    pub roles: Pubkey,
+}
+
+impl Fixture {
+    /// 플랫폼 수수료 1%, 최소 스테이킹 0.001 SOL로 플랫폼 초기화
+    pub fn new() -> Self {
+        let mut env = TestEnv::new();
+        let authority = env.new_wallet(100 * LAMPORTS_PER_SOL);
+        let admin_wallet = env.new_wallet(10 * LAMPORTS_PER_SOL);
+        let platform = pda(&[b"platform"]);
+        let roles = pda(&[b"roles"]);
+        env.call(
+            core_project::accounts::InitializePlatform {
+                platform,
+                payer: authority,
+                roles,
+                system_program: system_program::ID,
+                rent: sysvar::rent::ID,
+            },
+            core_project::instruction::InitializePlatform {
+                platform_fee: 100,
+                min_stake_amount: 1_000_000,
+                admin_wallet,
+            },
+            &[authority],
+        )
+        .unwrap_or_else(|err| panic!("initialize_platform failed: {err:?} {:?}", env.logs()));
+
+        Self {
+            env,
+            authority,
+            admin_wallet,
+            platform,
+            roles,
+        }
+    }
+
+    /// 30일 펀딩 기간으로 프로젝트 등록 (즉시 시작)
+    pub fn register_project(&mut self, creator: &Pubkey, name: &str, funding_goal: u64, lst_mint: &Pubkey) -> Pubkey {
+        let project = pda(&[b"project", name.as_bytes(), creator.as_ref()]);
+        self.env
+            .call(
+                core_project::accounts::RegisterProject {
+                    project,
+                    creator: *creator,
+                    platform: self.platform,
+                    system_program: system_program::ID,
+                    rent: sysvar::rent::ID,
+                },
+                core_project::instruction::RegisterProject {
+                    name: name.to_string(),
+                    symbol: "FRST".to_string(),
+                    description: "테스트 프로젝트".to_string(),
+                    website: "https://forest.example".to_string(),
+                    image_uri: "https://forest.example/image.png".to_string(),
+                    funding_goal,
+                    duration: 30 * 86400,
+                    lst_mint: *lst_mint,
+                    apy_estimate: 700,
+                    start_time: None,
+                    max_raise: None,
+                },
+                &[*creator],
+            )
+            .unwrap_or_else(|err| panic!("register_project failed: {err:?} {:?}", self.env.logs()));
+        project
+    }
+
+    /// 스테이크 풀 DepositSol CPI를 거치는 SOL 스테이킹
+    pub fn stake_sol(
+        &mut self,
+        project: &Pubkey,
+        pool: &StakePoolKeys,
+        user: &Pubkey,
+        user_lst_account: &Pubkey,
+        amount: u64,
+    ) -> std::result::Result<(), ProgramError> {
+        self.env.call(
+            core_project::accounts::StakeSol {
+                project: *project,
+                user: *user,
+                platform: self.platform,
+                stake_info: pda(&[b"stake_info", user.as_ref(), project.as_ref()]),
+                stake_pool: pool.pool,
+                stake_pool_withdraw_authority: pool.withdraw_authority,
+                reserve_stake: pool.reserve,
+                user_lst_account: *user_lst_account,
+                manager_fee_account: pool.manager_fee,
+                lst_mint: pool.mint,
+                stake_pool_program: SPL_STAKE_POOL_PROGRAM_ID,
+                token_program: spl_token::ID,
+                system_program: system_program::ID,
+                rent: sysvar::rent::ID,
+            },
+            core_project::instruction::StakeSol { amount },
+            &[*user],
+        )
+    }
+
+    /// 플랫폼 관리자가 테스트 스왑 프로그램으로 보상 스왑 설정
+    pub fn configure_swap(&mut self, usdc_mint: &Pubkey, project_share_bps: u16) {
+        self.env
+            .call(
+                core_project::accounts::ConfigureSwap {
+                    authority: self.authority,
+                    platform: self.platform,
+                    roles: None,
+                    swap_config: pda(&[b"swap_config"]),
+                    system_program: system_program::ID,
+                    rent: sysvar::rent::ID,
+                },
+                core_project::instruction::ConfigureSwap {
+                    swap_program: SWAP_PROGRAM_ID,
+                    adapter: core_project::SwapAdapterKind::SplTokenSwap,
+                    usdc_mint: *usdc_mint,
+                    project_share_bps,
+                },
+                &[self.authority],
+            )
+            .unwrap_or_else(|err| panic!("configure_swap failed: {err:?} {:?}", self.env.logs()));
+    }
+
+    /// 보상 운영자(관리자 지갑)가 에포크 보상 처리
+    pub fn process_epoch_rewards(
+        &mut self,
+        project: &Pubkey,
+        lst_mint: &Pubkey,
+        reward_source: &Pubkey,
+        epoch: u64,
+        total_rewards: u64,
+    ) -> std::result::Result<(), ProgramError> {
+        self.env.call(
+            core_project::accounts::ProcessEpochRewards {
+                project: *project,
+                authority: self.admin_wallet,
+                platform: self.platform,
+                roles: Some(self.roles),
+                rewards_info: pda(&[b"rewards_info", project.as_ref(), &epoch.to_le_bytes()]),
+                rewards_history: pda(&[b"rewards_history", project.as_ref()]),
+                restake_config: pda(&[b"restake_config", project.as_ref()]),
+                swap_config: pda(&[b"swap_config"]),
+                vault_authority: pda(&[b"vault_authority", project.as_ref()]),
+                reward_vault: pda(&[b"reward_vault", project.as_ref()]),
+                project_share_vault: pda(&[b"project_share_vault", project.as_ref()]),
+                reward_source: *reward_source,
+                platform_treasury: pda(&[b"platform_treasury", lst_mint.as_ref()]),
+                lst_mint: *lst_mint,
+                token_program: spl_token::ID,
+                system_program: system_program::ID,
+                rent: sysvar::rent::ID,
+            },
+            core_project::instruction::ProcessEpochRewards { epoch, total_rewards },
+            &[self.admin_wallet],
+        )
+    }
+
+    /// 프로젝트 보너스 볼트 충전
+    pub fn fund_bonus_vault(
+        &mut self,
+        project: &Pubkey,
+        lst_mint: &Pubkey,
+        funder: &Pubkey,
+        funder_token_account: &Pubkey,
+        amount: u64,
+    ) -> std::result::Result<(), ProgramError> {
+        self.env.call(
+            core_project::accounts::FundBonusVault {
+                project: *project,
+                platform: self.platform,
+                funder: *funder,
+                funder_token_account: *funder_token_account,
+                vault_authority: pda(&[b"vault_authority", project.as_ref()]),
+                bonus_vault: pda(&[b"bonus_vault", project.as_ref()]),
+                lst_mint: *lst_mint,
+                token_program: spl_token::ID,
+                system_program: system_program::ID,
+                rent: sysvar::rent::ID,
+            },
+            core_project::instruction::FundBonusVault { amount },
+            &[*funder],
+        )
+    }
+
+    /// 사용자의 다음 인덱스로 락업 생성 후 락업 주소 반환
+    pub fn create_lockup(
+        &mut self,
+        project: &Pubkey,
+        lst_mint: &Pubkey,
+        user: &Pubkey,
+        user_token_account: &Pubkey,
+        amount: u64,
+        duration: i64,
+    ) -> (std::result::Result<(), ProgramError>, Pubkey) {
+        let lockup_counter = pda(&[b"lockup_counter", user.as_ref(), project.as_ref()]);
+        let next_index = if self.env.exists(&lockup_counter) {
+            self.env.fetch::<core_project::LockupCounter>(&lockup_counter).next_index
+        } else {
+            0
+        };
+        let lockup = lockup_address(user, project, next_index);
+        let result = self.env.call(
+            core_project::accounts::CreateLockup {
+                project: *project,
+                platform: self.platform,
+                user: *user,
+                lockup_counter,
+                lockup,
+                lockup_tier_config: pda(&[b"lockup_tier_config", project.as_ref()]),
+                voting_power: pda(&[b"voting_power", user.as_ref(), project.as_ref()]),
+                user_token_account: *user_token_account,
+                lockup_vault: get_associated_token_address(&lockup, lst_mint),
+                vault_authority: pda(&[b"vault_authority", project.as_ref()]),
+                bonus_vault: pda(&[b"bonus_vault", project.as_ref()]),
+                lst_mint: *lst_mint,
+                token_program: spl_token::ID,
+                associated_token_program: spl_associated_token_account::ID,
+                system_program: system_program::ID,
+                rent: sysvar::rent::ID,
+            },
+            core_project::instruction::CreateLockup { amount, duration },
+            &[*user],
+        );
+        (result, lockup)
+    }
+
+    /// 프로젝트 멀티시그 활성화
+    pub fn setup_multisig(
+        &mut self,
+        project: &Pubkey,
+        authority: &Pubkey,
+        signers: &[Pubkey],
+        threshold: u8,
+    ) -> std::result::Result<(), ProgramError> {
+        self.env.call(
+            core_project::accounts::SetupMultisigManagement {
+                project: *project,
+                platform: self.platform,
+                authority: *authority,
+                multisig_config: pda(&[b"multisig_config", project.as_ref()]),
+                system_program: system_program::ID,
+                rent: sysvar::rent::ID,
+            },
+            core_project::instruction::SetupMultisigManagement {
+                signers: signers.to_vec(),
+                threshold,
+            },
+            &[*authority],
+        )
+    }
+
+    /// 다음 인덱스로 멀티시그 제안을 생성하고 제안 주소 반환
+    pub fn create_proposal(
+        &mut self,
+        project: &Pubkey,
+        proposer: &Pubkey,
+        action: core_project::ProjectAction,
+    ) -> (std::result::Result<(), ProgramError>, Pubkey) {
+        let multisig_config = pda(&[b"multisig_config", project.as_ref()]);
+        let proposal_count = self
+            .env
+            .fetch::<core_project::MultisigConfig>(&multisig_config)
+            .proposal_count;
+        let proposal = pda(&[b"multisig_proposal", multisig_config.as_ref(), &proposal_count.to_le_bytes()]);
+        let result = self.env.call(
+            core_project::accounts::CreateProposal {
+                project: *project,
+                platform: self.platform,
+                multisig_config,
+                proposal,
+                proposer: *proposer,
+                system_program: system_program::ID,
+                rent: sysvar::rent::ID,
+            },
+            core_project::instruction::CreateProposal { action },
+            &[*proposer],
+        );
+        (result, proposal)
+    }
+
+    /// 멀티시그 제안 승인
+    pub fn approve_proposal(
+        &mut self,
+        project: &Pubkey,
+        proposal: &Pubkey,
+        signer: &Pubkey,
+    ) -> std::result::Result<(), ProgramError> {
+        self.env.call(
+            core_project::accounts::ApproveProposal {
+                multisig_config: pda(&[b"multisig_config", project.as_ref()]),
+                platform: self.platform,
+                proposal: *proposal,
+                signer: *signer,
+            },
+            core_project::instruction::ApproveProposal {},
+            &[*signer],
+        )
+    }
+}
+
+impl Default for Fixture {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// core-project PDA 계산
+pub fn pda(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &core_project::ID).0
+}
+
+/// 사용자 락업 포지션 PDA 계산
+pub fn lockup_address(user: &Pubkey, project: &Pubkey, index: u64) -> Pubkey {
+    pda(&[b"lockup", user.as_ref(), project.as_ref(), &index.to_le_bytes()])
+}
+
+/// Anchor 오류를 ProgramError로 변환
+pub fn anchor_error(error: impl Into<anchor_lang::error::Error>) -> ProgramError {
+    error.into().into()
+}
+
+/// 명령어가 지정한 Anchor 오류로 실패했는지 확인
+#[track_caller]
+pub fn assert_error(result: std::result::Result<(), ProgramError>, error: impl Into<anchor_lang::error::Error>) {
+    assert_eq!(result, Err(anchor_error(error)));
+}
+
+/// SPL Stake Pool 테스트 대역 (DepositSol, WithdrawSol, WithdrawStake)
+///
+/// StakePool 계정 헤더 레이아웃과 명령어 계정 순서는 SPL Stake Pool과 같고,
+/// 리저브와 분할 대상 스테이크 계정은 이 프로그램이 소유한 일반 계정으로 대신합니다.
+pub mod stake_pool {
+    use super::*;
+
+    pub const POOL_LEN: usize = 282;
+    const TOTAL_LAMPORTS: usize = 258;
+    const POOL_TOKEN_SUPPLY: usize = 266;
+
+    fn read_u64(data: &[u8], offset: usize) -> u64 {
+        u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap())
+    }
+
+    fn write_u64(data: &mut [u8], offset: usize, value: u64) {
+        data[offset..offset + 8].copy_from_slice(&value.to_le_bytes());
+    }
+
+    pub fn process(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+        let (&tag, rest) = data.split_first().ok_or(ProgramError::InvalidInstructionData)?;
+        let amount = u64::from_le_bytes(rest.try_into().map_err(|_| ProgramError::InvalidInstructionData)?);
+        let pool = accounts.first().ok_or(ProgramError::NotEnoughAccountKeys)?;
+        if pool.owner != program_id {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+        let (total_lamports, supply, bump) = {
+            let pool_data = pool.data.borrow();
+            (
+                read_u64(&pool_data, TOTAL_LAMPORTS),
+                read_u64(&pool_data, POOL_TOKEN_SUPPLY),
+                pool_data[97],
+            )
+        };
+        let account = |index: usize| accounts.get(index).ok_or(ProgramError::NotEnoughAccountKeys);
+
+        let (lamports, pool_tokens) = match tag {
+            // DepositSol: pool, withdraw_authority, reserve, from, destination, manager_fee, referrer, mint, system, token
+            14 => {
+                let (withdraw_authority, reserve, from, destination, mint, system, token) =
+                    (account(1)?, account(2)?, account(3)?, account(4)?, account(7)?, account(8)?, account(9)?);
+                let pool_tokens = if supply == 0 {
+                    amount
+                } else {
+                    ((amount as u128) * (supply as u128) / (total_lamports as u128)) as u64
+                };
+                invoke(
+                    &anchor_lang::solana_program::system_instruction::transfer(from.key, reserve.key, amount),
+                    &[from.clone(), reserve.clone(), system.clone()],
+                )?;
+                invoke_signed(
+                    &spl_token::instruction::mint_to(
+                        token.key,
+                        mint.key,
+                        destination.key,
+                        withdraw_authority.key,
+                        &[],
+                        pool_tokens,
+                    )?,
+                    &[mint.clone(), destination.clone(), withdraw_authority.clone(), token.clone()],
+                    &[&[pool.key.as_ref(), b"withdraw", &[bump]]],
+                )?;
+                (total_lamports + amount, supply + pool_tokens)
+            }
+            // WithdrawSol: pool, withdraw_authority, user_transfer_authority, burn_from, reserve, lamports_to, manager_fee, mint, ...
+            16 => {
+                let (transfer_authority, burn_from, reserve, lamports_to, mint, token) =
+                    (account(2)?, account(3)?, account(4)?, account(5)?, account(7)?, account(11)?);
+                let lamports = ((amount as u128) * (total_lamports as u128) / (supply as u128)) as u64;
+                burn(token, burn_from, mint, transfer_authority, amount)?;
+                move_lamports(reserve, lamports_to, lamports)?;
+                (total_lamports - lamports, supply - amount)
+            }
+            // WithdrawStake: pool, validator_list, withdraw_authority, stake_to_split, stake_to_receive,
+            // user_stake_authority, user_transfer_authority, burn_from, manager_fee, mint, clock, token, stake_program
+            10 => {
+                let (stake_to_split, stake_to_receive, transfer_authority, burn_from, mint, token) =
+                    (account(3)?, account(4)?, account(6)?, account(7)?, account(9)?, account(11)?);
+                let lamports = ((amount as u128) * (total_lamports as u128) / (supply as u128)) as u64;
+                burn(token, burn_from, mint, transfer_authority, amount)?;
+                move_lamports(stake_to_split, stake_to_receive, lamports)?;
+                (total_lamports - lamports, supply - amount)
+            }
+            _ => return Err(ProgramError::InvalidInstructionData),
+        };
+
+        let mut pool_data = pool.data.borrow_mut();
+        write_u64(&mut pool_data, TOTAL_LAMPORTS, lamports);
+        write_u64(&mut pool_data, POOL_TOKEN_SUPPLY, pool_tokens);
+        Ok(())
+    }
+
+    fn burn<'a>(
+        token: &AccountInfo<'a>,
+        burn_from: &AccountInfo<'a>,
+        mint: &AccountInfo<'a>,
+        authority: &AccountInfo<'a>,
+        amount: u64,
+    ) -> ProgramResult {
+        invoke(
+            &spl_token::instruction::burn(token.key, burn_from.key, mint.key, authority.key, &[], amount)?,
+            &[burn_from.clone(), mint.clone(), authority.clone(), token.clone()],
+        )
+    }
+}
+
+/// SPL Token Swap (Constant Product) 테스트 대역
+///
+/// Swap 명령어(태그 1, amount_in, minimum_amount_out)와 계정 순서는 SPL Token Swap과 같고,
+/// swap 계정 데이터는 [권한 PDA 범프, 수수료 bps] 만 저장합니다.
+pub mod constant_product {
+    use super::*;
+
+    pub fn process(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+        if data.len() != 17 || data[0] != 1 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let amount_in = u64::from_le_bytes(data[1..9].try_into().unwrap());
+        let minimum_amount_out = u64::from_le_bytes(data[9..17].try_into().unwrap());
+
+        let [swap, authority, user_transfer_authority, source, pool_source, pool_destination, destination, _pool_mint, _pool_fee, token, ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+        if swap.owner != program_id {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+        let (bump, fee_bps) = {
+            let swap_data = swap.data.borrow();
+            (swap_data[0], u16::from_le_bytes([swap_data[1], swap_data[2]]))
+        };
+        let authority_seeds: &[&[u8]] = &[swap.key.as_ref(), &[bump]];
+        if Pubkey::create_program_address(authority_seeds, program_id)? != *authority.key {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        // x * y = k 기준 출력량 (입력에서 수수료 차감)
+        let reserve_in = spl_token::state::Account::unpack(&pool_source.data.borrow())?.amount as u128;
+        let reserve_out = spl_token::state::Account::unpack(&pool_destination.data.borrow())?.amount as u128;
+        let amount_in_after_fee = (amount_in as u128) * (10_000 - fee_bps as u128) / 10_000;
+        let amount_out = (reserve_out * amount_in_after_fee / (reserve_in + amount_in_after_fee)) as u64;
+        if amount_out == 0 || amount_out < minimum_amount_out {
+            return Err(ProgramError::Custom(SWAP_EXCEEDED_SLIPPAGE));
+        }
+
+        invoke(
+            &spl_token::instruction::transfer(
+                token.key,
+                source.key,
+                pool_source.key,
+                user_transfer_authority.key,
+                &[],
+                amount_in,
+            )?,
+            &[source.clone(), pool_source.clone(), user_transfer_authority.clone(), token.clone()],
+        )?;
+        invoke_signed(
+            &spl_token::instruction::transfer(
+                token.key,
+                pool_destination.key,
+                destination.key,
+                authority.key,
+                &[],
+                amount_out,
+            )?,
+            &[pool_destination.clone(), destination.clone(), authority.clone(), token.clone()],
+            &[authority_seeds],
+        )
+    }
+}
@@ -0,0 +1,256 @@
+mod common;
+
+use anchor_lang::{
+    prelude::*,
+    solana_program::{system_program, sysvar},
+};
+use anchor_spl::{associated_token::get_associated_token_address, token::spl_token};
+use common::*;
+use core_project::{ForestLabError, ProjectProposal, VoteRecord, VoteWeightSource, VotingPower};
+
+const DAY: i64 = 86400;
+const LST: u64 = LAMPORTS_PER_SOL;
+
+/// 교환 비율 2 SOL = 1 LST인 스테이크 풀과 프로젝트
+struct GovernanceSetup {
+    fixture: Fixture,
+    project: Pubkey,
+    creator: Pubkey,
+    pool: StakePoolKeys,
+}
+
+fn setup() -> GovernanceSetup {
+    let mut fixture = Fixture::new();
+    let pool = fixture.env.create_stake_pool(2_000 * LAMPORTS_PER_SOL, 1_000 * LAMPORTS_PER_SOL);
+    let creator = fixture.env.new_wallet(10 * LAMPORTS_PER_SOL);
+    let project = fixture.register_project(&creator, "forest", 100 * LAMPORTS_PER_SOL, &pool.mint);
+    GovernanceSetup {
+        fixture,
+        project,
+        creator,
+        pool,
+    }
+}
+
+/// SOL을 스테이킹한 투표자 (LST 계정 반환)
+fn staked_voter(setup: &mut GovernanceSetup, lamports: u64) -> (Pubkey, Pubkey) {
+    let voter = setup.fixture.env.new_wallet(20 * LAMPORTS_PER_SOL);
+    let voter_lst = setup.fixture.env.create_token_account(&setup.pool.mint, &voter, 0);
+    stake_more(setup, voter, voter_lst, lamports);
+    (voter, voter_lst)
+}
+
+fn stake_more(setup: &mut GovernanceSetup, voter: Pubkey, voter_lst: Pubkey, lamports: u64) {
+    let (project, pool) = (setup.project, setup.pool);
+    setup
+        .fixture
+        .stake_sol(&project, &pool, &voter, &voter_lst, lamports)
+        .unwrap();
+}
+
+/// 지금부터 3일간 투표하는 2지선다 제안 생성
+fn create_proposal(setup: &mut GovernanceSetup, proposer: Pubkey) -> (std::result::Result<(), ProgramError>, Pubkey) {
+    let project = setup.project;
+    let proposal_count = setup
+        .fixture
+        .env
+        .fetch::<core_project::Project>(&project)
+        .proposal_count;
+    let proposal = pda(&[b"project_proposal", project.as_ref(), &proposal_count.to_le_bytes()]);
+    let now = setup.fixture.env.unix_timestamp;
+    let result = setup.fixture.env.call(
+        core_project::accounts::CreateProjectProposal {
+            project,
+            platform: setup.fixture.platform,
+            proposal,
+            proposer,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        core_project::instruction::CreateProjectProposal {
+            title: "리스테이킹 비율 상향".to_string(),
+            description_uri: "https://forest.example/proposals/1".to_string(),
+            options: vec!["찬성".to_string(), "반대".to_string()],
+            voting_start: now,
+            voting_end: now + 3 * DAY,
+        },
+        &[proposer],
+    );
+    (result, proposal)
+}
+
+fn cast_vote(
+    setup: &mut GovernanceSetup,
+    proposal: Pubkey,
+    voter: Pubkey,
+    option: u8,
+    source: VoteWeightSource,
+) -> std::result::Result<(), ProgramError> {
+    let project = setup.project;
+    let (stake_info, voting_power) = match source {
+        VoteWeightSource::Stake => (Some(pda(&[b"stake_info", voter.as_ref(), project.as_ref()])), None),
+        VoteWeightSource::Lockup => (None, Some(pda(&[b"voting_power", voter.as_ref(), project.as_ref()]))),
+    };
+    setup.fixture.env.call(
+        core_project::accounts::CastVote {
+            proposal,
+            platform: setup.fixture.platform,
+            vote_record: pda(&[b"vote_record", proposal.as_ref(), voter.as_ref()]),
+            stake_info,
+            voting_power,
+            voter,
+            system_program: system_program::ID,
+        },
+        core_project::instruction::CastVote { option, source },
+        &[voter],
+    )
+}
+
+fn finalize_proposal(setup: &mut GovernanceSetup, proposal: Pubkey) -> std::result::Result<(), ProgramError> {
+    let authority = setup.fixture.env.new_wallet(LAMPORTS_PER_SOL);
+    setup.fixture.env.call(
+        core_project::accounts::FinalizeProposal {
+            proposal,
+            platform: setup.fixture.platform,
+            authority,
+        },
+        core_project::instruction::FinalizeProposal {},
+        &[authority],
+    )
+}
+
+fn vote_weight(setup: &GovernanceSetup, proposal: &Pubkey, voter: &Pubkey) -> u64 {
+    setup
+        .fixture
+        .env
+        .fetch::<VoteRecord>(&pda(&[b"vote_record", proposal.as_ref(), voter.as_ref()]))
+        .weight
+}
+
+#[test]
+fn stake_votes_are_weighed_at_snapshot_and_finalized_after_voting_ends() {
+    let mut setup = setup();
+    let (first, first_lst) = staked_voter(&mut setup, 2 * LAMPORTS_PER_SOL);
+    let (second, _) = staked_voter(&mut setup, 4 * LAMPORTS_PER_SOL);
+
+    let creator = setup.creator;
+    let (result, proposal) = create_proposal(&mut setup, creator);
+    result.unwrap();
+
+    // 스냅샷 이후 추가 스테이킹은 투표 가중치에 반영되지 않음
+    setup.fixture.env.warp(60);
+    stake_more(&mut setup, first, first_lst, 4 * LAMPORTS_PER_SOL);
+    cast_vote(&mut setup, proposal, first, 0, VoteWeightSource::Stake).unwrap();
+    cast_vote(&mut setup, proposal, second, 1, VoteWeightSource::Stake).unwrap();
+    assert_eq!(vote_weight(&setup, &proposal, &first), LST);
+    assert_eq!(vote_weight(&setup, &proposal, &second), 2 * LST);
+
+    // 같은 제안에 두 번 투표할 수 없음
+    let result = cast_vote(&mut setup, proposal, first, 0, VoteWeightSource::Stake);
+    assert!(result.is_err());
+
+    // 투표 종료 전에는 확정할 수 없음
+    let result = finalize_proposal(&mut setup, proposal);
+    assert_error(result, ForestLabError::VotingNotEnded);
+
+    setup.fixture.env.warp(3 * DAY);
+    finalize_proposal(&mut setup, proposal).unwrap();
+    let data: ProjectProposal = setup.fixture.env.fetch(&proposal);
+    assert!(data.finalized);
+    assert_eq!(data.votes, vec![LST, 2 * LST]);
+    assert_eq!(data.total_weight, 3 * LST);
+    assert_eq!(data.winning_option, Some(1));
+
+    let result = finalize_proposal(&mut setup, proposal);
+    assert_error(result, ForestLabError::ProposalAlreadyFinalized);
+}
+
+#[test]
+fn lockup_votes_use_voting_power_at_snapshot() {
+    let mut setup = setup();
+    let (voter, voter_lst) = staked_voter(&mut setup, 4 * LAMPORTS_PER_SOL);
+    let (project, lst_mint) = (setup.project, setup.pool.mint);
+    let (result, lockup) = setup
+        .fixture
+        .create_lockup(&project, &lst_mint, &voter, &voter_lst, LST, 30 * DAY);
+    result.unwrap();
+
+    let creator = setup.creator;
+    let (result, proposal) = create_proposal(&mut setup, creator);
+    result.unwrap();
+    let snapshot_time = setup.fixture.env.fetch::<ProjectProposal>(&proposal).snapshot_time;
+    let voting_power = pda(&[b"voting_power", voter.as_ref(), project.as_ref()]);
+    let expected = setup
+        .fixture
+        .env
+        .fetch::<VotingPower>(&voting_power)
+        .power_at(snapshot_time);
+    assert!(expected > 0);
+
+    // 스냅샷 이후 락업 추가 예치는 투표 가중치에 반영되지 않음
+    setup.fixture.env.warp(60);
+    setup.fixture.env.call(
+        core_project::accounts::IncreaseLockup {
+            lockup,
+            lockup_tier_config: pda(&[b"lockup_tier_config", project.as_ref()]),
+            voting_power,
+            user: voter,
+            project,
+            platform: setup.fixture.platform,
+            user_token_account: voter_lst,
+            lockup_vault: get_associated_token_address(&lockup, &lst_mint),
+            bonus_vault: pda(&[b"bonus_vault", project.as_ref()]),
+            lst_mint,
+            token_program: spl_token::ID,
+        },
+        core_project::instruction::IncreaseLockup { amount: LST },
+        &[voter],
+    )
+    .unwrap();
+    let current = setup
+        .fixture
+        .env
+        .fetch::<VotingPower>(&voting_power)
+        .power_at(setup.fixture.env.unix_timestamp);
+    assert!(current > expected);
+
+    cast_vote(&mut setup, proposal, voter, 0, VoteWeightSource::Lockup).unwrap();
+    assert_eq!(vote_weight(&setup, &proposal, &voter), expected);
+}
+
+#[test]
+fn stake_made_after_snapshot_carries_no_voting_power() {
+    let mut setup = setup();
+    let creator = setup.creator;
+    let (result, proposal) = create_proposal(&mut setup, creator);
+    result.unwrap();
+
+    // 스냅샷 이후 새 지갑으로 스테이킹한 경우 (LST 이전을 통한 이중 투표 방지)
+    setup.fixture.env.warp(60);
+    let (late_voter, _) = staked_voter(&mut setup, 2 * LAMPORTS_PER_SOL);
+    let result = cast_vote(&mut setup, proposal, late_voter, 0, VoteWeightSource::Stake);
+    assert_error(result, ForestLabError::NoVotingPower);
+}
+
+#[test]
+fn only_project_authority_can_create_proposals() {
+    let mut setup = setup();
+    let outsider = setup.fixture.env.new_wallet(LAMPORTS_PER_SOL);
+
+    let (result, proposal) = create_proposal(&mut setup, outsider);
+    assert_error(result, ForestLabError::Unauthorized);
+    assert!(!setup.fixture.env.exists(&proposal));
+}
+
+#[test]
+fn votes_outside_voting_window_are_rejected() {
+    let mut setup = setup();
+    let (voter, _) = staked_voter(&mut setup, 2 * LAMPORTS_PER_SOL);
+    let creator = setup.creator;
+    let (result, proposal) = create_proposal(&mut setup, creator);
+    result.unwrap();
+
+    setup.fixture.env.warp(3 * DAY);
+    let result = cast_vote(&mut setup, proposal, voter, 0, VoteWeightSource::Stake);
+    assert_error(result, ForestLabError::VotingNotActive);
+}
@@ -0,0 +1,292 @@
+mod common;
+
+use anchor_lang::{prelude::*, solana_program::system_program};
+use anchor_spl::{associated_token::get_associated_token_address, token::spl_token};
+use common::*;
+use core_project::{ForestLabError, Lockup, LockupCounter, Project};
+
+const DAY: i64 = 86400;
+const LST: u64 = LAMPORTS_PER_SOL;
+
+/// 보너스 볼트가 충전된 프로젝트와 LST 100개를 가진 사용자
+struct LockupSetup {
+    fixture: Fixture,
+    project: Pubkey,
+    lst_mint: Pubkey,
+    user: Pubkey,
+    user_tokens: Pubkey,
+}
+
+fn setup() -> LockupSetup {
+    let mut fixture = Fixture::new();
+    let lst_mint = fixture.env.create_mint(&Pubkey::new_unique(), 9);
+    let creator = fixture.env.new_wallet(10 * LAMPORTS_PER_SOL);
+    let project = fixture.register_project(&creator, "forest", 100 * LAMPORTS_PER_SOL, &lst_mint);
+
+    let creator_tokens = fixture.env.create_token_account(&lst_mint, &creator, 100 * LST);
+    fixture
+        .fund_bonus_vault(&project, &lst_mint, &creator, &creator_tokens, 100 * LST)
+        .unwrap();
+
+    let user = fixture.env.new_wallet(10 * LAMPORTS_PER_SOL);
+    let user_tokens = fixture.env.create_token_account(&lst_mint, &user, 100 * LST);
+    LockupSetup {
+        fixture,
+        project,
+        lst_mint,
+        user,
+        user_tokens,
+    }
+}
+
+fn create_lockup(setup: &mut LockupSetup, amount: u64, duration: i64) -> Pubkey {
+    let (project, lst_mint, user, user_tokens) = (setup.project, setup.lst_mint, setup.user, setup.user_tokens);
+    let (result, lockup) = setup
+        .fixture
+        .create_lockup(&project, &lst_mint, &user, &user_tokens, amount, duration);
+    result.unwrap_or_else(|err| panic!("create_lockup failed: {err:?} {:?}", setup.fixture.env.logs()));
+    lockup
+}
+
+fn release_lockup(
+    setup: &mut LockupSetup,
+    lockup: Pubkey,
+    user: Pubkey,
+    user_token_account: Pubkey,
+) -> std::result::Result<(), ProgramError> {
+    let project = setup.project;
+    setup.fixture.env.call(
+        core_project::accounts::ReleaseLockup {
+            lockup,
+            lockup_counter: pda(&[b"lockup_counter", user.as_ref(), project.as_ref()]),
+            lockup_tier_config: pda(&[b"lockup_tier_config", project.as_ref()]),
+            voting_power: pda(&[b"voting_power", user.as_ref(), project.as_ref()]),
+            user,
+            project,
+            platform: setup.fixture.platform,
+            user_token_account,
+            lockup_vault: get_associated_token_address(&lockup, &setup.lst_mint),
+            vault_authority: pda(&[b"vault_authority", project.as_ref()]),
+            bonus_vault: pda(&[b"bonus_vault", project.as_ref()]),
+            lst_mint: setup.lst_mint,
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+        },
+        core_project::instruction::ReleaseLockup {},
+        &[user],
+    )
+}
+
+fn lockup_counter(setup: &LockupSetup) -> LockupCounter {
+    setup
+        .fixture
+        .env
+        .fetch(&pda(&[b"lockup_counter", setup.user.as_ref(), setup.project.as_ref()]))
+}
+
+#[test]
+fn user_holds_several_indexed_lockups_in_one_project() {
+    let mut setup = setup();
+    let short = create_lockup(&mut setup, 10 * LST, 10 * DAY);
+    let medium = create_lockup(&mut setup, 20 * LST, 90 * DAY);
+    let long = create_lockup(&mut setup, 30 * LST, 365 * DAY);
+
+    // 각 락업은 인덱스로 구분되는 PDA와 자체 볼트를 가짐
+    for (index, (lockup, amount, bonus_bps)) in [(short, 10 * LST, 0), (medium, 20 * LST, 250), (long, 30 * LST, 1000)]
+        .into_iter()
+        .enumerate()
+    {
+        assert_eq!(lockup, lockup_address(&setup.user, &setup.project, index as u64));
+        let data: Lockup = setup.fixture.env.fetch(&lockup);
+        assert_eq!(data.index, index as u64);
+        assert_eq!(data.amount, amount);
+        assert_eq!(data.bonus_percentage, bonus_bps);
+        let vault = get_associated_token_address(&lockup, &setup.lst_mint);
+        assert_eq!(setup.fixture.env.token_balance(&vault), amount);
+    }
+
+    let counter = lockup_counter(&setup);
+    assert_eq!(counter.next_index, 3);
+    assert_eq!(counter.active_count, 3);
+    assert_eq!(setup.fixture.env.token_balance(&setup.user_tokens), 40 * LST);
+}
+
+#[test]
+fn releasing_one_lockup_keeps_the_others_and_frees_a_new_index() {
+    let mut setup = setup();
+    let short = create_lockup(&mut setup, 10 * LST, 10 * DAY);
+    let long = create_lockup(&mut setup, 30 * LST, 365 * DAY);
+
+    // 만료 전에는 해제할 수 없음
+    let (user, user_tokens) = (setup.user, setup.user_tokens);
+    let result = release_lockup(&mut setup, short, user, user_tokens);
+    assert_error(result, ForestLabError::LockupNotExpired);
+
+    setup.fixture.env.warp(10 * DAY);
+    release_lockup(&mut setup, short, user, user_tokens).unwrap();
+
+    assert_eq!(setup.fixture.env.token_balance(&setup.user_tokens), 70 * LST);
+    let released: Lockup = setup.fixture.env.fetch(&short);
+    assert!(released.is_released);
+    let remaining: Lockup = setup.fixture.env.fetch(&long);
+    assert!(!remaining.is_released);
+    assert_eq!(lockup_counter(&setup).active_count, 1);
+
+    // 해제된 락업 계정이 남아 있어도 다음 인덱스로 새 락업 생성 가능
+    let next = create_lockup(&mut setup, 5 * LST, 30 * DAY);
+    assert_eq!(next, lockup_address(&setup.user, &setup.project, 2));
+    let counter = lockup_counter(&setup);
+    assert_eq!(counter.next_index, 3);
+    assert_eq!(counter.active_count, 2);
+
+    let project: Project = setup.fixture.env.fetch(&setup.project);
+    let long_bonus = remaining.bonus_amount;
+    assert_eq!(project.bonus_committed, long_bonus);
+}
+
+#[test]
+fn another_user_cannot_release_someone_elses_lockup() {
+    let mut setup = setup();
+    let lockup = create_lockup(&mut setup, 10 * LST, 10 * DAY);
+    setup.fixture.env.warp(10 * DAY);
+
+    let (attacker, attacker_tokens) = other_user_with_lockup(&mut setup);
+    let result = release_lockup(&mut setup, lockup, attacker, attacker_tokens);
+    assert_error(result, anchor_lang::error::ErrorCode::ConstraintSeeds);
+
+    let vault = get_associated_token_address(&lockup, &setup.lst_mint);
+    assert_eq!(setup.fixture.env.token_balance(&vault), 10 * LST);
+    assert_eq!(setup.fixture.env.token_balance(&attacker_tokens), 9 * LST);
+}
+
+fn extend_lockup(
+    setup: &mut LockupSetup,
+    lockup: Pubkey,
+    user: Pubkey,
+    additional_duration: i64,
+) -> std::result::Result<(), ProgramError> {
+    let project = setup.project;
+    setup.fixture.env.call(
+        core_project::accounts::ExtendLockup {
+            lockup,
+            lockup_tier_config: pda(&[b"lockup_tier_config", project.as_ref()]),
+            voting_power: pda(&[b"voting_power", user.as_ref(), project.as_ref()]),
+            user,
+            project,
+            platform: setup.fixture.platform,
+            bonus_vault: pda(&[b"bonus_vault", project.as_ref()]),
+        },
+        core_project::instruction::ExtendLockup { additional_duration },
+        &[user],
+    )
+}
+
+fn increase_lockup(
+    setup: &mut LockupSetup,
+    lockup: Pubkey,
+    user: Pubkey,
+    user_token_account: Pubkey,
+    amount: u64,
+) -> std::result::Result<(), ProgramError> {
+    let project = setup.project;
+    setup.fixture.env.call(
+        core_project::accounts::IncreaseLockup {
+            lockup,
+            lockup_tier_config: pda(&[b"lockup_tier_config", project.as_ref()]),
+            voting_power: pda(&[b"voting_power", user.as_ref(), project.as_ref()]),
+            user,
+            project,
+            platform: setup.fixture.platform,
+            user_token_account,
+            lockup_vault: get_associated_token_address(&lockup, &setup.lst_mint),
+            bonus_vault: pda(&[b"bonus_vault", project.as_ref()]),
+            lst_mint: setup.lst_mint,
+            token_program: spl_token::ID,
+        },
+        core_project::instruction::IncreaseLockup { amount },
+        &[user],
+    )
+}
+
+/// 자신의 락업 카운터와 투표권 계정을 가진 다른 사용자
+fn other_user_with_lockup(setup: &mut LockupSetup) -> (Pubkey, Pubkey) {
+    let (project, lst_mint) = (setup.project, setup.lst_mint);
+    let other = setup.fixture.env.new_wallet(LAMPORTS_PER_SOL);
+    let other_tokens = setup.fixture.env.create_token_account(&lst_mint, &other, 10 * LST);
+    let (result, _) = setup
+        .fixture
+        .create_lockup(&project, &lst_mint, &other, &other_tokens, LST, 30 * DAY);
+    result.unwrap();
+    (other, other_tokens)
+}
+
+#[test]
+fn extend_lockup_moves_end_time_and_upgrades_bonus_tier() {
+    let mut setup = setup();
+    let lockup = create_lockup(&mut setup, 10 * LST, 60 * DAY);
+    let before: Lockup = setup.fixture.env.fetch(&lockup);
+    assert_eq!(before.bonus_percentage, 0);
+
+    // 60일 + 60일 = 120일로 90일 티어(2.5%) 적용
+    let user = setup.user;
+    extend_lockup(&mut setup, lockup, user, 60 * DAY).unwrap();
+
+    let after: Lockup = setup.fixture.env.fetch(&lockup);
+    assert_eq!(after.end_time, before.end_time + 60 * DAY);
+    assert_eq!(after.bonus_percentage, 250);
+    assert_eq!(after.amount_seconds, (10 * LST) as u128 * (120 * DAY) as u128);
+    assert_eq!(after.bonus_amount, Lockup::compute_bonus(after.amount_seconds, 250));
+    let project: Project = setup.fixture.env.fetch(&setup.project);
+    assert_eq!(project.bonus_committed, after.bonus_amount);
+
+    // 최대 락업 기간(4년)을 넘는 연장은 거부
+    let result = extend_lockup(&mut setup, lockup, user, 4 * Lockup::SECONDS_PER_YEAR);
+    assert_error(result, ForestLabError::LockupTooLong);
+}
+
+#[test]
+fn extend_lockup_rejects_expired_lockup() {
+    let mut setup = setup();
+    let lockup = create_lockup(&mut setup, 10 * LST, 10 * DAY);
+    setup.fixture.env.warp(10 * DAY);
+
+    let user = setup.user;
+    let result = extend_lockup(&mut setup, lockup, user, 90 * DAY);
+    assert_error(result, ForestLabError::LockupAlreadyExpired);
+}
+
+#[test]
+fn increase_lockup_adds_tokens_with_bonus_for_remaining_duration() {
+    let mut setup = setup();
+    let lockup = create_lockup(&mut setup, 10 * LST, 365 * DAY);
+    setup.fixture.env.warp(5 * DAY);
+
+    let (user, user_tokens) = (setup.user, setup.user_tokens);
+    increase_lockup(&mut setup, lockup, user, user_tokens, 10 * LST).unwrap();
+
+    // 추가분은 남은 360일만큼만 보너스 계산
+    let data: Lockup = setup.fixture.env.fetch(&lockup);
+    let amount_seconds = (10 * LST) as u128 * (365 * DAY) as u128 + (10 * LST) as u128 * (360 * DAY) as u128;
+    assert_eq!(data.amount, 20 * LST);
+    assert_eq!(data.amount_seconds, amount_seconds);
+    assert_eq!(data.bonus_amount, Lockup::compute_bonus(amount_seconds, 1000));
+    let vault = get_associated_token_address(&lockup, &setup.lst_mint);
+    assert_eq!(setup.fixture.env.token_balance(&vault), 20 * LST);
+    assert_eq!(setup.fixture.env.token_balance(&user_tokens), 80 * LST);
+}
+
+#[test]
+fn another_user_cannot_extend_or_increase_someone_elses_lockup() {
+    let mut setup = setup();
+    let lockup = create_lockup(&mut setup, 10 * LST, 90 * DAY);
+    let (other, other_tokens) = other_user_with_lockup(&mut setup);
+
+    let result = extend_lockup(&mut setup, lockup, other, 90 * DAY);
+    assert_error(result, anchor_lang::error::ErrorCode::ConstraintSeeds);
+    let result = increase_lockup(&mut setup, lockup, other, other_tokens, LST);
+    assert_error(result, anchor_lang::error::ErrorCode::ConstraintSeeds);
+
+    let data: Lockup = setup.fixture.env.fetch(&lockup);
+    assert_eq!(data.amount, 10 * LST);
+    assert_eq!(data.end_time - data.start_time, 90 * DAY);
+}
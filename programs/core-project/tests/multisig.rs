@@ -0,0 +1,257 @@
+mod common;
+
+use anchor_lang::{
+    prelude::*,
+    solana_program::{system_program, sysvar},
+};
+use common::*;
+use core_project::{ForestLabError, MultisigConfig, MultisigProposal, Project, ProjectAction};
+
+/// 프로젝트 관리자와 서명자 3명 (임계값 2)
+struct MultisigSetup {
+    fixture: Fixture,
+    project: Pubkey,
+    creator: Pubkey,
+    signers: [Pubkey; 3],
+    multisig_config: Pubkey,
+}
+
+fn setup() -> MultisigSetup {
+    let mut fixture = Fixture::new();
+    let lst_mint = fixture.env.create_mint(&Pubkey::new_unique(), 9);
+    let creator = fixture.env.new_wallet(10 * LAMPORTS_PER_SOL);
+    let project = fixture.register_project(&creator, "forest", 100 * LAMPORTS_PER_SOL, &lst_mint);
+    let signers = [
+        fixture.env.new_wallet(LAMPORTS_PER_SOL),
+        fixture.env.new_wallet(LAMPORTS_PER_SOL),
+        fixture.env.new_wallet(LAMPORTS_PER_SOL),
+    ];
+    MultisigSetup {
+        fixture,
+        project,
+        creator,
+        signers,
+        multisig_config: pda(&[b"multisig_config", project.as_ref()]),
+    }
+}
+
+fn setup_multisig(setup: &mut MultisigSetup, authority: Pubkey, threshold: u8) -> std::result::Result<(), ProgramError> {
+    let signers = setup.signers;
+    setup.fixture.setup_multisig(&setup.project, &authority, &signers, threshold)
+}
+
+/// 서명자 3명, 임계값 2로 멀티시그를 활성화한 환경
+fn multisig_setup() -> MultisigSetup {
+    let mut setup = setup();
+    let creator = setup.creator;
+    setup_multisig(&mut setup, creator, 2).unwrap();
+    setup
+}
+
+fn update_project_fee(setup: &mut MultisigSetup, manager_fee_percentage: u16) -> std::result::Result<(), ProgramError> {
+    let creator = setup.creator;
+    setup.fixture.env.call(
+        core_project::accounts::UpdateProjectSettings {
+            project: setup.project,
+            platform: setup.fixture.platform,
+            authority: creator,
+            multisig_config: setup.multisig_config,
+            system_program: system_program::ID,
+        },
+        core_project::instruction::UpdateProjectFee {
+            manager_fee_percentage,
+            payout_wallet: None,
+        },
+        &[creator],
+    )
+}
+
+fn create_proposal(
+    setup: &mut MultisigSetup,
+    proposer: Pubkey,
+    action: ProjectAction,
+) -> (std::result::Result<(), ProgramError>, Pubkey) {
+    let project = setup.project;
+    setup.fixture.create_proposal(&project, &proposer, action)
+}
+
+fn approve_proposal(setup: &mut MultisigSetup, proposal: Pubkey, signer: Pubkey) -> std::result::Result<(), ProgramError> {
+    let project = setup.project;
+    setup.fixture.approve_proposal(&project, &proposal, &signer)
+}
+
+fn execute_proposal(setup: &mut MultisigSetup, proposal: Pubkey, executor: Pubkey) -> std::result::Result<(), ProgramError> {
+    setup.fixture.env.call(
+        core_project::accounts::ExecuteProposal {
+            project: setup.project,
+            platform: setup.fixture.platform,
+            multisig_config: setup.multisig_config,
+            proposal,
+            executor,
+            restake_config: None,
+            vault_authority: None,
+            restake_vault: None,
+            restake_position: None,
+            restake_destination: None,
+            token_program: None,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        core_project::instruction::ExecuteProposal {},
+        &[executor],
+    )
+}
+
+fn update_fee_action(manager_fee_percentage: u16) -> ProjectAction {
+    ProjectAction::UpdateFee {
+        manager_fee_percentage,
+        payout_wallet: None,
+    }
+}
+
+#[test]
+fn project_authority_updates_fee_directly_without_multisig() {
+    let mut setup = setup();
+    update_project_fee(&mut setup, 500).unwrap();
+
+    let project: Project = setup.fixture.env.fetch(&setup.project);
+    assert_eq!(project.manager_fee_percentage, 500);
+}
+
+#[test]
+fn active_multisig_blocks_direct_fee_update() {
+    let mut setup = multisig_setup();
+
+    let result = update_project_fee(&mut setup, 500);
+    assert_error(result, ForestLabError::MultisigRequired);
+    let project: Project = setup.fixture.env.fetch(&setup.project);
+    assert_eq!(project.manager_fee_percentage, 250);
+}
+
+#[test]
+fn fee_update_proposal_executes_only_after_threshold_approvals() {
+    let mut setup = multisig_setup();
+    let [first, second, _] = setup.signers;
+
+    // 제안자의 승인 1개로는 임계값 2에 미달
+    let (result, proposal) = create_proposal(&mut setup, first, update_fee_action(500));
+    result.unwrap();
+    let result = execute_proposal(&mut setup, proposal, first);
+    assert_error(result, ForestLabError::ThresholdNotMet);
+
+    approve_proposal(&mut setup, proposal, second).unwrap();
+    execute_proposal(&mut setup, proposal, first).unwrap();
+
+    let project: Project = setup.fixture.env.fetch(&setup.project);
+    assert_eq!(project.manager_fee_percentage, 500);
+    let proposal_data: MultisigProposal = setup.fixture.env.fetch(&proposal);
+    assert!(proposal_data.executed);
+
+    // 실행된 제안은 다시 실행할 수 없음
+    let result = execute_proposal(&mut setup, proposal, first);
+    assert_error(result, ForestLabError::ProposalAlreadyExecuted);
+}
+
+#[test]
+fn non_signers_cannot_propose_or_approve() {
+    let mut setup = multisig_setup();
+    let outsider = setup.fixture.env.new_wallet(LAMPORTS_PER_SOL);
+
+    // 프로젝트 관리자라도 서명자가 아니면 제안할 수 없음
+    let creator = setup.creator;
+    let (result, _) = create_proposal(&mut setup, creator, update_fee_action(500));
+    assert_error(result, ForestLabError::NotMultisigSigner);
+
+    let signer = setup.signers[0];
+    let (result, proposal) = create_proposal(&mut setup, signer, update_fee_action(500));
+    result.unwrap();
+    let result = approve_proposal(&mut setup, proposal, outsider);
+    assert_error(result, ForestLabError::NotMultisigSigner);
+    let result = execute_proposal(&mut setup, proposal, outsider);
+    assert_error(result, ForestLabError::ThresholdNotMet);
+}
+
+#[test]
+fn only_project_authority_can_enable_multisig() {
+    let mut setup = setup();
+    let outsider = setup.fixture.env.new_wallet(LAMPORTS_PER_SOL);
+
+    let result = setup_multisig(&mut setup, outsider, 2);
+    assert_error(result, ForestLabError::Unauthorized);
+    assert!(!setup.fixture.env.exists(&setup.multisig_config));
+}
+
+/// 서명자 두 명의 승인으로 제안을 생성하고 실행
+fn pass_proposal(setup: &mut MultisigSetup, action: ProjectAction) -> Pubkey {
+    let [first, second, _] = setup.signers;
+    let (result, proposal) = create_proposal(setup, first, action);
+    result.unwrap();
+    approve_proposal(setup, proposal, second).unwrap();
+    execute_proposal(setup, proposal, first).unwrap();
+    proposal
+}
+
+#[test]
+fn signer_rotation_and_threshold_change_apply_through_proposals() {
+    let mut setup = multisig_setup();
+    let new_signer = setup.fixture.env.new_wallet(LAMPORTS_PER_SOL);
+    let [first, second, third] = setup.signers;
+
+    pass_proposal(&mut setup, ProjectAction::AddSigner { signer: new_signer });
+    pass_proposal(&mut setup, ProjectAction::RemoveSigner { signer: third });
+    pass_proposal(&mut setup, ProjectAction::ChangeThreshold { threshold: 3 });
+
+    let multisig: MultisigConfig = setup.fixture.env.fetch(&setup.multisig_config);
+    assert_eq!(multisig.signers, vec![first, second, new_signer]);
+    assert_eq!(multisig.threshold, 3);
+
+    // 새 서명자는 승인할 수 있고 제거된 서명자는 승인할 수 없음
+    let (result, proposal) = create_proposal(&mut setup, first, update_fee_action(500));
+    result.unwrap();
+    approve_proposal(&mut setup, proposal, second).unwrap();
+    let result = approve_proposal(&mut setup, proposal, third);
+    assert_error(result, ForestLabError::NotMultisigSigner);
+    let result = execute_proposal(&mut setup, proposal, first);
+    assert_error(result, ForestLabError::ThresholdNotMet);
+
+    approve_proposal(&mut setup, proposal, new_signer).unwrap();
+    execute_proposal(&mut setup, proposal, new_signer).unwrap();
+    let project: Project = setup.fixture.env.fetch(&setup.project);
+    assert_eq!(project.manager_fee_percentage, 500);
+}
+
+#[test]
+fn approval_from_removed_signer_no_longer_counts() {
+    let mut setup = multisig_setup();
+    let [first, _, third] = setup.signers;
+
+    // 세 번째 서명자가 승인해 임계값을 채운 제안
+    let (result, pending) = create_proposal(&mut setup, first, update_fee_action(500));
+    result.unwrap();
+    approve_proposal(&mut setup, pending, third).unwrap();
+
+    // 실행 전에 세 번째 서명자를 제거하면 그 승인은 더 이상 인정되지 않음
+    pass_proposal(&mut setup, ProjectAction::RemoveSigner { signer: third });
+    let result = execute_proposal(&mut setup, pending, first);
+    assert_error(result, ForestLabError::ThresholdNotMet);
+    let project: Project = setup.fixture.env.fetch(&setup.project);
+    assert_eq!(project.manager_fee_percentage, 250);
+}
+
+#[test]
+fn signer_changes_that_would_break_the_multisig_are_rejected() {
+    let mut setup = multisig_setup();
+    let first = setup.signers[0];
+    let outsider = setup.fixture.env.new_wallet(LAMPORTS_PER_SOL);
+
+    let (result, _) = create_proposal(&mut setup, first, ProjectAction::ChangeThreshold { threshold: 4 });
+    assert_error(result, ForestLabError::ThresholdTooHigh);
+    let (result, _) = create_proposal(&mut setup, first, ProjectAction::RemoveSigner { signer: outsider });
+    assert_error(result, ForestLabError::NotMultisigSigner);
+    let (result, _) = create_proposal(&mut setup, first, ProjectAction::AddSigner { signer: first });
+    assert_error(result, ForestLabError::DuplicateSigner);
+
+    // 서명자가 아닌 주소는 서명자 변경을 제안할 수 없음
+    let (result, _) = create_proposal(&mut setup, outsider, ProjectAction::AddSigner { signer: outsider });
+    assert_error(result, ForestLabError::NotMultisigSigner);
+}
@@ -0,0 +1,373 @@
+mod common;
+
+use anchor_lang::{
+    prelude::*,
+    solana_program::{system_program, sysvar},
+};
+use anchor_spl::token::spl_token;
+use common::*;
+use core_project::{ForestLabError, ProjectAction, RestakePosition, RewardsInfo, SPL_STAKE_POOL_PROGRAM_ID};
+
+const EPOCH: u64 = 100;
+const TOTAL_REWARDS: u64 = 10 * LAMPORTS_PER_SOL;
+/// 풀 수수료 0.3%
+const POOL_FEE_BPS: u16 = 30;
+/// 수수료 1%를 뺀 9.9 LST 중 리스테이킹 몫 50%
+const RESTAKE_AMOUNT: u64 = 4_950_000_000;
+
+/// 리스테이킹 테스트 환경 (소스 LST -> 타겟 LST 상수 곱 풀, 타겟 풀 교환 비율 1.1 SOL = 1 LST)
+struct RestakeSetup {
+    fixture: Fixture,
+    project: Pubkey,
+    creator: Pubkey,
+    target_pool: StakePoolKeys,
+    swap_pool: SwapPoolKeys,
+}
+
+fn setup() -> RestakeSetup {
+    let mut fixture = Fixture::new();
+    let source_pool = fixture.env.create_stake_pool(2_000 * LAMPORTS_PER_SOL, 1_000 * LAMPORTS_PER_SOL);
+    let target_pool = fixture.env.create_stake_pool(1_100 * LAMPORTS_PER_SOL, 1_000 * LAMPORTS_PER_SOL);
+    let creator = fixture.env.new_wallet(10 * LAMPORTS_PER_SOL);
+    let project = fixture.register_project(&creator, "forest", 100 * LAMPORTS_PER_SOL, &source_pool.mint);
+
+    let staker = fixture.env.new_wallet(10 * LAMPORTS_PER_SOL);
+    let staker_lst = fixture.env.create_token_account(&source_pool.mint, &staker, 0);
+    fixture
+        .stake_sol(&project, &source_pool, &staker, &staker_lst, 2 * LAMPORTS_PER_SOL)
+        .unwrap();
+
+    // 프로젝트 몫 스왑 없이 리스테이킹 50%
+    let usdc_mint = fixture.env.create_mint(&Pubkey::new_unique(), 6);
+    fixture.configure_swap(&usdc_mint, 0);
+    let mut setup = RestakeSetup {
+        swap_pool: fixture.env.create_swap_pool(
+            &source_pool.mint,
+            1_000 * LAMPORTS_PER_SOL,
+            &target_pool.mint,
+            1_000 * LAMPORTS_PER_SOL,
+            POOL_FEE_BPS,
+        ),
+        fixture,
+        project,
+        creator,
+        target_pool,
+    };
+    let creator = setup.creator;
+    setup_restaking(&mut setup, creator).unwrap();
+
+    let reward_source = setup
+        .fixture
+        .env
+        .create_token_account(&source_pool.mint, &setup.fixture.admin_wallet, TOTAL_REWARDS);
+    setup
+        .fixture
+        .process_epoch_rewards(&project, &source_pool.mint, &reward_source, EPOCH, TOTAL_REWARDS)
+        .unwrap();
+    setup
+}
+
+fn setup_restaking(setup: &mut RestakeSetup, authority: Pubkey) -> std::result::Result<(), ProgramError> {
+    let project = setup.project;
+    setup.fixture.env.call(
+        core_project::accounts::SetupRestaking {
+            project,
+            platform: setup.fixture.platform,
+            authority,
+            restake_config: pda(&[b"restake_config", project.as_ref()]),
+            multisig_config: pda(&[b"multisig_config", project.as_ref()]),
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        core_project::instruction::SetupRestaking {
+            target_lst_mint: setup.target_pool.mint,
+            restake_percentage: 5_000,
+        },
+        &[authority],
+    )
+}
+
+fn restake_vault(setup: &RestakeSetup) -> Pubkey {
+    pda(&[b"restake_vault", setup.project.as_ref(), setup.target_pool.mint.as_ref()])
+}
+
+fn restake_position(setup: &RestakeSetup) -> Pubkey {
+    pda(&[b"restake_position", setup.project.as_ref(), setup.target_pool.mint.as_ref()])
+}
+
+fn execute_restake(setup: &mut RestakeSetup, authority: Pubkey, min_out: u64) -> std::result::Result<(), ProgramError> {
+    let project = setup.project;
+    let accounts = core_project::accounts::ExecuteRestake {
+        project,
+        authority,
+        platform: setup.fixture.platform,
+        roles: Some(setup.fixture.roles),
+        crankers: None,
+        restake_config: pda(&[b"restake_config", project.as_ref()]),
+        rewards_info: pda(&[b"rewards_info", project.as_ref(), &EPOCH.to_le_bytes()]),
+        vault_authority: pda(&[b"vault_authority", project.as_ref()]),
+        project_share_vault: pda(&[b"project_share_vault", project.as_ref()]),
+        restake_vault: restake_vault(setup),
+        target_lst_mint: setup.target_pool.mint,
+        restake_position: restake_position(setup),
+        swap_config: pda(&[b"swap_config"]),
+        swap_program: SWAP_PROGRAM_ID,
+        target_stake_pool: setup.target_pool.pool,
+        target_stake_pool_program: SPL_STAKE_POOL_PROGRAM_ID,
+        token_program: spl_token::ID,
+        system_program: system_program::ID,
+        rent: sysvar::rent::ID,
+    };
+    setup.fixture.env.call_with_remaining(
+        accounts,
+        core_project::instruction::ExecuteRestake { epoch: EPOCH, min_out },
+        setup.swap_pool.remaining_accounts(),
+        &[authority],
+    )
+}
+
+/// 보상 운영자가 리스테이킹을 실행하고 받은 타겟 LST 수량 반환
+fn restaked_setup() -> (RestakeSetup, u64) {
+    let mut setup = setup();
+    let target_amount = expected_target_out(&setup, RESTAKE_AMOUNT);
+    let operator = setup.fixture.admin_wallet;
+    execute_restake(&mut setup, operator, target_amount)
+        .unwrap_or_else(|err| panic!("execute_restake failed: {err:?} {:?}", setup.fixture.env.logs()));
+    (setup, target_amount)
+}
+
+fn withdraw_restake(
+    setup: &mut RestakeSetup,
+    authority: Pubkey,
+    destination: Pubkey,
+    amount: u64,
+) -> std::result::Result<(), ProgramError> {
+    let project = setup.project;
+    setup.fixture.env.call(
+        core_project::accounts::WithdrawRestake {
+            project,
+            authority,
+            platform: setup.fixture.platform,
+            vault_authority: pda(&[b"vault_authority", project.as_ref()]),
+            restake_vault: restake_vault(setup),
+            restake_position: restake_position(setup),
+            target_lst_mint: setup.target_pool.mint,
+            destination,
+            multisig_config: pda(&[b"multisig_config", project.as_ref()]),
+            token_program: spl_token::ID,
+        },
+        core_project::instruction::WithdrawRestake { amount },
+        &[authority],
+    )
+}
+
+/// 상수 곱 풀에서 기대되는 타겟 LST 출력량
+fn expected_target_out(setup: &RestakeSetup, amount_in: u64) -> u64 {
+    let reserve_in = setup.fixture.env.token_balance(&setup.swap_pool.reserve_a) as u128;
+    let reserve_out = setup.fixture.env.token_balance(&setup.swap_pool.reserve_b) as u128;
+    let amount_in = amount_in as u128 * (10_000 - POOL_FEE_BPS as u128) / 10_000;
+    (reserve_out * amount_in / (reserve_in + amount_in)) as u64
+}
+
+#[test]
+fn execute_restake_swaps_restake_share_into_target_lst() {
+    let mut setup = setup();
+    let share_vault = pda(&[b"project_share_vault", setup.project.as_ref()]);
+    assert_eq!(setup.fixture.env.token_balance(&share_vault), RESTAKE_AMOUNT);
+
+    let target_amount = expected_target_out(&setup, RESTAKE_AMOUNT);
+    let operator = setup.fixture.admin_wallet;
+    execute_restake(&mut setup, operator, target_amount).unwrap();
+
+    assert_eq!(setup.fixture.env.token_balance(&share_vault), 0);
+    assert_eq!(setup.fixture.env.token_balance(&restake_vault(&setup)), target_amount);
+
+    // 타겟 풀 교환 비율(1.1 SOL = 1 LST)로 원금 평가
+    let position: RestakePosition = setup.fixture.env.fetch(&restake_position(&setup));
+    assert_eq!(position.project, setup.project);
+    assert_eq!(position.source_lst_restaked, RESTAKE_AMOUNT);
+    assert_eq!(position.target_lst_received, target_amount);
+    assert_eq!(position.principal_lamports, target_amount * 11 / 10);
+    assert_eq!(position.last_restaked_epoch, EPOCH);
+    let rewards_info: RewardsInfo = setup
+        .fixture
+        .env
+        .fetch(&pda(&[b"rewards_info", setup.project.as_ref(), &EPOCH.to_le_bytes()]));
+    assert!(rewards_info.restaked);
+
+    // 같은 에포크는 다시 리스테이킹할 수 없음
+    let result = execute_restake(&mut setup, operator, 1);
+    assert_error(result, ForestLabError::AlreadyRestaked);
+}
+
+#[test]
+fn execute_restake_rejects_caller_without_operator_role() {
+    let mut setup = setup();
+    let outsider = setup.fixture.env.new_wallet(LAMPORTS_PER_SOL);
+
+    let result = execute_restake(&mut setup, outsider, 1);
+    assert_error(result, ForestLabError::Unauthorized);
+    assert!(!setup.fixture.env.exists(&restake_position(&setup)));
+}
+
+#[test]
+fn execute_restake_fails_when_pool_output_is_below_min_out() {
+    let mut setup = setup();
+    let target_amount = expected_target_out(&setup, RESTAKE_AMOUNT);
+    let operator = setup.fixture.admin_wallet;
+
+    let result = execute_restake(&mut setup, operator, target_amount + 1);
+    assert_eq!(result, Err(ProgramError::Custom(SWAP_EXCEEDED_SLIPPAGE)));
+    let share_vault = pda(&[b"project_share_vault", setup.project.as_ref()]);
+    assert_eq!(setup.fixture.env.token_balance(&share_vault), RESTAKE_AMOUNT);
+}
+
+#[test]
+fn setup_restaking_rejects_non_authority() {
+    let mut setup = setup();
+    let outsider = setup.fixture.env.new_wallet(LAMPORTS_PER_SOL);
+
+    let result = setup_restaking(&mut setup, outsider);
+    assert_error(result, ForestLabError::Unauthorized);
+}
+
+#[test]
+fn withdraw_restake_sends_target_lst_to_payout_wallet() {
+    let (mut setup, target_amount) = restaked_setup();
+    let creator = setup.creator;
+    let destination = setup.fixture.env.create_token_account(&setup.target_pool.mint, &creator, 0);
+
+    withdraw_restake(&mut setup, creator, destination, target_amount / 2).unwrap();
+
+    // 출금 비율만큼 원금 평가가 줄어듦
+    assert_eq!(setup.fixture.env.token_balance(&destination), target_amount / 2);
+    assert_eq!(
+        setup.fixture.env.token_balance(&restake_vault(&setup)),
+        target_amount - target_amount / 2
+    );
+    let position: RestakePosition = setup.fixture.env.fetch(&restake_position(&setup));
+    let principal = target_amount * 11 / 10;
+    assert_eq!(
+        position.principal_lamports,
+        (principal as u128 * (target_amount - target_amount / 2) as u128 / target_amount as u128) as u64
+    );
+}
+
+#[test]
+fn withdraw_restake_rejects_non_authority_and_foreign_destination() {
+    let (mut setup, target_amount) = restaked_setup();
+    let outsider = setup.fixture.env.new_wallet(LAMPORTS_PER_SOL);
+    let outsider_account = setup.fixture.env.create_token_account(&setup.target_pool.mint, &outsider, 0);
+
+    let result = withdraw_restake(&mut setup, outsider, outsider_account, target_amount);
+    assert_error(result, anchor_lang::error::ErrorCode::ConstraintTokenOwner);
+
+    // 지불 지갑 계정을 지정해도 프로젝트 관리자가 아니면 출금할 수 없음
+    let creator = setup.creator;
+    let destination = setup.fixture.env.create_token_account(&setup.target_pool.mint, &creator, 0);
+    let result = withdraw_restake(&mut setup, outsider, destination, target_amount);
+    assert_error(result, ForestLabError::Unauthorized);
+    assert_eq!(setup.fixture.env.token_balance(&restake_vault(&setup)), target_amount);
+}
+
+/// WithdrawRestake 제안을 리스테이킹 출금 계정과 함께 실행
+fn execute_withdraw_proposal(
+    setup: &mut RestakeSetup,
+    proposal: Pubkey,
+    executor: Pubkey,
+    destination: Pubkey,
+) -> std::result::Result<(), ProgramError> {
+    let project = setup.project;
+    setup.fixture.env.call(
+        core_project::accounts::ExecuteProposal {
+            project,
+            platform: setup.fixture.platform,
+            multisig_config: pda(&[b"multisig_config", project.as_ref()]),
+            proposal,
+            executor,
+            restake_config: None,
+            vault_authority: Some(pda(&[b"vault_authority", project.as_ref()])),
+            restake_vault: Some(restake_vault(setup)),
+            restake_position: Some(restake_position(setup)),
+            restake_destination: Some(destination),
+            token_program: Some(spl_token::ID),
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        core_project::instruction::ExecuteProposal {},
+        &[executor],
+    )
+}
+
+#[test]
+fn multisig_project_withdraws_restake_through_proposal() {
+    let (mut setup, target_amount) = restaked_setup();
+    let (project, creator) = (setup.project, setup.creator);
+    let signers = [
+        setup.fixture.env.new_wallet(LAMPORTS_PER_SOL),
+        setup.fixture.env.new_wallet(LAMPORTS_PER_SOL),
+    ];
+    setup.fixture.setup_multisig(&project, &creator, &signers, 2).unwrap();
+    let destination = setup.fixture.env.create_token_account(&setup.target_pool.mint, &creator, 0);
+
+    // 멀티시그 활성화 후에는 직접 출금할 수 없음
+    let result = withdraw_restake(&mut setup, creator, destination, target_amount);
+    assert_error(result, ForestLabError::MultisigRequired);
+
+    let action = ProjectAction::WithdrawRestake {
+        target_mint: setup.target_pool.mint,
+        amount: target_amount,
+    };
+    let (result, proposal) = setup.fixture.create_proposal(&project, &signers[0], action);
+    result.unwrap();
+    let result = execute_withdraw_proposal(&mut setup, proposal, signers[0], destination);
+    assert_error(result, ForestLabError::ThresholdNotMet);
+
+    setup.fixture.approve_proposal(&project, &proposal, &signers[1]).unwrap();
+    execute_withdraw_proposal(&mut setup, proposal, signers[0], destination)
+        .unwrap_or_else(|err| panic!("execute_proposal failed: {err:?} {:?}", setup.fixture.env.logs()));
+
+    assert_eq!(setup.fixture.env.token_balance(&destination), target_amount);
+    assert_eq!(setup.fixture.env.token_balance(&restake_vault(&setup)), 0);
+    let position: RestakePosition = setup.fixture.env.fetch(&restake_position(&setup));
+    assert_eq!(position.principal_lamports, 0);
+}
+
+#[test]
+fn withdraw_restake_proposal_requires_restake_accounts() {
+    let (mut setup, target_amount) = restaked_setup();
+    let (project, creator) = (setup.project, setup.creator);
+    let signers = [
+        setup.fixture.env.new_wallet(LAMPORTS_PER_SOL),
+        setup.fixture.env.new_wallet(LAMPORTS_PER_SOL),
+    ];
+    setup.fixture.setup_multisig(&project, &creator, &signers, 1).unwrap();
+
+    let action = ProjectAction::WithdrawRestake {
+        target_mint: setup.target_pool.mint,
+        amount: target_amount,
+    };
+    let (result, proposal) = setup.fixture.create_proposal(&project, &signers[0], action);
+    result.unwrap();
+    let result = setup.fixture.env.call(
+        core_project::accounts::ExecuteProposal {
+            project,
+            platform: setup.fixture.platform,
+            multisig_config: pda(&[b"multisig_config", project.as_ref()]),
+            proposal,
+            executor: signers[0],
+            restake_config: None,
+            vault_authority: None,
+            restake_vault: None,
+            restake_position: None,
+            restake_destination: None,
+            token_program: None,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        core_project::instruction::ExecuteProposal {},
+        &[signers[0]],
+    );
+    assert_error(result, ForestLabError::MissingRestakeAccounts);
+    assert_eq!(setup.fixture.env.token_balance(&restake_vault(&setup)), target_amount);
+}
@@ -0,0 +1,291 @@
+mod common;
+
+use anchor_lang::{
+    prelude::*,
+    solana_program::{system_program, sysvar},
+};
+use anchor_spl::token::spl_token;
+use common::*;
+use core_project::{ForestLabError, Platform, RewardsInfo};
+
+const EPOCH: u64 = 100;
+const TOTAL_REWARDS: u64 = 10 * LAMPORTS_PER_SOL;
+const USDC: u64 = 1_000_000;
+/// 풀 수수료 0.3%
+const POOL_FEE_BPS: u16 = 30;
+
+/// 보상 스왑 테스트 환경 (스테이커 1명, 프로젝트 몫 50%, LST/USDC 상수 곱 풀)
+struct RewardsSetup {
+    fixture: Fixture,
+    project: Pubkey,
+    creator: Pubkey,
+    lst_mint: Pubkey,
+    usdc_mint: Pubkey,
+    swap_pool: SwapPoolKeys,
+    reward_source: Pubkey,
+}
+
+fn setup() -> RewardsSetup {
+    let mut fixture = Fixture::new();
+    let pool = fixture.env.create_stake_pool(2_000 * LAMPORTS_PER_SOL, 1_000 * LAMPORTS_PER_SOL);
+    let creator = fixture.env.new_wallet(10 * LAMPORTS_PER_SOL);
+    let project = fixture.register_project(&creator, "forest", 100 * LAMPORTS_PER_SOL, &pool.mint);
+
+    let staker = fixture.env.new_wallet(10 * LAMPORTS_PER_SOL);
+    let staker_lst = fixture.env.create_token_account(&pool.mint, &staker, 0);
+    fixture
+        .stake_sol(&project, &pool, &staker, &staker_lst, 2 * LAMPORTS_PER_SOL)
+        .unwrap();
+
+    let usdc_mint = fixture.env.create_mint(&Pubkey::new_unique(), 6);
+    fixture.configure_swap(&usdc_mint, 5_000);
+
+    // 1 LST = 2 USDC 상수 곱 풀
+    let swap_pool = fixture
+        .env
+        .create_swap_pool(&pool.mint, 1_000 * LAMPORTS_PER_SOL, &usdc_mint, 2_000 * USDC, POOL_FEE_BPS);
+    let reward_source = fixture
+        .env
+        .create_token_account(&pool.mint, &fixture.admin_wallet, TOTAL_REWARDS);
+
+    RewardsSetup {
+        fixture,
+        project,
+        creator,
+        lst_mint: pool.mint,
+        usdc_mint,
+        swap_pool,
+        reward_source,
+    }
+}
+
+/// 보상 운영자(관리자 지갑)가 에포크 보상을 처리
+fn process_epoch_rewards(setup: &mut RewardsSetup) {
+    let (project, lst_mint, reward_source) = (setup.project, setup.lst_mint, setup.reward_source);
+    setup
+        .fixture
+        .process_epoch_rewards(&project, &lst_mint, &reward_source, EPOCH, TOTAL_REWARDS)
+        .unwrap_or_else(|err| panic!("process_epoch_rewards failed: {err:?} {:?}", setup.fixture.env.logs()));
+}
+
+fn rewards_info_key(project: &Pubkey) -> Pubkey {
+    pda(&[b"rewards_info", project.as_ref(), &EPOCH.to_le_bytes()])
+}
+
+/// 프로젝트 지급 계정과 수수료 계정을 만들고 스왑 실행
+fn swap_and_distribute(
+    setup: &mut RewardsSetup,
+    authority: Pubkey,
+    min_out: u64,
+) -> (std::result::Result<(), ProgramError>, Pubkey, Pubkey) {
+    let project = setup.project;
+    let payout_account = setup.fixture.env.create_token_account(&setup.usdc_mint, &setup.creator, 0);
+    let fee_account = setup
+        .fixture
+        .env
+        .create_token_account(&setup.usdc_mint, &setup.fixture.admin_wallet, 0);
+    let result = setup.fixture.env.call_with_remaining(
+        core_project::accounts::SwapAndDistributeRewards {
+            project,
+            authority,
+            platform: setup.fixture.platform,
+            roles: Some(setup.fixture.roles),
+            rewards_info: rewards_info_key(&project),
+            swap_config: pda(&[b"swap_config"]),
+            swap_program: SWAP_PROGRAM_ID,
+            vault_authority: pda(&[b"vault_authority", project.as_ref()]),
+            project_share_vault: pda(&[b"project_share_vault", project.as_ref()]),
+            usdc_vault: pda(&[b"usdc_vault", project.as_ref()]),
+            usdc_mint: setup.usdc_mint,
+            payout_account,
+            project_fee_account: fee_account,
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        core_project::instruction::SwapAndDistributeRewards {
+            _epoch: EPOCH,
+            min_out,
+        },
+        setup.swap_pool.remaining_accounts(),
+        &[authority],
+    );
+    (result, payout_account, fee_account)
+}
+
+/// 상수 곱 풀에서 기대되는 USDC 출력량
+fn expected_usdc_out(setup: &RewardsSetup, amount_in: u64) -> u64 {
+    let reserve_in = setup.fixture.env.token_balance(&setup.swap_pool.reserve_a) as u128;
+    let reserve_out = setup.fixture.env.token_balance(&setup.swap_pool.reserve_b) as u128;
+    let amount_in = amount_in as u128 * (10_000 - POOL_FEE_BPS as u128) / 10_000;
+    (reserve_out * amount_in / (reserve_in + amount_in)) as u64
+}
+
+#[test]
+fn swap_and_distribute_swaps_project_share_through_constant_product_pool() {
+    let mut setup = setup();
+    process_epoch_rewards(&mut setup);
+
+    // 수수료 1%를 뺀 9.9 LST 중 절반이 프로젝트 몫 볼트에 적립
+    let project = setup.project;
+    let share_vault = pda(&[b"project_share_vault", project.as_ref()]);
+    let swap_amount = 4_950_000_000;
+    assert_eq!(setup.fixture.env.token_balance(&share_vault), swap_amount);
+
+    let expected_out = expected_usdc_out(&setup, swap_amount);
+    let operator = setup.fixture.admin_wallet;
+    let (result, payout_account, fee_account) = swap_and_distribute(&mut setup, operator, expected_out);
+    result.unwrap_or_else(|err| panic!("swap failed: {err:?} {:?}", setup.fixture.env.logs()));
+
+    // 볼트의 LST가 풀로 들어가고 풀의 USDC가 프로젝트 수수료 2.5%를 빼고 지급
+    let project_fee = expected_out * 250 / 10_000;
+    assert_eq!(setup.fixture.env.token_balance(&share_vault), 0);
+    assert_eq!(
+        setup.fixture.env.token_balance(&setup.swap_pool.reserve_a),
+        1_000 * LAMPORTS_PER_SOL + swap_amount
+    );
+    assert_eq!(
+        setup.fixture.env.token_balance(&setup.swap_pool.reserve_b),
+        2_000 * USDC - expected_out
+    );
+    assert_eq!(setup.fixture.env.token_balance(&payout_account), expected_out - project_fee);
+    assert_eq!(setup.fixture.env.token_balance(&fee_account), project_fee);
+
+    let rewards_info: RewardsInfo = setup.fixture.env.fetch(&rewards_info_key(&project));
+    assert!(rewards_info.processed);
+    assert_eq!(rewards_info.swapped_amount, expected_out);
+    assert_eq!(rewards_info.project_fee, project_fee);
+    assert_eq!(rewards_info.project_amount, expected_out - project_fee);
+}
+
+#[test]
+fn swap_and_distribute_fails_when_pool_output_is_below_min_out() {
+    let mut setup = setup();
+    process_epoch_rewards(&mut setup);
+
+    let project = setup.project;
+    let share_vault = pda(&[b"project_share_vault", project.as_ref()]);
+    let expected_out = expected_usdc_out(&setup, 4_950_000_000);
+    let operator = setup.fixture.admin_wallet;
+    let (result, payout_account, _) = swap_and_distribute(&mut setup, operator, expected_out + 1);
+
+    // 풀이 슬리피지 오류로 거부하고 모든 변경이 되돌려짐
+    assert_eq!(result, Err(ProgramError::Custom(SWAP_EXCEEDED_SLIPPAGE)));
+    assert_eq!(setup.fixture.env.token_balance(&share_vault), 4_950_000_000);
+    assert_eq!(setup.fixture.env.token_balance(&setup.swap_pool.reserve_b), 2_000 * USDC);
+    assert_eq!(setup.fixture.env.token_balance(&payout_account), 0);
+    let rewards_info: RewardsInfo = setup.fixture.env.fetch(&rewards_info_key(&project));
+    assert!(!rewards_info.processed);
+
+    // 허용 가능한 min_out으로 다시 시도하면 성공
+    let (result, _, _) = swap_and_distribute(&mut setup, operator, expected_out);
+    result.unwrap();
+}
+
+#[test]
+fn swap_and_distribute_rejects_second_swap_for_same_epoch() {
+    let mut setup = setup();
+    process_epoch_rewards(&mut setup);
+
+    let operator = setup.fixture.admin_wallet;
+    let (result, _, _) = swap_and_distribute(&mut setup, operator, 1);
+    result.unwrap();
+
+    let (result, _, _) = swap_and_distribute(&mut setup, operator, 1);
+    assert_error(result, ForestLabError::AlreadyProcessed);
+}
+
+#[test]
+fn swap_and_distribute_rejects_caller_without_reward_operator_role() {
+    let mut setup = setup();
+    process_epoch_rewards(&mut setup);
+
+    let outsider = setup.fixture.env.new_wallet(LAMPORTS_PER_SOL);
+    let (result, _, _) = swap_and_distribute(&mut setup, outsider, 1);
+    assert_error(result, ForestLabError::MissingRole);
+
+    let rewards_info: RewardsInfo = setup.fixture.env.fetch(&rewards_info_key(&setup.project));
+    assert!(!rewards_info.processed);
+}
+
+/// 플랫폼 트레저리에서 관리자 지갑 토큰 계정으로 수수료 출금
+fn withdraw_platform_fees(
+    setup: &mut RewardsSetup,
+    authority: Pubkey,
+    admin_token_account: Pubkey,
+    amount: u64,
+) -> std::result::Result<(), ProgramError> {
+    setup.fixture.env.call(
+        core_project::accounts::WithdrawPlatformFees {
+            authority,
+            platform: setup.fixture.platform,
+            roles: Some(setup.fixture.roles),
+            platform_treasury: pda(&[b"platform_treasury", setup.lst_mint.as_ref()]),
+            admin_token_account,
+            mint: setup.lst_mint,
+            token_program: spl_token::ID,
+        },
+        core_project::instruction::WithdrawPlatformFees { amount },
+        &[authority],
+    )
+}
+
+#[test]
+fn withdraw_platform_fees_moves_collected_fees_to_admin_wallet() {
+    let mut setup = setup();
+    process_epoch_rewards(&mut setup);
+
+    // 에포크 보상의 1%가 트레저리에 징수됨
+    let treasury = pda(&[b"platform_treasury", setup.lst_mint.as_ref()]);
+    let fee = TOTAL_REWARDS / 100;
+    assert_eq!(setup.fixture.env.token_balance(&treasury), fee);
+
+    let admin_token_account = setup
+        .fixture
+        .env
+        .create_token_account(&setup.lst_mint, &setup.fixture.admin_wallet, 0);
+    let authority = setup.fixture.authority;
+    withdraw_platform_fees(&mut setup, authority, admin_token_account, fee / 2).unwrap();
+
+    assert_eq!(setup.fixture.env.token_balance(&treasury), fee / 2);
+    assert_eq!(setup.fixture.env.token_balance(&admin_token_account), fee / 2);
+    let platform: Platform = setup.fixture.env.fetch(&setup.fixture.platform);
+    let ledger = platform
+        .fees_collected
+        .iter()
+        .find(|entry| entry.mint == setup.lst_mint)
+        .unwrap();
+    assert_eq!(ledger.collected, fee);
+    assert_eq!(ledger.withdrawn, fee / 2);
+
+    // 남은 잔액을 넘는 출금은 거부
+    let result = withdraw_platform_fees(&mut setup, authority, admin_token_account, fee);
+    assert_error(result, ForestLabError::InsufficientFunds);
+}
+
+#[test]
+fn withdraw_platform_fees_rejects_caller_without_fee_manager_role() {
+    let mut setup = setup();
+    process_epoch_rewards(&mut setup);
+
+    // 관리자 지갑은 보상 운영자 역할만 가지고 수수료 관리자 역할은 없음
+    let operator = setup.fixture.admin_wallet;
+    let admin_token_account = setup.fixture.env.create_token_account(&setup.lst_mint, &operator, 0);
+    let result = withdraw_platform_fees(&mut setup, operator, admin_token_account, 1);
+    assert_error(result, ForestLabError::MissingRole);
+
+    let treasury = pda(&[b"platform_treasury", setup.lst_mint.as_ref()]);
+    assert_eq!(setup.fixture.env.token_balance(&treasury), TOTAL_REWARDS / 100);
+}
+
+#[test]
+fn withdraw_platform_fees_rejects_destination_outside_admin_wallet() {
+    let mut setup = setup();
+    process_epoch_rewards(&mut setup);
+
+    let attacker = setup.fixture.env.new_wallet(LAMPORTS_PER_SOL);
+    let attacker_token_account = setup.fixture.env.create_token_account(&setup.lst_mint, &attacker, 0);
+    let authority = setup.fixture.authority;
+    let result = withdraw_platform_fees(&mut setup, authority, attacker_token_account, 1);
+    assert_error(result, anchor_lang::error::ErrorCode::ConstraintTokenOwner);
+}
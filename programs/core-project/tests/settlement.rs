@@ -0,0 +1,287 @@
+mod common;
+
+use anchor_lang::{
+    prelude::*,
+    solana_program::{stake, system_program, sysvar},
+};
+use anchor_spl::{
+    associated_token::{get_associated_token_address, spl_associated_token_account},
+    token::spl_token,
+};
+use common::*;
+use core_project::{ForestLabError, Lockup, Project, ProjectStatus, SPL_STAKE_POOL_PROGRAM_ID};
+
+const DAY: i64 = 86400;
+const LST: u64 = LAMPORTS_PER_SOL;
+const EPOCH: u64 = 100;
+const TOTAL_REWARDS: u64 = 10 * LST;
+
+/// 목표 100 SOL, 30일 펀딩 프로젝트 (교환 비율 2 SOL = 1 LST)
+struct SettlementSetup {
+    fixture: Fixture,
+    project: Pubkey,
+    pool: StakePoolKeys,
+}
+
+fn setup() -> SettlementSetup {
+    let mut fixture = Fixture::new();
+    let pool = fixture.env.create_stake_pool(2_000 * LAMPORTS_PER_SOL, 1_000 * LAMPORTS_PER_SOL);
+    let creator = fixture.env.new_wallet(10 * LAMPORTS_PER_SOL);
+    let project = fixture.register_project(&creator, "forest", 100 * LAMPORTS_PER_SOL, &pool.mint);
+    SettlementSetup { fixture, project, pool }
+}
+
+fn staked_user(setup: &mut SettlementSetup, lamports: u64) -> (Pubkey, Pubkey) {
+    let (project, pool) = (setup.project, setup.pool);
+    let user = setup.fixture.env.new_wallet(lamports + 10 * LAMPORTS_PER_SOL);
+    let user_lst = setup.fixture.env.create_token_account(&pool.mint, &user, 0);
+    setup
+        .fixture
+        .stake_sol(&project, &pool, &user, &user_lst, lamports)
+        .unwrap();
+    (user, user_lst)
+}
+
+fn finalize_project(setup: &mut SettlementSetup) -> std::result::Result<(), ProgramError> {
+    let project = setup.project;
+    let authority = setup.fixture.env.new_wallet(LAMPORTS_PER_SOL);
+    setup.fixture.env.call(
+        core_project::accounts::FinalizeProject {
+            project,
+            platform: setup.fixture.platform,
+            authority,
+            vault_authority: pda(&[b"vault_authority", project.as_ref()]),
+            project_share_vault: pda(&[b"project_share_vault", project.as_ref()]),
+            reward_vault: pda(&[b"reward_vault", project.as_ref()]),
+            token_program: spl_token::ID,
+        },
+        core_project::instruction::FinalizeProject {},
+        &[authority],
+    )
+}
+
+fn claim_project_rewards(
+    setup: &mut SettlementSetup,
+    user: Pubkey,
+    stake_info: Pubkey,
+) -> std::result::Result<(), ProgramError> {
+    let project = setup.project;
+    setup.fixture.env.call(
+        core_project::accounts::ClaimProjectRewards {
+            stake_info,
+            user,
+            project,
+            platform: setup.fixture.platform,
+            vault_authority: pda(&[b"vault_authority", project.as_ref()]),
+            reward_vault: pda(&[b"reward_vault", project.as_ref()]),
+            user_reward_account: get_associated_token_address(&user, &setup.pool.mint),
+            lst_mint: setup.pool.mint,
+            token_program: spl_token::ID,
+            associated_token_program: spl_associated_token_account::ID,
+            system_program: system_program::ID,
+        },
+        core_project::instruction::ClaimProjectRewards {},
+        &[user],
+    )
+}
+
+fn early_release_lockup(
+    setup: &mut SettlementSetup,
+    lockup: Pubkey,
+    user: Pubkey,
+    user_token_account: Pubkey,
+) -> std::result::Result<(), ProgramError> {
+    let project = setup.project;
+    setup.fixture.env.call(
+        core_project::accounts::EarlyReleaseLockup {
+            lockup,
+            lockup_counter: pda(&[b"lockup_counter", user.as_ref(), project.as_ref()]),
+            lockup_tier_config: pda(&[b"lockup_tier_config", project.as_ref()]),
+            voting_power: pda(&[b"voting_power", user.as_ref(), project.as_ref()]),
+            user,
+            project,
+            platform: setup.fixture.platform,
+            user_token_account,
+            lockup_vault: get_associated_token_address(&lockup, &setup.pool.mint),
+            vault_authority: pda(&[b"vault_authority", project.as_ref()]),
+            reward_vault: pda(&[b"reward_vault", project.as_ref()]),
+            bonus_vault: pda(&[b"bonus_vault", project.as_ref()]),
+            lst_mint: setup.pool.mint,
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        core_project::instruction::EarlyReleaseLockup {},
+        &[user],
+    )
+}
+
+/// 스테이커 1명과 처리된 에포크 보상 (프로젝트 몫 50%가 스왑 전 상태로 보관)
+fn cancelled_candidate() -> (SettlementSetup, Pubkey, Pubkey) {
+    let mut setup = setup();
+    let (staker, staker_lst) = staked_user(&mut setup, 2 * LAMPORTS_PER_SOL);
+
+    let usdc_mint = setup.fixture.env.create_mint(&Pubkey::new_unique(), 6);
+    setup.fixture.configure_swap(&usdc_mint, 5_000);
+    let (project, lst_mint) = (setup.project, setup.pool.mint);
+    let reward_source = setup
+        .fixture
+        .env
+        .create_token_account(&lst_mint, &setup.fixture.admin_wallet, TOTAL_REWARDS);
+    setup
+        .fixture
+        .process_epoch_rewards(&project, &lst_mint, &reward_source, EPOCH, TOTAL_REWARDS)
+        .unwrap();
+    (setup, staker, staker_lst)
+}
+
+#[test]
+fn finalize_project_completes_when_goal_is_reached() {
+    let mut setup = setup();
+    staked_user(&mut setup, 100 * LAMPORTS_PER_SOL);
+
+    // 펀딩 기간 종료 전에는 정산할 수 없음
+    let result = finalize_project(&mut setup);
+    assert_error(result, ForestLabError::CampaignNotEnded);
+
+    setup.fixture.env.warp(30 * DAY);
+    finalize_project(&mut setup).unwrap();
+    let project: Project = setup.fixture.env.fetch(&setup.project);
+    assert_eq!(project.status, ProjectStatus::Completed);
+
+    let result = finalize_project(&mut setup);
+    assert_error(result, ForestLabError::ProjectAlreadyFinalized);
+}
+
+#[test]
+fn cancelled_project_returns_unswapped_share_to_stakers() {
+    let (mut setup, staker, _) = cancelled_candidate();
+    let project = setup.project;
+    let share_vault = pda(&[b"project_share_vault", project.as_ref()]);
+    let reward_vault = pda(&[b"reward_vault", project.as_ref()]);
+    let staker_rewards = 4_950_000_000;
+    assert_eq!(setup.fixture.env.token_balance(&share_vault), staker_rewards);
+    assert_eq!(setup.fixture.env.token_balance(&reward_vault), staker_rewards);
+
+    setup.fixture.env.warp(30 * DAY);
+    finalize_project(&mut setup).unwrap();
+
+    // 목표 미달로 취소되고 프로젝트 몫은 보상 볼트로 돌아감
+    let project_data: Project = setup.fixture.env.fetch(&project);
+    assert_eq!(project_data.status, ProjectStatus::Cancelled);
+    assert_eq!(setup.fixture.env.token_balance(&share_vault), 0);
+    assert_eq!(setup.fixture.env.token_balance(&reward_vault), 2 * staker_rewards);
+
+    let stake_info = pda(&[b"stake_info", staker.as_ref(), project.as_ref()]);
+    claim_project_rewards(&mut setup, staker, stake_info).unwrap();
+    let staker_rewards_account = get_associated_token_address(&staker, &setup.pool.mint);
+    assert_eq!(setup.fixture.env.token_balance(&staker_rewards_account), 2 * staker_rewards);
+}
+
+#[test]
+fn cancelled_project_cannot_swap_rewards_and_others_cannot_claim_stakers_rewards() {
+    let (mut setup, staker, _) = cancelled_candidate();
+    let project = setup.project;
+    setup.fixture.env.warp(30 * DAY);
+    finalize_project(&mut setup).unwrap();
+
+    // 다른 사용자가 스테이커의 스테이킹 정보로 보상을 청구할 수 없음
+    let outsider = setup.fixture.env.new_wallet(LAMPORTS_PER_SOL);
+    let stake_info = pda(&[b"stake_info", staker.as_ref(), project.as_ref()]);
+    let result = claim_project_rewards(&mut setup, outsider, stake_info);
+    assert_error(result, anchor_lang::error::ErrorCode::ConstraintSeeds);
+
+    // 취소된 프로젝트는 보상을 USDC로 받을 수 없음
+    let usdc_mint = setup.fixture.env.fetch::<core_project::SwapConfig>(&pda(&[b"swap_config"])).usdc_mint;
+    let creator = setup.fixture.env.fetch::<Project>(&project).payout_wallet;
+    let payout_account = setup.fixture.env.create_token_account(&usdc_mint, &creator, 0);
+    let fee_account = setup
+        .fixture
+        .env
+        .create_token_account(&usdc_mint, &setup.fixture.admin_wallet, 0);
+    let operator = setup.fixture.admin_wallet;
+    let result = setup.fixture.env.call(
+        core_project::accounts::SwapAndDistributeRewards {
+            project,
+            authority: operator,
+            platform: setup.fixture.platform,
+            roles: Some(setup.fixture.roles),
+            rewards_info: pda(&[b"rewards_info", project.as_ref(), &EPOCH.to_le_bytes()]),
+            swap_config: pda(&[b"swap_config"]),
+            swap_program: SWAP_PROGRAM_ID,
+            vault_authority: pda(&[b"vault_authority", project.as_ref()]),
+            project_share_vault: pda(&[b"project_share_vault", project.as_ref()]),
+            usdc_vault: pda(&[b"usdc_vault", project.as_ref()]),
+            usdc_mint,
+            payout_account,
+            project_fee_account: fee_account,
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        core_project::instruction::SwapAndDistributeRewards { _epoch: EPOCH, min_out: 1 },
+        &[operator],
+    );
+    assert_error(result, ForestLabError::ProjectCancelled);
+}
+
+#[test]
+fn stakers_of_cancelled_project_unstake_and_exit_lockups_without_penalty() {
+    let mut setup = setup();
+    let (staker, staker_lst) = staked_user(&mut setup, 2 * LAMPORTS_PER_SOL);
+    let (other, other_lst) = staked_user(&mut setup, 2 * LAMPORTS_PER_SOL);
+    let (project, lst_mint) = (setup.project, setup.pool.mint);
+    let (result, lockup) = setup
+        .fixture
+        .create_lockup(&project, &lst_mint, &staker, &staker_lst, LST / 2, 60 * DAY);
+    result.unwrap();
+    let (result, _) = setup
+        .fixture
+        .create_lockup(&project, &lst_mint, &other, &other_lst, LST / 2, 60 * DAY);
+    result.unwrap();
+
+    setup.fixture.env.warp(30 * DAY);
+    finalize_project(&mut setup).unwrap();
+
+    // 다른 사용자는 스테이커의 락업을 해제할 수 없음
+    let result = early_release_lockup(&mut setup, lockup, other, other_lst);
+    assert_error(result, anchor_lang::error::ErrorCode::ConstraintSeeds);
+
+    // 만료 전 락업이지만 취소된 프로젝트라 원금 전액 반환
+    early_release_lockup(&mut setup, lockup, staker, staker_lst).unwrap();
+    assert_eq!(setup.fixture.env.token_balance(&staker_lst), LST);
+    let released: Lockup = setup.fixture.env.fetch(&lockup);
+    assert!(released.is_released);
+
+    // 스테이크 풀을 통해 SOL로 환불
+    let lamports_before = setup.fixture.env.lamports(&staker);
+    let pool = setup.pool;
+    setup
+        .fixture
+        .env
+        .call(
+            core_project::accounts::UnstakeSol {
+                project,
+                user: staker,
+                platform: setup.fixture.platform,
+                stake_info: pda(&[b"stake_info", staker.as_ref(), project.as_ref()]),
+                stake_pool: pool.pool,
+                stake_pool_withdraw_authority: pool.withdraw_authority,
+                reserve_stake: pool.reserve,
+                user_lst_account: staker_lst,
+                manager_fee_account: pool.manager_fee,
+                lst_mint,
+                stake_pool_program: SPL_STAKE_POOL_PROGRAM_ID,
+                clock: sysvar::clock::ID,
+                stake_history: sysvar::stake_history::ID,
+                stake_program: stake::program::ID,
+                token_program: spl_token::ID,
+                system_program: system_program::ID,
+            },
+            core_project::instruction::UnstakeSol { lst_amount: LST },
+            &[staker],
+        )
+        .unwrap();
+    assert_eq!(setup.fixture.env.token_balance(&staker_lst), 0);
+    assert_eq!(setup.fixture.env.lamports(&staker), lamports_before + 2 * LAMPORTS_PER_SOL);
+}
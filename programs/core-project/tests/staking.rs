@@ -0,0 +1,230 @@
+mod common;
+
+use anchor_lang::{
+    prelude::*,
+    solana_program::{stake, system_program, sysvar},
+};
+use anchor_spl::token::spl_token;
+use common::*;
+use core_project::{ForestLabError, Platform, Project, StakeInfo, SPL_STAKE_POOL_PROGRAM_ID};
+
+/// 교환 비율 2 SOL = 1 LST인 스테이크 풀과 프로젝트
+fn setup() -> (Fixture, Pubkey, StakePoolKeys) {
+    let mut fixture = Fixture::new();
+    let pool = fixture.env.create_stake_pool(2_000 * LAMPORTS_PER_SOL, 1_000 * LAMPORTS_PER_SOL);
+    let creator = fixture.env.new_wallet(10 * LAMPORTS_PER_SOL);
+    let project = fixture.register_project(&creator, "forest", 100 * LAMPORTS_PER_SOL, &pool.mint);
+    (fixture, project, pool)
+}
+
+#[test]
+fn stake_sol_records_minted_lst_and_lamport_delta() {
+    let (mut fixture, project, pool) = setup();
+    let user = fixture.env.new_wallet(10 * LAMPORTS_PER_SOL);
+    let user_lst = fixture.env.create_token_account(&pool.mint, &user, 0);
+
+    fixture
+        .stake_sol(&project, &pool, &user, &user_lst, 2 * LAMPORTS_PER_SOL)
+        .unwrap();
+
+    // 풀 교환 비율대로 발행된 LST와 리저브로 이동한 SOL
+    assert_eq!(fixture.env.token_balance(&user_lst), LAMPORTS_PER_SOL);
+    assert_eq!(fixture.env.lamports(&pool.reserve), 2_002 * LAMPORTS_PER_SOL);
+
+    let stake_info: StakeInfo = fixture.env.fetch(&pda(&[b"stake_info", user.as_ref(), project.as_ref()]));
+    assert_eq!(stake_info.user, user);
+    assert_eq!(stake_info.current_lst_amount, LAMPORTS_PER_SOL);
+    assert_eq!(stake_info.initial_stake_amount, 2 * LAMPORTS_PER_SOL);
+
+    let project_data: Project = fixture.env.fetch(&project);
+    assert_eq!(project_data.funds_raised, 2 * LAMPORTS_PER_SOL);
+    assert_eq!(project_data.total_staked_lst, LAMPORTS_PER_SOL);
+    assert_eq!(project_data.supporters_count, 1);
+    let platform: Platform = fixture.env.fetch(&fixture.platform);
+    assert_eq!(platform.total_staked_sol, 2 * LAMPORTS_PER_SOL);
+}
+
+#[test]
+fn stake_sol_rejects_pool_not_owned_by_stake_pool_program() {
+    let (mut fixture, project, pool) = setup();
+    let user = fixture.env.new_wallet(10 * LAMPORTS_PER_SOL);
+    let user_lst = fixture.env.create_token_account(&pool.mint, &user, 0);
+
+    // 같은 데이터를 가진 위조 풀 계정 (다른 프로그램 소유)
+    let mut forged = fixture.env.account(&pool.pool).unwrap().clone();
+    forged.owner = Pubkey::new_unique();
+    fixture.env.set_account(pool.pool, forged);
+
+    let result = fixture.stake_sol(&project, &pool, &user, &user_lst, LAMPORTS_PER_SOL);
+    assert_error(result, ForestLabError::InvalidStakePool);
+    assert_eq!(fixture.env.lamports(&user), 10 * LAMPORTS_PER_SOL);
+}
+
+#[test]
+fn stake_sol_rejects_lst_account_of_another_user() {
+    let (mut fixture, project, pool) = setup();
+    let user = fixture.env.new_wallet(10 * LAMPORTS_PER_SOL);
+    let other = fixture.env.new_wallet(LAMPORTS_PER_SOL);
+    let other_lst = fixture.env.create_token_account(&pool.mint, &other, 0);
+
+    let result = fixture.stake_sol(&project, &pool, &user, &other_lst, LAMPORTS_PER_SOL);
+    assert_error(result, anchor_lang::error::ErrorCode::ConstraintTokenOwner);
+}
+
+fn unstake_sol_accounts(
+    fixture: &Fixture,
+    project: &Pubkey,
+    pool: &StakePoolKeys,
+    user: &Pubkey,
+    stake_info: &Pubkey,
+    user_lst_account: &Pubkey,
+) -> core_project::accounts::UnstakeSol {
+    core_project::accounts::UnstakeSol {
+        project: *project,
+        user: *user,
+        platform: fixture.platform,
+        stake_info: *stake_info,
+        stake_pool: pool.pool,
+        stake_pool_withdraw_authority: pool.withdraw_authority,
+        reserve_stake: pool.reserve,
+        user_lst_account: *user_lst_account,
+        manager_fee_account: pool.manager_fee,
+        lst_mint: pool.mint,
+        stake_pool_program: SPL_STAKE_POOL_PROGRAM_ID,
+        clock: sysvar::clock::ID,
+        stake_history: sysvar::stake_history::ID,
+        stake_program: stake::program::ID,
+        token_program: spl_token::ID,
+        system_program: system_program::ID,
+    }
+}
+
+/// 2 SOL을 스테이킹해 1 LST를 받은 사용자
+fn staked_user(fixture: &mut Fixture, project: &Pubkey, pool: &StakePoolKeys) -> (Pubkey, Pubkey, Pubkey) {
+    let user = fixture.env.new_wallet(10 * LAMPORTS_PER_SOL);
+    let user_lst = fixture.env.create_token_account(&pool.mint, &user, 0);
+    fixture
+        .stake_sol(project, pool, &user, &user_lst, 2 * LAMPORTS_PER_SOL)
+        .unwrap();
+    let stake_info = pda(&[b"stake_info", user.as_ref(), project.as_ref()]);
+    (user, user_lst, stake_info)
+}
+
+#[test]
+fn unstake_sol_burns_lst_through_pool_before_updating_stake_info() {
+    let (mut fixture, project, pool) = setup();
+    let (user, user_lst, stake_info) = staked_user(&mut fixture, &project, &pool);
+    let lamports_before = fixture.env.lamports(&user);
+
+    let accounts = unstake_sol_accounts(&fixture, &project, &pool, &user, &stake_info, &user_lst);
+    fixture
+        .env
+        .call(
+            accounts,
+            core_project::instruction::UnstakeSol {
+                lst_amount: LAMPORTS_PER_SOL / 2,
+            },
+            &[user],
+        )
+        .unwrap();
+
+    // 소각된 LST만큼 풀 비율로 SOL 수령
+    assert_eq!(fixture.env.token_balance(&user_lst), LAMPORTS_PER_SOL / 2);
+    assert_eq!(fixture.env.lamports(&user), lamports_before + LAMPORTS_PER_SOL);
+
+    let stake_info: StakeInfo = fixture.env.fetch(&stake_info);
+    assert_eq!(stake_info.current_lst_amount, LAMPORTS_PER_SOL / 2);
+    assert_eq!(stake_info.initial_stake_amount, LAMPORTS_PER_SOL);
+    let project_data: Project = fixture.env.fetch(&project);
+    assert_eq!(project_data.funds_raised, LAMPORTS_PER_SOL);
+    assert_eq!(project_data.total_staked_lst, LAMPORTS_PER_SOL / 2);
+}
+
+#[test]
+fn unstake_sol_rejects_another_users_stake_info() {
+    let (mut fixture, project, pool) = setup();
+    let (_victim, _victim_lst, victim_stake_info) = staked_user(&mut fixture, &project, &pool);
+    let attacker = fixture.env.new_wallet(LAMPORTS_PER_SOL);
+    let attacker_lst = fixture.env.create_token_account(&pool.mint, &attacker, LAMPORTS_PER_SOL);
+
+    let accounts = unstake_sol_accounts(&fixture, &project, &pool, &attacker, &victim_stake_info, &attacker_lst);
+    let result = fixture.env.call(
+        accounts,
+        core_project::instruction::UnstakeSol {
+            lst_amount: LAMPORTS_PER_SOL,
+        },
+        &[attacker],
+    );
+    assert_error(result, anchor_lang::error::ErrorCode::ConstraintSeeds);
+}
+
+#[test]
+fn unstake_sol_rejects_more_than_staked() {
+    let (mut fixture, project, pool) = setup();
+    let (user, user_lst, stake_info) = staked_user(&mut fixture, &project, &pool);
+
+    let accounts = unstake_sol_accounts(&fixture, &project, &pool, &user, &stake_info, &user_lst);
+    let result = fixture.env.call(
+        accounts,
+        core_project::instruction::UnstakeSol {
+            lst_amount: 2 * LAMPORTS_PER_SOL,
+        },
+        &[user],
+    );
+    assert_error(result, ForestLabError::InsufficientLstBalance);
+}
+
+#[test]
+fn unstake_stake_moves_lamports_into_new_stake_account() {
+    let (mut fixture, project, pool) = setup();
+    let (user, user_lst, stake_info) = staked_user(&mut fixture, &project, &pool);
+
+    // 스테이크 프로그램 소유의 미초기화 스테이크 계정
+    let stake_to_receive = Pubkey::new_unique();
+    let rent = Rent::default().minimum_balance(200);
+    fixture.env.set_account(
+        stake_to_receive,
+        TestAccount {
+            lamports: rent,
+            data: vec![0; 200],
+            owner: stake::program::ID,
+            executable: false,
+        },
+    );
+
+    fixture
+        .env
+        .call(
+            core_project::accounts::UnstakeStake {
+                project,
+                user,
+                platform: fixture.platform,
+                stake_info,
+                stake_pool: pool.pool,
+                validator_list: pool.validator_list,
+                stake_pool_withdraw_authority: pool.withdraw_authority,
+                stake_to_split: pool.reserve,
+                stake_to_receive,
+                user_lst_account: user_lst,
+                manager_fee_account: pool.manager_fee,
+                lst_mint: pool.mint,
+                stake_pool_program: SPL_STAKE_POOL_PROGRAM_ID,
+                clock: sysvar::clock::ID,
+                stake_program: stake::program::ID,
+                token_program: spl_token::ID,
+                system_program: system_program::ID,
+            },
+            core_project::instruction::UnstakeStake {
+                lst_amount: LAMPORTS_PER_SOL,
+            },
+            &[user],
+        )
+        .unwrap();
+
+    assert_eq!(fixture.env.lamports(&stake_to_receive), rent + 2 * LAMPORTS_PER_SOL);
+    assert_eq!(fixture.env.token_balance(&user_lst), 0);
+    let stake_info: StakeInfo = fixture.env.fetch(&stake_info);
+    assert_eq!(stake_info.current_lst_amount, 0);
+    let project_data: Project = fixture.env.fetch(&project);
+    assert_eq!(project_data.supporters_count, 0);
+}
@@ -11,77 +11,172 @@ describe("core-project", () => {
   // 프로그램 ID를 사용하여 프로그램 객체 가져오기
   const program = anchor.workspace.CoreProject as Program<CoreProject>;
 
-  // 테스트에 사용할 지갑
+  // 테스트에 사용할 지갑 (플랫폼 관리자)
   const wallet = provider.wallet as anchor.Wallet;
-
-  it("Initialize 테스트", async () => {
-    // 초기화 트랜잭션 실행
-    const tx = await program.methods
-        .initialize()
-        .accounts({})
+  const adminWallet = anchor.web3.Keypair.generate();
+
+  // 플랫폼 PDA
+  const [platform] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("platform")],
+      program.programId
+  );
+
+  // 작업별 일시 중지 플래그 (Platform::PAUSE_*)
+  const PAUSE_STAKE = 1 << 0;
+  const PAUSE_REGISTRATION = 1 << 5;
+
+  const projectName = "forest";
+  const [project] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("project"), Buffer.from(projectName), wallet.publicKey.toBuffer()],
+      program.programId
+  );
+
+  const registerProject = (name: string) =>
+      program.methods
+          .registerProject(
+              name,
+              "FRST",
+              "테스트 프로젝트",
+              "https://forest.example",
+              "https://forest.example/image.png",
+              new anchor.BN(10 * anchor.web3.LAMPORTS_PER_SOL),
+              new anchor.BN(30 * 86400),
+              anchor.web3.Keypair.generate().publicKey,
+              500,
+              null,
+              null
+          )
+          .accountsPartial({
+            creator: wallet.publicKey,
+            platform,
+          });
+
+  it("플랫폼 초기화 테스트", async () => {
+    await program.methods
+        .initializePlatform(100, new anchor.BN(1_000_000), adminWallet.publicKey)
+        .accountsPartial({ platform, payer: wallet.publicKey })
         .rpc();
 
-    console.log("초기화 트랜잭션:", tx);
-    // 트랜잭션이 성공적으로 완료되었으면 테스트 통과
+    const platformData = await program.account.platform.fetch(platform);
+    expect(platformData.authority.toString()).to.equal(wallet.publicKey.toString());
+    expect(platformData.adminWallet.toString()).to.equal(adminWallet.publicKey.toString());
+    expect(platformData.platformFee).to.equal(100);
+    expect(platformData.isActive).to.be.true;
+    expect(platformData.pausedOperations).to.equal(0);
+    expect(platformData.settingsTimelock.toNumber()).to.equal(2 * 86400);
+
+    // 관리자 지갑에는 보상 운영자(1 << 2)와 프로젝트 중재자(1 << 4) 역할이 부여됨
+    const [roles] = anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("roles")],
+        program.programId
+    );
+    const rolesData = await program.account.roles.fetch(roles);
+    expect(rolesData.members).to.have.lengthOf(1);
+    expect(rolesData.members[0].member.toString()).to.equal(adminWallet.publicKey.toString());
+    expect(rolesData.members[0].roles).to.equal((1 << 2) | (1 << 4));
   });
 
-  it("지갑 정보 확인 테스트", async () => {
-    // 지갑 정보 확인 트랜잭션 실행
-    const tx = await program.methods
-        .checkWalletInfo()
-        .accounts({
-          wallet: wallet.publicKey,
-          systemProgram: anchor.web3.SystemProgram.programId,
-        })
-        .rpc();
+  it("프로젝트 등록 테스트", async () => {
+    await registerProject(projectName).rpc();
 
-    console.log("지갑 정보 확인 트랜잭션:", tx);
-    console.log("지갑 주소:", wallet.publicKey.toString());
+    const projectData = await program.account.project.fetch(project);
+    expect(projectData.creator.toString()).to.equal(wallet.publicKey.toString());
+    expect(projectData.authority.toString()).to.equal(wallet.publicKey.toString());
+    expect(projectData.name).to.equal(projectName);
+    expect(projectData.status).to.deep.equal({ active: {} });
+    expect(projectData.endTime.toNumber()).to.equal(
+        projectData.startTime.toNumber() + 30 * 86400
+    );
 
-    // 트랜잭션이 성공적으로 완료되었는지 확인
-    const confirmedTx = await provider.connection.getParsedTransaction(tx, "confirmed");
-    expect(confirmedTx).to.not.be.null;
+    const platformData = await program.account.platform.fetch(platform);
+    expect(platformData.totalProjects.toNumber()).to.equal(1);
   });
 
-  it("메시지 저장 및 조회 테스트", async () => {
-    // 메시지 계정 PDA 찾기
-    const [messageAccount] = await anchor.web3.PublicKey.findProgramAddressSync(
-        [Buffer.from("message"), wallet.publicKey.toBuffer()],
-        program.programId
-    );
-
-    // 테스트용 메시지
-    const testMessage = "안녕하세요, 솔라나 블록체인!";
-
-    // 메시지 저장 트랜잭션 실행
-    const tx = await program.methods
-        .saveMessage(testMessage)
-        .accounts({
-          author: wallet.publicKey,
-          messageAccount: messageAccount,
-          systemProgram: anchor.web3.SystemProgram.programId,
-        })
+  it("등록 일시 중지 중에는 프로젝트 등록 불가 테스트", async () => {
+    await program.methods
+        .pauseOperations(PAUSE_REGISTRATION | PAUSE_STAKE)
+        .accountsPartial({ authority: wallet.publicKey, platform, roles: null })
         .rpc();
 
-    console.log("메시지 저장 트랜잭션:", tx);
+    let platformData = await program.account.platform.fetch(platform);
+    expect(platformData.pausedOperations).to.equal(PAUSE_REGISTRATION | PAUSE_STAKE);
 
-    // 저장된 메시지 조회
-    const messageData = await program.account.messageAccount.fetch(messageAccount);
+    try {
+      await registerProject("paused").rpc();
+      expect.fail("일시 중지 중 등록이 성공함");
+    } catch (err) {
+      expect(err).to.be.instanceOf(anchor.AnchorError);
+      expect((err as anchor.AnchorError).error.errorCode.code).to.equal("OperationPaused");
+    }
 
-    // 저장된 메시지 검증
-    expect(messageData.author.toString()).to.equal(wallet.publicKey.toString());
-    expect(messageData.message).to.equal(testMessage);
+    await program.methods
+        .unpauseOperations(PAUSE_REGISTRATION | PAUSE_STAKE)
+        .accountsPartial({ authority: wallet.publicKey, platform, roles: null })
+        .rpc();
 
-    // BN 타입 체크 - timestamp는 BN 타입입니다
-    expect(messageData.timestamp).to.not.be.null;
-    expect(messageData.timestamp.toNumber).to.be.a('function');
+    platformData = await program.account.platform.fetch(platform);
+    expect(platformData.pausedOperations).to.equal(0);
+  });
+
+  it("설정 변경 항목별 예약 및 취소 테스트", async () => {
+    const newAdmin = anchor.web3.Keypair.generate().publicKey;
+
+    // 관리자 지갑 변경 예약 후 수수료 변경 예약 (관리자 지갑 대기 시간은 재시작되지 않음)
+    await program.methods
+        .updatePlatformSettings(null, null, newAdmin, null, null)
+        .accountsPartial({ platform, roles: null, authority: wallet.publicKey })
+        .rpc();
+    let platformData = await program.account.platform.fetch(platform);
+    const adminEffectiveAt = platformData.pendingAdminWalletEffectiveAt.toNumber();
+    expect(adminEffectiveAt).to.be.greaterThan(0);
 
-    const timestamp = messageData.timestamp.toNumber();
-    expect(timestamp).to.be.a('number');
-    expect(timestamp).to.be.greaterThan(0);
+    await program.methods
+        .updatePlatformSettings(200, null, null, null, null)
+        .accountsPartial({ platform, roles: null, authority: wallet.publicKey })
+        .rpc();
+    platformData = await program.account.platform.fetch(platform);
+    expect(platformData.pendingPlatformFee).to.equal(200);
+    expect(platformData.pendingAdminWalletEffectiveAt.toNumber()).to.equal(adminEffectiveAt);
+
+    // 수수료 변경만 취소
+    await program.methods
+        .cancelPlatformSettings(true, false, false)
+        .accountsPartial({ platform, roles: null, authority: wallet.publicKey })
+        .rpc();
+    platformData = await program.account.platform.fetch(platform);
+    expect(platformData.pendingPlatformFee).to.be.null;
+    expect(platformData.pendingAdminWallet.toString()).to.equal(newAdmin.toString());
+
+    // 대기 시간 이전에는 실행 불가
+    try {
+      await program.methods
+          .executePlatformSettings()
+          .accountsPartial({ platform, executor: wallet.publicKey })
+          .rpc();
+      expect.fail("대기 시간 이전에 실행이 성공함");
+    } catch (err) {
+      expect(err).to.be.instanceOf(anchor.AnchorError);
+      expect((err as anchor.AnchorError).error.errorCode.code).to.equal("SettingsTimelockNotElapsed");
+    }
+
+    await program.methods
+        .cancelPlatformSettings(false, true, false)
+        .accountsPartial({ platform, roles: null, authority: wallet.publicKey })
+        .rpc();
+    platformData = await program.account.platform.fetch(platform);
+    expect(platformData.pendingAdminWallet).to.be.null;
+  });
 
-    console.log("저장된 메시지:", messageData.message);
-    console.log("작성자:", messageData.author.toString());
-    console.log("작성 시간:", new Date(timestamp * 1000).toLocaleString());
+  it("유효하지 않은 일시 중지 플래그 거부 테스트", async () => {
+    try {
+      await program.methods
+          .pauseOperations(0)
+          .accountsPartial({ authority: wallet.publicKey, platform, roles: null })
+          .rpc();
+      expect.fail("빈 플래그로 일시 중지가 성공함");
+    } catch (err) {
+      expect(err).to.be.instanceOf(anchor.AnchorError);
+      expect((err as anchor.AnchorError).error.errorCode.code).to.equal("InvalidPauseFlags");
+    }
   });
-});
\ No newline at end of file
+});